@@ -0,0 +1,165 @@
+use crate::{
+    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle,
+    Point, Polygon, Rect, Triangle,
+};
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Point<[N; D]> {
+    /// Returns the bounding box of the Point.
+    pub fn bounding_rect(&self) -> Rect<[N; D]> {
+        Rect::new(*self.coord(), *self.coord())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Line<[N; D]> {
+    /// Returns the bounding box of the Line.
+    pub fn bounding_rect(&self) -> Rect<[N; D]> {
+        Rect::new(*self.start(), *self.end())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Triangle<[N; D]> {
+    /// Returns the bounding box of the Triangle.
+    pub fn bounding_rect(&self) -> Rect<[N; D]> {
+        Rect::from_coords(self.iter()).unwrap()
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> MultiPoint<'_, [N; D]> {
+    /// Returns the bounding box of the MultiPoint, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        Rect::from_coords(self.iter())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> LineString<'_, [N; D]> {
+    /// Returns the bounding box of the LineString, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        Rect::from_coords(self.iter())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> MultiLineString<'_, [N; D]> {
+    /// Returns the bounding box of the MultiLineString, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        Rect::from_coords(self.raw_coords().iter().copied())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Polygon<'_, [N; D]> {
+    /// Returns the bounding box of the Polygon, or `None` if it is empty.
+    ///
+    /// Interior rings are taken into account as well, so that the result also makes sense for invalid polygons.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        Rect::from_coords(self.raw_coords().iter().copied())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> MultiPolygon<'_, [N; D]> {
+    /// Returns the bounding box of the MultiPolygon, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        Rect::from_coords(self.raw_coords().iter().copied())
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> MultiTriangle<'_, [N; D]> {
+    /// Returns the bounding box of the vertices referred to by the triangles, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        let coords = self.raw_coords();
+        Rect::from_coords(self.raw_indices().iter().map(|&i| coords[i as usize]))
+    }
+}
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Geometry<'_, [N; D]> {
+    /// Returns the bounding box of the Geometry, or `None` if it is empty.
+    pub fn bounding_rect(&self) -> Option<Rect<[N; D]>> {
+        match self {
+            Geometry::Point(geom) => Some(geom.bounding_rect()),
+            Geometry::MultiPoint(geom) => geom.bounding_rect(),
+            Geometry::Line(geom) => Some(geom.bounding_rect()),
+            Geometry::LineString(geom) => geom.bounding_rect(),
+            Geometry::MultiLineString(geom) => geom.bounding_rect(),
+            Geometry::Polygon(geom) => geom.bounding_rect(),
+            Geometry::MultiPolygon(geom) => geom.bounding_rect(),
+            Geometry::Triangle(geom) => Some(geom.bounding_rect()),
+            Geometry::MultiTriangle(geom) => geom.bounding_rect(),
+            Geometry::GeometryCollection(geoms) => geoms
+                .iter()
+                .filter_map(|geom| geom.bounding_rect())
+                .reduce(|a, b| a.union(&b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Geometry2, LineString2, MultiLineString3, MultiPoint2, MultiPolygon2, MultiTriangle2,
+        Polygon2, Rect2, Rect3,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn test_bounding_rect() {
+        assert_eq!(
+            Point::new([1., 2.]).bounding_rect(),
+            Rect2::new([1., 2.], [1., 2.])
+        );
+        assert_eq!(
+            Line::new([3., 0.], [1., 2.]).bounding_rect(),
+            Rect2::new([1., 0.], [3., 2.])
+        );
+        assert_eq!(
+            Triangle::new([0, 0], [4, 1], [1, -2]).bounding_rect(),
+            Rect2::new([0, -2], [4, 1])
+        );
+
+        assert_eq!(MultiPoint2::<f64>::new().bounding_rect(), None);
+        assert_eq!(
+            MultiPoint2::from_raw(vec![[1., 1.], [-1., 3.]].into()).bounding_rect(),
+            Some(Rect2::new([-1., 1.], [1., 3.]))
+        );
+
+        let ls = LineString2::from_raw(vec![[0., 0.], [5., -1.], [2., 7.]].into());
+        assert_eq!(ls.bounding_rect(), Some(Rect2::new([0., -1.], [5., 7.])));
+
+        let mut mls = MultiLineString3::new();
+        mls.add_linestring([[0., 0., 0.], [1., 1., 1.]]);
+        mls.add_linestring([[-1., 3., 0.], [1., 1., -5.]]);
+        assert_eq!(
+            mls.bounding_rect(),
+            Some(Rect3::new([-1., 0., -5.], [1., 3., 1.]))
+        );
+
+        let mut poly = Polygon2::new();
+        assert_eq!(poly.bounding_rect(), None);
+        poly.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        assert_eq!(poly.bounding_rect(), Some(Rect2::new([0., 0.], [5., 5.])));
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&poly);
+        mpoly.add_exterior([[10., 10.], [11., 10.], [11., 12.]]);
+        assert_eq!(
+            mpoly.bounding_rect(),
+            Some(Rect2::new([0., 0.], [11., 12.]))
+        );
+
+        let mut mtri = MultiTriangle2::new();
+        mtri.push_vertex([100., 100.]); // unused vertex
+        mtri.push(&Triangle::new([0., 0.], [1., 0.], [0., 1.]));
+        assert_eq!(mtri.bounding_rect(), Some(Rect2::new([0., 0.], [1., 1.])));
+    }
+
+    #[test]
+    fn test_geometry_bounding_rect() {
+        let geom = Geometry2::GeometryCollection(vec![
+            Geometry2::Point(Point::new([-3., 1.])),
+            Geometry2::MultiPoint(MultiPoint2::new()),
+            Geometry2::GeometryCollection(vec![Geometry2::Line(Line::new([0., 0.], [2., 9.]))]),
+        ]);
+        assert_eq!(geom.bounding_rect(), Some(Rect2::new([-3., 0.], [2., 9.])));
+
+        let geom = Geometry2::<f64>::GeometryCollection(vec![]);
+        assert_eq!(geom.bounding_rect(), None);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    Coord2d, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle,
+    Point, Point2, Polygon, Triangle,
+};
+
+/// Weighted sum of positions
+#[derive(Default, Clone, Copy)]
+struct WeightedSum {
+    weight: f64,
+    x: f64,
+    y: f64,
+}
+
+impl WeightedSum {
+    fn add(&mut self, weight: f64, (x, y): (f64, f64)) {
+        self.weight += weight;
+        self.x += weight * x;
+        self.y += weight * y;
+    }
+
+    fn centroid(&self) -> Option<Point2> {
+        (self.weight != 0.0).then(|| Point::new([self.x / self.weight, self.y / self.weight]))
+    }
+}
+
+/// Accumulates the centroid of the parts of the highest dimension.
+#[derive(Default)]
+struct Centroid {
+    points: WeightedSum,
+    lines: WeightedSum,
+    areas: WeightedSum,
+}
+
+impl Centroid {
+    fn add_point(&mut self, p: (f64, f64)) {
+        self.points.add(1.0, p);
+    }
+
+    fn add_path<T: Coord2d>(&mut self, mut coords: impl Iterator<Item = T>) {
+        let Some(first) = coords.next() else {
+            return;
+        };
+        let mut prev = first.xy();
+        // zero-length paths still count as points
+        self.add_point(prev);
+        for c in coords {
+            let p = c.xy();
+            let len = libm::hypot(p.0 - prev.0, p.1 - prev.1);
+            self.lines
+                .add(len, ((prev.0 + p.0) / 2.0, (prev.1 + p.1) / 2.0));
+            prev = p;
+        }
+    }
+
+    /// Adds a ring with its area counted positively (exterior) or negatively (interior).
+    fn add_ring<T: Coord2d>(&mut self, ring: &LineString<'_, T>, exterior: bool) {
+        self.add_path(ring.iter_closed());
+        let coords = ring.raw_coords();
+        if coords.len() < 3 {
+            return;
+        }
+        // centroid of the ring relative to its first vertex, for numerical stability
+        let origin = coords[0].xy();
+        let (mut area2, mut cx, mut cy) = (0.0, 0.0, 0.0);
+        let mut prev = (0.0, 0.0);
+        for c in &coords[1..] {
+            let (x, y) = c.xy();
+            let p = (x - origin.0, y - origin.1);
+            let cross = prev.0 * p.1 - p.0 * prev.1;
+            area2 += cross;
+            cx += (prev.0 + p.0) * cross;
+            cy += (prev.1 + p.1) * cross;
+            prev = p;
+        }
+        if area2 == 0.0 {
+            return;
+        }
+        let centroid = (origin.0 + cx / (3.0 * area2), origin.1 + cy / (3.0 * area2));
+        let area = (area2 / 2.0).abs();
+        self.areas
+            .add(if exterior { area } else { -area }, centroid);
+    }
+
+    fn add_polygon<T: Coord2d>(&mut self, poly: &Polygon<'_, T>) {
+        if poly.raw_coords().is_empty() {
+            return;
+        }
+        for (i, ring) in poly.rings().enumerate() {
+            self.add_ring(&ring, i == 0);
+        }
+    }
+
+    fn add_geometry<T: Coord2d>(&mut self, geom: &Geometry<'_, T>) {
+        match geom {
+            Geometry::Point(p) => self.add_point(p.coord().xy()),
+            Geometry::MultiPoint(mp) => mp.iter().for_each(|c| self.add_point(c.xy())),
+            Geometry::Line(line) => self.add_path(line.iter()),
+            Geometry::LineString(ls) => self.add_path(ls.iter()),
+            Geometry::MultiLineString(mls) => mls.iter().for_each(|ls| self.add_path(ls.iter())),
+            Geometry::Polygon(poly) => self.add_polygon(poly),
+            Geometry::MultiPolygon(mpoly) => mpoly.iter().for_each(|p| self.add_polygon(&p)),
+            Geometry::Triangle(tri) => self.add_triangle(tri),
+            Geometry::MultiTriangle(mtri) => mtri.iter().for_each(|t| self.add_triangle(&t)),
+            Geometry::GeometryCollection(geoms) => geoms.iter().for_each(|g| self.add_geometry(g)),
+        }
+    }
+
+    fn add_triangle<T: Coord2d>(&mut self, tri: &Triangle<T>) {
+        self.add_ring(&LineString::from_raw(tri.raw_coords().into()), true);
+    }
+
+    fn result(&self) -> Option<Point2> {
+        self.areas
+            .centroid()
+            .or_else(|| self.lines.centroid())
+            .or_else(|| self.points.centroid())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Point<T> {
+    /// Returns the Point itself as a 2D point.
+    pub fn centroid(&self) -> Point2 {
+        let (x, y) = self.coord().xy();
+        Point::new([x, y])
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPoint<'_, T> {
+    /// Returns the mean of the points, or `None` if the MultiPoint is empty.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        self.iter().for_each(|p| c.add_point(p.xy()));
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Line<T> {
+    /// Returns the midpoint of the Line.
+    pub fn centroid(&self) -> Point2 {
+        let (a, b) = (self.start().xy(), self.end().xy());
+        Point::new([(a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0])
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> LineString<'_, T> {
+    /// Returns the length-weighted centroid of the segments, or `None` if the LineString is empty.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        c.add_path(self.iter());
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiLineString<'_, T> {
+    /// Returns the length-weighted centroid of all segments, or `None` if the MultiLineString is empty.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        self.iter().for_each(|ls| c.add_path(ls.iter()));
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Returns the area-weighted centroid, or `None` if the Polygon is empty.
+    ///
+    /// Falls back to the centroid of the rings as lines if the Polygon has no area.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        c.add_polygon(self);
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Returns the area-weighted centroid, or `None` if the MultiPolygon is empty.
+    ///
+    /// Falls back to the centroid of the rings as lines if the MultiPolygon has no area.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        self.iter().for_each(|poly| c.add_polygon(&poly));
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Triangle<T> {
+    /// Returns the centroid (the mean of the vertices) of the Triangle.
+    pub fn centroid(&self) -> Point2 {
+        let [a, b, c] = [0, 1, 2].map(|i| self.raw_coords()[i].xy());
+        Point::new([(a.0 + b.0 + c.0) / 3.0, (a.1 + b.1 + c.1) / 3.0])
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiTriangle<'_, T> {
+    /// Returns the area-weighted centroid, or `None` if the MultiTriangle is empty.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        self.iter().for_each(|tri| c.add_triangle(&tri));
+        c.result()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Geometry<'_, T> {
+    /// Returns the centroid of the parts of the highest dimension, or `None` if the Geometry is empty.
+    ///
+    /// Areal parts are weighted by area, linear parts by length, and points equally.
+    pub fn centroid(&self) -> Option<Point2> {
+        let mut c = Centroid::default();
+        c.add_geometry(self);
+        c.result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, LineString2, MultiPoint2, MultiPolygon2, Polygon2};
+    use alloc::vec;
+
+    #[test]
+    fn test_centroid_points_and_lines() {
+        assert_eq!(Point::new([1, 2]).centroid().coord(), &[1., 2.]);
+        let mp = MultiPoint2::from_raw(vec![[0., 0.], [2., 0.], [1., 3.]].into());
+        assert_eq!(mp.centroid().unwrap().coord(), &[1., 1.]);
+        assert_eq!(MultiPoint2::<f64>::new().centroid(), None);
+
+        assert_eq!(Line::new([0., 0.], [2., 2.]).centroid().coord(), &[1., 1.]);
+        let ls = LineString2::from_raw(vec![[0., 0.], [4., 0.], [4., 2.]].into());
+        assert_eq!(ls.centroid().unwrap().coord(), &[8. / 3., 1. / 3.]);
+        // zero length
+        let ls = LineString2::from_raw(vec![[1., 1.], [1., 1.]].into());
+        assert_eq!(ls.centroid().unwrap().coord(), &[1., 1.]);
+    }
+
+    #[test]
+    fn test_centroid_polygons() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        assert_eq!(poly.centroid().unwrap().coord(), &[2., 2.]);
+        // the hole shifts the centroid, regardless of its winding order
+        poly.add_ring([[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        let [x, y] = *poly.centroid().unwrap().coord();
+        assert!((x - 7. / 3.).abs() < 1e-12 && (y - 7. / 3.).abs() < 1e-12);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        mpoly.add_exterior([[10., 0.], [12., 0.], [12., 2.], [10., 2.]]);
+        assert_eq!(mpoly.centroid().unwrap().coord(), &[6., 1.]);
+
+        // degenerate polygon falls back to its boundary
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [2., 0.], [4., 0.]]);
+        assert_eq!(poly.centroid().unwrap().coord(), &[2., 0.]);
+
+        let tri = Triangle::new([0., 0.], [3., 0.], [0., 3.]);
+        assert_eq!(tri.centroid().coord(), &[1., 1.]);
+    }
+
+    #[test]
+    fn test_centroid_geometry() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+        let geom = Geometry2::GeometryCollection(vec![
+            Geometry::Polygon(poly),
+            Geometry::Point(Point::new([100., 100.])),
+            Geometry::LineString(LineString::from_raw(vec![[50., 0.], [60., 0.]].into())),
+        ]);
+        assert_eq!(geom.centroid().unwrap().coord(), &[1., 1.]);
+        assert_eq!(
+            Geometry2::<f64>::GeometryCollection(vec![]).centroid(),
+            None
+        );
+    }
+}
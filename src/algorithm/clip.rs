@@ -0,0 +1,396 @@
+use alloc::vec::Vec;
+
+use num_traits::float::FloatCore;
+
+use crate::{CoordNum, LineString, MultiLineString, MultiPolygon, Polygon, Rect2};
+
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+fn outcode<const D: usize, N: CoordNum + FloatCore>(c: &[N; D], rect: &Rect2<N>) -> u8 {
+    let (min, max) = (rect.min(), rect.max());
+    let mut code = 0;
+    if c[0] < min[0] {
+        code |= LEFT;
+    } else if c[0] > max[0] {
+        code |= RIGHT;
+    }
+    if c[1] < min[1] {
+        code |= BOTTOM;
+    } else if c[1] > max[1] {
+        code |= TOP;
+    }
+    code
+}
+
+/// Computes the point on the segment `a`-`b` where `axis` equals `value`,
+/// linearly interpolating the other values (including Z).
+fn intersect<const D: usize, N: CoordNum + FloatCore>(
+    a: &[N; D],
+    b: &[N; D],
+    axis: usize,
+    value: N,
+) -> [N; D] {
+    let t = (value - a[axis]) / (b[axis] - a[axis]);
+    let mut p: [N; D] = core::array::from_fn(|i| a[i] + (b[i] - a[i]) * t);
+    p[axis] = value;
+    p
+}
+
+/// Clips a segment to the rectangle (Cohen–Sutherland).
+fn clip_segment<const D: usize, N: CoordNum + FloatCore>(
+    mut a: [N; D],
+    mut b: [N; D],
+    rect: &Rect2<N>,
+) -> Option<([N; D], [N; D])> {
+    let mut code_a = outcode(&a, rect);
+    let mut code_b = outcode(&b, rect);
+    loop {
+        if code_a | code_b == 0 {
+            return Some((a, b));
+        }
+        if code_a & code_b != 0 {
+            return None;
+        }
+        let code = if code_a != 0 { code_a } else { code_b };
+        let (axis, value) = if code & LEFT != 0 {
+            (0, rect.min()[0])
+        } else if code & RIGHT != 0 {
+            (0, rect.max()[0])
+        } else if code & BOTTOM != 0 {
+            (1, rect.min()[1])
+        } else {
+            (1, rect.max()[1])
+        };
+        let p = intersect(&a, &b, axis, value);
+        if code == code_a {
+            a = p;
+            code_a = outcode(&a, rect);
+        } else {
+            b = p;
+            code_b = outcode(&b, rect);
+        }
+    }
+}
+
+/// Clips a LineString, appending the parts inside the rectangle to `out`.
+fn clip_linestring<const D: usize, N: CoordNum + FloatCore>(
+    coords: &[[N; D]],
+    rect: &Rect2<N>,
+    out: &mut MultiLineString<'_, [N; D]>,
+) {
+    let mut part: Vec<[N; D]> = Vec::new();
+    let mut flush = |part: &mut Vec<[N; D]>| {
+        if part.len() >= 2 {
+            out.add_linestring(part.drain(..));
+        }
+        part.clear();
+    };
+    for seg in coords.windows(2) {
+        let Some((p, q)) = clip_segment(seg[0], seg[1], rect) else {
+            flush(&mut part);
+            continue;
+        };
+        if p == q {
+            // touches the rectangle at a single point (or the segment has zero length)
+            continue;
+        }
+        if part.is_empty() || p != seg[0] {
+            flush(&mut part);
+            part.push(p);
+        }
+        part.push(q);
+        if q != seg[1] {
+            flush(&mut part);
+        }
+    }
+    flush(&mut part);
+}
+
+/// Clips a ring to the rectangle (Sutherland–Hodgman).
+///
+/// The result is written to `out`, which is left empty if the ring degenerates to zero area.
+fn clip_ring<const D: usize, N: CoordNum + FloatCore>(
+    ring: &[[N; D]],
+    rect: &Rect2<N>,
+    out: &mut Vec<[N; D]>,
+    tmp: &mut Vec<[N; D]>,
+) {
+    out.clear();
+    out.extend_from_slice(ring);
+    let edges = [
+        (0, rect.min()[0], true),
+        (0, rect.max()[0], false),
+        (1, rect.min()[1], true),
+        (1, rect.max()[1], false),
+    ];
+    for (axis, value, keep_greater) in edges {
+        if out.is_empty() {
+            break;
+        }
+        let inside = |c: &[N; D]| {
+            if keep_greater {
+                c[axis] >= value
+            } else {
+                c[axis] <= value
+            }
+        };
+        tmp.clear();
+        let mut prev = out[out.len() - 1];
+        for &cur in out.iter() {
+            match (inside(&prev), inside(&cur)) {
+                (true, true) => tmp.push(cur),
+                (false, true) => {
+                    tmp.push(intersect(&prev, &cur, axis, value));
+                    tmp.push(cur);
+                }
+                (true, false) => tmp.push(intersect(&prev, &cur, axis, value)),
+                (false, false) => {}
+            }
+            prev = cur;
+        }
+        core::mem::swap(out, tmp);
+    }
+    if out.len() < 3 || ring_area2(out) == N::zero() {
+        out.clear();
+    }
+}
+
+/// Expands the rectangle by `buffer` on all sides.
+fn buffered<N: CoordNum + FloatCore>(rect: &Rect2<N>, buffer: N) -> Rect2<N> {
+    let (min, max) = (rect.min(), rect.max());
+    Rect2::new(
+        [min[0] - buffer, min[1] - buffer],
+        [max[0] + buffer, max[1] + buffer],
+    )
+}
+
+/// Twice the signed area of a ring
+fn ring_area2<const D: usize, N: CoordNum + FloatCore>(ring: &[[N; D]]) -> N {
+    let mut prev = &ring[ring.len() - 1];
+    let mut area = N::zero();
+    for cur in ring {
+        area = area + (prev[0] * cur[1] - cur[0] * prev[1]);
+        prev = cur;
+    }
+    area
+}
+
+impl<const D: usize, N: CoordNum + FloatCore> LineString<'_, [N; D]> {
+    /// Clips the LineString to the rectangle, which may split it into multiple parts.
+    ///
+    /// Values other than X and Y (such as Z) are interpolated along the cut segments.
+    pub fn clip(&self, rect: &Rect2<N>) -> MultiLineString<'static, [N; D]> {
+        let mut mls = MultiLineString::new();
+        clip_linestring(self.raw_coords(), rect, &mut mls);
+        mls
+    }
+
+    /// Clips the LineString to the rectangle expanded by `buffer` on all sides.
+    ///
+    /// This is useful for clipping to a tile with a buffer around it.
+    pub fn clip_buffered(&self, rect: &Rect2<N>, buffer: N) -> MultiLineString<'static, [N; D]> {
+        self.clip(&buffered(rect, buffer))
+    }
+}
+
+impl<const D: usize, N: CoordNum + FloatCore> MultiLineString<'_, [N; D]> {
+    /// Clips the MultiLineString to the rectangle.
+    ///
+    /// Values other than X and Y (such as Z) are interpolated along the cut segments.
+    pub fn clip(&self, rect: &Rect2<N>) -> MultiLineString<'static, [N; D]> {
+        let mut mls = MultiLineString::new();
+        for ls in self {
+            clip_linestring(ls.raw_coords(), rect, &mut mls);
+        }
+        mls
+    }
+
+    /// Clips the MultiLineString to the rectangle expanded by `buffer` on all sides.
+    ///
+    /// This is useful for clipping to a tile with a buffer around it.
+    pub fn clip_buffered(&self, rect: &Rect2<N>, buffer: N) -> MultiLineString<'static, [N; D]> {
+        self.clip(&buffered(rect, buffer))
+    }
+}
+
+impl<const D: usize, N: CoordNum + FloatCore> Polygon<'_, [N; D]> {
+    /// Clips the Polygon to the rectangle.
+    ///
+    /// Each ring is clipped independently, so the result may contain edges running along
+    /// the rectangle boundary. Rings that fall outside the rectangle (or collapse onto its
+    /// boundary) are dropped, and the result is empty if the exterior ring is. Values other than
+    /// X and Y (such as Z) are interpolated along the cut edges.
+    pub fn clip(&self, rect: &Rect2<N>) -> Polygon<'static, [N; D]> {
+        let mut poly = Polygon::new();
+        let (mut buf, mut tmp) = (Vec::new(), Vec::new());
+        for (i, ring) in self.rings().enumerate() {
+            clip_ring(ring.raw_coords(), rect, &mut buf, &mut tmp);
+            if !buf.is_empty() {
+                poly.add_ring(buf.drain(..));
+            } else if i == 0 {
+                break;
+            }
+        }
+        poly
+    }
+
+    /// Clips the Polygon to the rectangle expanded by `buffer` on all sides.
+    ///
+    /// This is useful for clipping to a tile with a buffer around it.
+    pub fn clip_buffered(&self, rect: &Rect2<N>, buffer: N) -> Polygon<'static, [N; D]> {
+        self.clip(&buffered(rect, buffer))
+    }
+}
+
+impl<const D: usize, N: CoordNum + FloatCore> MultiPolygon<'_, [N; D]> {
+    /// Clips the MultiPolygon to the rectangle.
+    ///
+    /// See [`Polygon::clip`] for details.
+    pub fn clip(&self, rect: &Rect2<N>) -> MultiPolygon<'static, [N; D]> {
+        let mut mpoly = MultiPolygon::new();
+        let (mut buf, mut tmp) = (Vec::new(), Vec::new());
+        for poly in self {
+            for (i, ring) in poly.rings().enumerate() {
+                clip_ring(ring.raw_coords(), rect, &mut buf, &mut tmp);
+                if buf.is_empty() {
+                    if i == 0 {
+                        break;
+                    }
+                } else if i == 0 {
+                    mpoly.add_exterior(buf.drain(..));
+                } else {
+                    mpoly.add_interior(buf.drain(..));
+                }
+            }
+        }
+        mpoly
+    }
+
+    /// Clips the MultiPolygon to the rectangle expanded by `buffer` on all sides.
+    ///
+    /// This is useful for clipping to a tile with a buffer around it.
+    pub fn clip_buffered(&self, rect: &Rect2<N>, buffer: N) -> MultiPolygon<'static, [N; D]> {
+        self.clip(&buffered(rect, buffer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, LineString3, MultiPolygon2, Polygon2, Polygon3};
+    use alloc::vec;
+
+    fn rect() -> Rect2 {
+        Rect2::new([0., 0.], [10., 10.])
+    }
+
+    #[test]
+    fn test_clip_linestring() {
+        let ls = LineString2::from_raw(
+            vec![
+                [-5., 5.],
+                [5., 5.],
+                [5., 15.],
+                [8., 15.],
+                [8., 5.],
+                [9., 5.],
+            ]
+            .into(),
+        );
+        let mls = ls.clip(&rect());
+        assert_eq!(mls.len(), 2);
+        let parts: Vec<_> = mls.iter().map(|ls| ls.raw_coords().to_vec()).collect();
+        assert_eq!(parts[0], [[0., 5.], [5., 5.], [5., 10.]]);
+        assert_eq!(parts[1], [[8., 10.], [8., 5.], [9., 5.]]);
+
+        // entirely outside
+        let ls = LineString2::from_raw(vec![[-5., -5.], [-1., 20.]].into());
+        assert!(ls.clip(&rect()).is_empty());
+
+        // Z is interpolated
+        let ls = LineString3::from_raw(vec![[-10., 5., 0.], [20., 5., 30.]].into());
+        let mls = ls.clip(&rect());
+        assert_eq!(mls.raw_coords(), [[0., 5., 10.], [10., 5., 20.]]);
+    }
+
+    #[test]
+    fn test_clip_linestring_leaving_boundary() {
+        // starts on the boundary and leaves the rectangle
+        let ls = LineString2::from_raw(vec![[0., 5.], [-5., 5.]].into());
+        assert!(ls.clip(&rect()).is_empty());
+
+        let ls = LineString2::from_raw(vec![[5., 5.], [0., 5.], [-5., 5.]].into());
+        let mls = ls.clip(&rect());
+        assert_eq!(mls.len(), 1);
+        assert_eq!(mls.raw_coords(), [[5., 5.], [0., 5.]]);
+
+        // touches a corner only
+        let ls = LineString2::from_raw(vec![[-5., 5.], [5., 15.]].into());
+        assert!(ls.clip(&rect()).is_empty());
+    }
+
+    #[test]
+    fn test_clip_buffered() {
+        let ls = LineString2::from_raw(vec![[-5., 5.], [15., 5.]].into());
+        let mls = ls.clip_buffered(&rect(), 2.);
+        assert_eq!(mls.raw_coords(), [[-2., 5.], [12., 5.]]);
+
+        let mut poly = Polygon2::new();
+        poly.add_ring([[-5., -5.], [5., -5.], [5., 5.], [-5., 5.]]);
+        assert_eq!(poly.clip_buffered(&rect(), 1.).exterior().ring_area(), 36.);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&poly);
+        assert_eq!(
+            mpoly
+                .clip_buffered(&rect(), 1.)
+                .get(0)
+                .exterior()
+                .ring_area(),
+            36.
+        );
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[-5., -5.], [5., -5.], [5., 5.], [-5., 5.]]);
+        poly.add_ring([[-4., -4.], [-4., -3.], [-3., -3.], [-3., -4.]]); // outside
+        poly.add_ring([[1., 1.], [1., 2.], [2., 2.], [2., 1.]]);
+        let clipped = poly.clip(&rect());
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(clipped.exterior().ring_area(), 25.);
+        assert_eq!(clipped.hole_indices(), [clipped.exterior().len() as u32]);
+
+        let mut poly = Polygon2::new();
+        poly.add_ring([[20., 20.], [30., 20.], [30., 30.]]);
+        poly.add_ring([[21., 21.], [22., 21.], [22., 22.]]);
+        assert!(poly.clip(&rect()).raw_coords().is_empty());
+
+        // Z is interpolated
+        let mut poly = Polygon3::new();
+        poly.add_ring([[5., 0., 0.], [15., 0., 10.], [15., 10., 10.], [5., 10., 0.]]);
+        let clipped = poly.clip(&rect());
+        assert_eq!(clipped.exterior().len(), 4);
+        for c in clipped.raw_coords() {
+            assert_eq!(c[2], c[0] - 5.);
+        }
+    }
+
+    #[test]
+    fn test_clip_multipolygon() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[-5., -5.], [20., -5.], [20., 20.], [-5., 20.]]);
+        mpoly.add_interior([[2., 2.], [2., 4.], [4., 4.], [4., 2.]]);
+        mpoly.add_exterior([[30., 30.], [40., 30.], [40., 40.]]);
+        mpoly.add_exterior([[8., 8.], [12., 8.], [12., 12.], [8., 12.]]);
+        let clipped = mpoly.clip(&rect());
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(clipped.get(0).exterior().ring_area(), 100.);
+        assert_eq!(clipped.get(0).interiors().count(), 1);
+        assert_eq!(clipped.get(1).exterior().ring_area(), 4.);
+    }
+}
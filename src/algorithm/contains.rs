@@ -0,0 +1,321 @@
+use crate::{Coord2d, LineString, MultiPolygon, Polygon};
+
+/// Rule deciding which regions enclosed by rings are inside a polygon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the rings an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A point is inside if the rings wind around it a non-zero number of times.
+    NonZero,
+}
+
+/// Location of a point relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointLocation {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Cross product of `b - a` and `c - a`
+pub(super) fn orient(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Returns `true` if `p`, known to be collinear with `a`-`b`, lies within the segment.
+pub(super) fn within_segment(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> bool {
+    a.0.min(b.0) <= p.0 && p.0 <= a.0.max(b.0) && a.1.min(b.1) <= p.1 && p.1 <= a.1.max(b.1)
+}
+
+pub(super) fn segments_intersect(
+    a: (f64, f64),
+    b: (f64, f64),
+    c: (f64, f64),
+    d: (f64, f64),
+) -> bool {
+    let (o1, o2) = (orient(a, b, c), orient(a, b, d));
+    let (o3, o4) = (orient(c, d, a), orient(c, d, b));
+    if ((o1 > 0.0 && o2 < 0.0) || (o1 < 0.0 && o2 > 0.0))
+        && ((o3 > 0.0 && o4 < 0.0) || (o3 < 0.0 && o4 > 0.0))
+    {
+        return true;
+    }
+    (o1 == 0.0 && within_segment(c, a, b))
+        || (o2 == 0.0 && within_segment(d, a, b))
+        || (o3 == 0.0 && within_segment(a, c, d))
+        || (o4 == 0.0 && within_segment(b, c, d))
+}
+
+/// Even-odd location of `p` relative to a closed ring, or `None` if `p` is on the ring.
+pub(super) fn locate_in_ring(ring: &[(f64, f64)], p: (f64, f64)) -> Option<bool> {
+    let mut inside = false;
+    let mut prev = *ring.last()?;
+    for &cur in ring {
+        let (a, b) = (prev, cur);
+        prev = cur;
+        if orient(a, b, p) == 0.0 && within_segment(p, a, b) {
+            return None;
+        }
+        if (a.1 > p.1) != (b.1 > p.1) && p.0 < a.0 + (p.1 - a.1) * (b.0 - a.0) / (b.1 - a.1) {
+            inside = !inside;
+        }
+    }
+    Some(inside)
+}
+
+/// Iterates over the segments of a path
+fn segments<T: Coord2d>(
+    mut coords: impl Iterator<Item = T>,
+) -> impl Iterator<Item = ((f64, f64), (f64, f64))> {
+    let mut prev = coords.next().map(|c| c.xy());
+    coords.map(move |c| {
+        let xy = c.xy();
+        let seg = (prev.unwrap(), xy);
+        prev = Some(xy);
+        seg
+    })
+}
+
+/// Winding number and crossing count of the rings around `p`, or `None` if `p` is on a ring.
+fn winding<'a, T: Coord2d + 'a>(
+    rings: impl Iterator<Item = LineString<'a, T>>,
+    p: (f64, f64),
+) -> Option<(i32, u32)> {
+    let (mut winding, mut crossings) = (0, 0);
+    for ring in rings {
+        for (a, b) in segments(ring.iter_closed()) {
+            let o = orient(a, b, p);
+            if o == 0.0 && within_segment(p, a, b) {
+                return None;
+            }
+            if a.1 <= p.1 {
+                if b.1 > p.1 && o > 0.0 {
+                    winding += 1;
+                    crossings += 1;
+                }
+            } else if b.1 <= p.1 && o < 0.0 {
+                winding -= 1;
+                crossings += 1;
+            }
+        }
+    }
+    Some((winding, crossings))
+}
+
+// 2-dimensional only
+impl<T: Coord2d> LineString<'_, T> {
+    /// Returns `true` if the LineStrings share at least one point.
+    pub fn intersects_linestring(&self, other: &LineString<'_, T>) -> bool {
+        if self.len() == 1 || other.len() == 1 {
+            let (single, line) = if self.len() == 1 {
+                (self, other)
+            } else {
+                (other, self)
+            };
+            let p = single.iter().next().unwrap().xy();
+            return line.iter().any(|c| c.xy() == p)
+                || segments(line.iter())
+                    .any(|(a, b)| orient(a, b, p) == 0.0 && within_segment(p, a, b));
+        }
+        segments(self.iter())
+            .any(|(a, b)| segments(other.iter()).any(|(c, d)| segments_intersect(a, b, c, d)))
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Determines whether the point is inside, outside, or on the boundary of the Polygon.
+    pub fn locate_point(&self, point: &T, rule: FillRule) -> PointLocation {
+        if self.raw_coords().is_empty() {
+            return PointLocation::Outside;
+        }
+        match winding(self.rings(), point.xy()) {
+            None => PointLocation::Boundary,
+            Some((winding, crossings)) => {
+                let inside = match rule {
+                    FillRule::EvenOdd => crossings % 2 == 1,
+                    FillRule::NonZero => winding != 0,
+                };
+                match inside {
+                    true => PointLocation::Inside,
+                    false => PointLocation::Outside,
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the point lies in the interior of the Polygon (not on its boundary).
+    pub fn contains_point(&self, point: &T, rule: FillRule) -> bool {
+        self.locate_point(point, rule) == PointLocation::Inside
+    }
+
+    /// Returns `true` if the point lies in the interior or on the boundary of the Polygon.
+    pub fn intersects_point(&self, point: &T, rule: FillRule) -> bool {
+        self.locate_point(point, rule) != PointLocation::Outside
+    }
+
+    /// Returns `true` if the LineString touches or crosses the Polygon (using the even-odd rule).
+    pub fn intersects_linestring(&self, ls: &LineString<'_, T>) -> bool {
+        if self.raw_coords().is_empty() || ls.is_empty() {
+            return false;
+        }
+        if self.intersects_point(&ls.iter().next().unwrap(), FillRule::EvenOdd) {
+            return true;
+        }
+        self.rings().any(|ring| {
+            segments(ring.iter_closed())
+                .any(|(a, b)| segments(ls.iter()).any(|(c, d)| segments_intersect(a, b, c, d)))
+        })
+    }
+
+    /// Returns `true` if the Polygons share at least one point (using the even-odd rule).
+    pub fn intersects_polygon(&self, other: &Polygon<'_, T>) -> bool {
+        if self.raw_coords().is_empty() || other.raw_coords().is_empty() {
+            return false;
+        }
+        // one polygon lies in the other, or their boundaries intersect
+        self.intersects_point(&other.raw_coords()[0], FillRule::EvenOdd)
+            || other.intersects_point(&self.raw_coords()[0], FillRule::EvenOdd)
+            || self.rings().any(|ring| {
+                let ring = LineString::from_raw(ring.iter_closed().collect());
+                other.intersects_linestring(&ring)
+            })
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Determines whether the point is inside, outside, or on the boundary of the MultiPolygon.
+    ///
+    /// The rule is applied to each Polygon separately, and the point is inside if it is inside any of them.
+    pub fn locate_point(&self, point: &T, rule: FillRule) -> PointLocation {
+        let mut location = PointLocation::Outside;
+        for poly in self {
+            match poly.locate_point(point, rule) {
+                PointLocation::Inside => return PointLocation::Inside,
+                PointLocation::Boundary => location = PointLocation::Boundary,
+                PointLocation::Outside => {}
+            }
+        }
+        location
+    }
+
+    /// Returns `true` if the point lies in the interior of the MultiPolygon (not on its boundary).
+    pub fn contains_point(&self, point: &T, rule: FillRule) -> bool {
+        self.locate_point(point, rule) == PointLocation::Inside
+    }
+
+    /// Returns `true` if the point lies in the interior or on the boundary of the MultiPolygon.
+    pub fn intersects_point(&self, point: &T, rule: FillRule) -> bool {
+        self.locate_point(point, rule) != PointLocation::Outside
+    }
+
+    /// Returns `true` if the LineString touches or crosses any of the Polygons.
+    pub fn intersects_linestring(&self, ls: &LineString<'_, T>) -> bool {
+        self.iter().any(|poly| poly.intersects_linestring(ls))
+    }
+
+    /// Returns `true` if the Polygon shares at least one point with any of the Polygons.
+    pub fn intersects_polygon(&self, other: &Polygon<'_, T>) -> bool {
+        self.iter().any(|poly| poly.intersects_polygon(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, MultiPolygon2, Polygon2};
+    use alloc::{vec, vec::Vec};
+
+    fn square_with_hole() -> Polygon2<'static> {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [10., 0.], [10., 10.], [0., 10.]]);
+        poly.add_ring([[3., 3.], [3., 7.], [7., 7.], [7., 3.]]);
+        poly
+    }
+
+    #[test]
+    fn test_locate_point() {
+        let poly = square_with_hole();
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            assert_eq!(poly.locate_point(&[1., 1.], rule), PointLocation::Inside);
+            assert_eq!(poly.locate_point(&[5., 5.], rule), PointLocation::Outside);
+            assert_eq!(poly.locate_point(&[11., 5.], rule), PointLocation::Outside);
+            assert_eq!(poly.locate_point(&[10., 5.], rule), PointLocation::Boundary);
+            assert_eq!(poly.locate_point(&[0., 0.], rule), PointLocation::Boundary);
+            assert_eq!(poly.locate_point(&[5., 3.], rule), PointLocation::Boundary);
+            // ray passing through a vertex
+            assert_eq!(poly.locate_point(&[-1., 10.], rule), PointLocation::Outside);
+            assert_eq!(poly.locate_point(&[2., 3.], rule), PointLocation::Inside);
+        }
+        assert!(poly.contains_point(&[1., 1.], FillRule::EvenOdd));
+        assert!(!poly.contains_point(&[0., 1.], FillRule::EvenOdd));
+        assert!(poly.intersects_point(&[0., 1.], FillRule::EvenOdd));
+        assert!(!Polygon2::new().contains_point(&[0., 0.], FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_fill_rules() {
+        // a hole with the same winding as the exterior
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [10., 0.], [10., 10.], [0., 10.]]);
+        poly.add_ring([[3., 3.], [7., 3.], [7., 7.], [3., 7.]]);
+        assert!(!poly.contains_point(&[5., 5.], FillRule::EvenOdd));
+        assert!(poly.contains_point(&[5., 5.], FillRule::NonZero));
+
+        // self-overlapping ring (pentagram)
+        let mut star = Polygon2::new();
+        star.add_ring([[0., 3.], [10., 3.], [2., -3.], [5., 7.], [8., -3.]]);
+        assert!(!star.contains_point(&[5., 2.], FillRule::EvenOdd));
+        assert!(star.contains_point(&[5., 2.], FillRule::NonZero));
+    }
+
+    #[test]
+    fn test_multipolygon_locate_point() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&square_with_hole());
+        mpoly.add_exterior([[4., 4.], [6., 4.], [6., 6.], [4., 6.]]);
+        assert!(mpoly.contains_point(&[1., 1.], FillRule::EvenOdd));
+        assert!(mpoly.contains_point(&[5., 5.], FillRule::EvenOdd));
+        assert!(!mpoly.contains_point(&[3.5, 3.5], FillRule::EvenOdd));
+        assert_eq!(
+            mpoly.locate_point(&[3., 5.], FillRule::EvenOdd),
+            PointLocation::Boundary
+        );
+    }
+
+    #[test]
+    fn test_intersects() {
+        let poly = square_with_hole();
+        let ls = |coords: Vec<[f64; 2]>| LineString2::from_raw(coords.into());
+
+        assert!(poly.intersects_linestring(&ls(vec![[1., 1.], [2., 2.]])));
+        assert!(poly.intersects_linestring(&ls(vec![[-5., 5.], [15., 5.]])));
+        assert!(poly.intersects_linestring(&ls(vec![[-5., 10.], [0., 10.]])));
+        assert!(!poly.intersects_linestring(&ls(vec![[4., 4.], [6., 6.]])));
+        assert!(!poly.intersects_linestring(&ls(vec![[-5., -5.], [-1., 20.]])));
+
+        assert!(ls(vec![[0., 0.], [2., 2.]]).intersects_linestring(&ls(vec![[0., 2.], [2., 0.]])));
+        assert!(ls(vec![[0., 0.], [2., 0.]]).intersects_linestring(&ls(vec![[1., 0.], [3., 0.]])));
+        assert!(!ls(vec![[0., 0.], [2., 0.]]).intersects_linestring(&ls(vec![[0., 1.], [2., 1.]])));
+        assert!(ls(vec![[0., 0.], [2., 0.]]).intersects_linestring(&ls(vec![[1., 0.]])));
+
+        let mut inner = Polygon2::new();
+        inner.add_ring([[4., 4.], [6., 4.], [6., 6.]]);
+        assert!(!poly.intersects_polygon(&inner));
+        let mut outer = Polygon2::new();
+        outer.add_ring([[-5., -5.], [20., -5.], [20., 20.], [-5., 20.]]);
+        assert!(poly.intersects_polygon(&outer));
+        assert!(outer.intersects_polygon(&poly));
+        let mut crossing = Polygon2::new();
+        crossing.add_ring([[-1., 5.], [5., -1.], [5., 5.]]);
+        assert!(poly.intersects_polygon(&crossing));
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&inner);
+        mpoly.push(&crossing);
+        assert!(mpoly.intersects_polygon(&poly));
+        assert!(mpoly.intersects_linestring(&ls(vec![[5., 4.5], [5.5, 4.5]])));
+    }
+}
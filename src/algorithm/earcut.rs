@@ -0,0 +1,782 @@
+//! Polygon triangulation based on the ear clipping algorithm of [mapbox/earcut](https://github.com/mapbox/earcut).
+//!
+//! Rings are stored as circular doubly linked lists of nodes in a single arena `Vec`,
+//! so that the flat `coords` + `hole_indices` layout can be consumed without any copying
+//! other than the node array itself.
+
+use alloc::vec::Vec;
+
+const NIL: usize = usize::MAX;
+
+struct Node {
+    /// vertex index in the input coordinates
+    i: u32,
+    x: f64,
+    y: f64,
+    prev: usize,
+    next: usize,
+    /// z-order curve value
+    z: u32,
+    prev_z: usize,
+    next_z: usize,
+    /// indicates whether this is a steiner point
+    steiner: bool,
+}
+
+struct Earcut<'a> {
+    coords: &'a [[f64; 2]],
+    nodes: Vec<Node>,
+    min_x: f64,
+    min_y: f64,
+    inv_size: f64,
+}
+
+/// Triangulates a polygon given as a flat list of vertices and `hole_indices`.
+///
+/// Appends vertex indices (three per triangle, offset by `offset`) to `triangles`.
+pub(crate) fn earcut(
+    coords: &[[f64; 2]],
+    hole_indices: &[u32],
+    offset: u32,
+    triangles: &mut Vec<u32>,
+) {
+    let mut ec = Earcut {
+        coords,
+        nodes: Vec::with_capacity(coords.len() * 3 / 2),
+        min_x: 0.,
+        min_y: 0.,
+        inv_size: 0.,
+    };
+    let outer_len = hole_indices.first().map_or(coords.len(), |&i| i as usize);
+
+    let Some(mut outer_node) = ec.linked_list(0, outer_len, true) else {
+        return;
+    };
+    if ec.nodes[outer_node].next == ec.nodes[outer_node].prev {
+        return;
+    }
+
+    if !hole_indices.is_empty() {
+        outer_node = ec.eliminate_holes(hole_indices, outer_node);
+    }
+
+    // if the shape is not too simple, we'll use z-order curve hash later
+    if coords.len() > 80 {
+        let [mut max_x, mut max_y] = coords[0];
+        [ec.min_x, ec.min_y] = coords[0];
+        for &[x, y] in &coords[1..outer_len] {
+            ec.min_x = ec.min_x.min(x);
+            ec.min_y = ec.min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+        // min_x, min_y and inv_size are later used to transform coords into integers for z-order calculation
+        let size = (max_x - ec.min_x).max(max_y - ec.min_y);
+        ec.inv_size = if size != 0. { 32767. / size } else { 0. };
+    }
+
+    let start = triangles.len();
+    ec.earcut_linked(Some(outer_node), triangles, 0);
+    if offset != 0 {
+        triangles[start..].iter_mut().for_each(|i| *i += offset);
+    }
+}
+
+impl Earcut<'_> {
+    /// create a circular doubly linked list from polygon points in the specified winding order
+    fn linked_list(&mut self, start: usize, end: usize, clockwise: bool) -> Option<usize> {
+        let mut last = None;
+        if clockwise == (signed_area(&self.coords[start..end]) > 0.) {
+            for i in start..end {
+                last = Some(self.insert_node(i as u32, last));
+            }
+        } else {
+            for i in (start..end).rev() {
+                last = Some(self.insert_node(i as u32, last));
+            }
+        }
+        if let Some(l) = last {
+            let next = self.nodes[l].next;
+            if self.equals(l, next) {
+                self.remove_node(l);
+                last = Some(next);
+            }
+        }
+        last
+    }
+
+    /// eliminate colinear or duplicate points
+    fn filter_points(&mut self, start: usize, end: Option<usize>) -> usize {
+        let mut end = end.unwrap_or(start);
+        let mut p = start;
+        loop {
+            let mut again = false;
+            let (prev, next) = (self.nodes[p].prev, self.nodes[p].next);
+            if !self.nodes[p].steiner && (self.equals(p, next) || self.area(prev, p, next) == 0.) {
+                self.remove_node(p);
+                p = prev;
+                end = prev;
+                if p == self.nodes[p].next {
+                    break;
+                }
+                again = true;
+            } else {
+                p = next;
+            }
+            if !again && p == end {
+                break;
+            }
+        }
+        end
+    }
+
+    /// main ear slicing loop which triangulates a polygon (given as a linked list)
+    fn earcut_linked(&mut self, ear: Option<usize>, triangles: &mut Vec<u32>, pass: usize) {
+        let Some(mut ear) = ear else {
+            return;
+        };
+
+        // interlink polygon nodes in z-order
+        if pass == 0 && self.inv_size != 0. {
+            self.index_curve(ear);
+        }
+
+        let mut stop = ear;
+
+        // iterate through ears, slicing them one by one
+        while self.nodes[ear].prev != self.nodes[ear].next {
+            let (prev, next) = (self.nodes[ear].prev, self.nodes[ear].next);
+
+            let is_ear = if self.inv_size != 0. {
+                self.is_ear_hashed(ear)
+            } else {
+                self.is_ear(ear)
+            };
+            if is_ear {
+                // cut off the triangle
+                triangles.extend([self.nodes[prev].i, self.nodes[ear].i, self.nodes[next].i]);
+                self.remove_node(ear);
+
+                // skipping the next vertex leads to less sliver triangles
+                ear = self.nodes[next].next;
+                stop = ear;
+                continue;
+            }
+
+            ear = next;
+
+            // if we looped through the whole remaining polygon and can't find any more ears
+            if ear == stop {
+                match pass {
+                    // try filtering points and slicing again
+                    0 => {
+                        let ear = self.filter_points(ear, None);
+                        self.earcut_linked(Some(ear), triangles, 1);
+                    }
+                    // if this didn't work, try curing all small self-intersections locally
+                    1 => {
+                        let filtered = self.filter_points(ear, None);
+                        let ear = self.cure_local_intersections(filtered, triangles);
+                        self.earcut_linked(Some(ear), triangles, 2);
+                    }
+                    // as a last resort, try splitting the remaining polygon into two
+                    _ => self.split_earcut(ear, triangles),
+                }
+                break;
+            }
+        }
+    }
+
+    /// check whether a polygon node forms a valid ear with adjacent nodes
+    fn is_ear(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.nodes[ear].prev, ear, self.nodes[ear].next);
+        if self.area(a, b, c) >= 0. {
+            return false; // reflex, can't be an ear
+        }
+
+        // now make sure we don't have other points inside the potential ear
+        let (a_n, b_n, c_n) = (&self.nodes[a], &self.nodes[b], &self.nodes[c]);
+        let (x0, x1) = min_max3(a_n.x, b_n.x, c_n.x);
+        let (y0, y1) = min_max3(a_n.y, b_n.y, c_n.y);
+
+        let mut p = c_n.next;
+        while p != a {
+            let p_n = &self.nodes[p];
+            if p_n.x >= x0
+                && p_n.x <= x1
+                && p_n.y >= y0
+                && p_n.y <= y1
+                && point_in_triangle(a_n.x, a_n.y, b_n.x, b_n.y, c_n.x, c_n.y, p_n.x, p_n.y)
+                && self.area(p_n.prev, p, p_n.next) >= 0.
+            {
+                return false;
+            }
+            p = p_n.next;
+        }
+        true
+    }
+
+    fn is_ear_hashed(&self, ear: usize) -> bool {
+        let (a, b, c) = (self.nodes[ear].prev, ear, self.nodes[ear].next);
+        if self.area(a, b, c) >= 0. {
+            return false; // reflex, can't be an ear
+        }
+
+        let (a_n, b_n, c_n) = (&self.nodes[a], &self.nodes[b], &self.nodes[c]);
+        // triangle bbox
+        let (x0, x1) = min_max3(a_n.x, b_n.x, c_n.x);
+        let (y0, y1) = min_max3(a_n.y, b_n.y, c_n.y);
+
+        // z-order range for the current triangle bbox
+        let min_z = self.z_order(x0, y0);
+        let max_z = self.z_order(x1, y1);
+
+        let blocks = |p: usize| {
+            let p_n = &self.nodes[p];
+            p_n.x >= x0
+                && p_n.x <= x1
+                && p_n.y >= y0
+                && p_n.y <= y1
+                && p != a
+                && p != c
+                && point_in_triangle(a_n.x, a_n.y, b_n.x, b_n.y, c_n.x, c_n.y, p_n.x, p_n.y)
+                && self.area(p_n.prev, p, p_n.next) >= 0.
+        };
+
+        let mut p = self.nodes[ear].prev_z;
+        let mut n = self.nodes[ear].next_z;
+
+        // look for points inside the triangle in both directions
+        while p != NIL && self.nodes[p].z >= min_z && n != NIL && self.nodes[n].z <= max_z {
+            if blocks(p) {
+                return false;
+            }
+            p = self.nodes[p].prev_z;
+            if blocks(n) {
+                return false;
+            }
+            n = self.nodes[n].next_z;
+        }
+
+        // look for remaining points in decreasing z-order
+        while p != NIL && self.nodes[p].z >= min_z {
+            if blocks(p) {
+                return false;
+            }
+            p = self.nodes[p].prev_z;
+        }
+
+        // look for remaining points in increasing z-order
+        while n != NIL && self.nodes[n].z <= max_z {
+            if blocks(n) {
+                return false;
+            }
+            n = self.nodes[n].next_z;
+        }
+        true
+    }
+
+    /// go through all polygon nodes and cure small local self-intersections
+    fn cure_local_intersections(&mut self, mut start: usize, triangles: &mut Vec<u32>) -> usize {
+        let mut p = start;
+        loop {
+            let a = self.nodes[p].prev;
+            let p_next = self.nodes[p].next;
+            let b = self.nodes[p_next].next;
+
+            if !self.equals(a, b)
+                && self.intersects(a, p, p_next, b)
+                && self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+            {
+                triangles.extend([self.nodes[a].i, self.nodes[p].i, self.nodes[b].i]);
+
+                // remove two nodes involved
+                self.remove_node(p);
+                self.remove_node(p_next);
+                p = b;
+                start = b;
+            }
+            p = self.nodes[p].next;
+            if p == start {
+                break;
+            }
+        }
+        self.filter_points(p, None)
+    }
+
+    /// try splitting polygon into two and triangulate them independently
+    fn split_earcut(&mut self, start: usize, triangles: &mut Vec<u32>) {
+        // look for a valid diagonal that divides the polygon into two
+        let mut a = start;
+        loop {
+            let mut b = self.nodes[self.nodes[a].next].next;
+            while b != self.nodes[a].prev {
+                if self.nodes[a].i != self.nodes[b].i && self.is_valid_diagonal(a, b) {
+                    // split the polygon in two by the diagonal
+                    let c = self.split_polygon(a, b);
+
+                    // filter colinear points around the cuts
+                    let a = self.filter_points(a, Some(self.nodes[a].next));
+                    let c = self.filter_points(c, Some(self.nodes[c].next));
+
+                    // run earcut on each half
+                    self.earcut_linked(Some(a), triangles, 0);
+                    self.earcut_linked(Some(c), triangles, 0);
+                    return;
+                }
+                b = self.nodes[b].next;
+            }
+            a = self.nodes[a].next;
+            if a == start {
+                break;
+            }
+        }
+    }
+
+    /// link every hole into the outer loop, producing a single-ring polygon without holes
+    fn eliminate_holes(&mut self, hole_indices: &[u32], mut outer_node: usize) -> usize {
+        let mut queue = Vec::with_capacity(hole_indices.len());
+        for (i, &start) in hole_indices.iter().enumerate() {
+            let end = hole_indices
+                .get(i + 1)
+                .map_or(self.coords.len(), |&e| e as usize);
+            if let Some(list) = self.linked_list(start as usize, end, false) {
+                if list == self.nodes[list].next {
+                    self.nodes[list].steiner = true;
+                }
+                queue.push(self.get_leftmost(list));
+            }
+        }
+
+        queue.sort_by(|&a, &b| {
+            let (a, b) = (&self.nodes[a], &self.nodes[b]);
+            a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+        });
+
+        // process holes from left to right
+        for hole in queue {
+            outer_node = self.eliminate_hole(hole, outer_node);
+        }
+        outer_node
+    }
+
+    /// find a bridge between vertices that connects hole with an outer ring and link it
+    fn eliminate_hole(&mut self, hole: usize, outer_node: usize) -> usize {
+        let Some(bridge) = self.find_hole_bridge(hole, outer_node) else {
+            return outer_node;
+        };
+        let bridge_reverse = self.split_polygon(bridge, hole);
+
+        // filter collinear points around the cuts
+        self.filter_points(bridge_reverse, Some(self.nodes[bridge_reverse].next));
+        self.filter_points(bridge, Some(self.nodes[bridge].next))
+    }
+
+    /// David Eberly's algorithm for finding a bridge between hole and outer polygon
+    fn find_hole_bridge(&self, hole: usize, outer_node: usize) -> Option<usize> {
+        let mut p = outer_node;
+        let (hx, hy) = (self.nodes[hole].x, self.nodes[hole].y);
+        let mut qx = f64::NEG_INFINITY;
+        let mut m = None;
+
+        // find a segment intersected by a ray from the hole's leftmost point to the left;
+        // segment's endpoint with lesser x will be potential connection point
+        loop {
+            let (p_n, next_n) = (&self.nodes[p], &self.nodes[self.nodes[p].next]);
+            if hy <= p_n.y && hy >= next_n.y && next_n.y != p_n.y {
+                let x = p_n.x + (hy - p_n.y) * (next_n.x - p_n.x) / (next_n.y - p_n.y);
+                if x <= hx && x > qx {
+                    qx = x;
+                    let candidate = if p_n.x < next_n.x { p } else { p_n.next };
+                    if x == hx {
+                        // hole touches outer segment; pick leftmost endpoint
+                        return Some(candidate);
+                    }
+                    m = Some(candidate);
+                }
+            }
+            p = p_n.next;
+            if p == outer_node {
+                break;
+            }
+        }
+
+        let mut m = m?;
+
+        // look for points inside the triangle of hole point, segment intersection and endpoint;
+        // if there are no points found, we have a valid connection;
+        // otherwise choose the point of the minimum angle with the ray as connection point
+        let stop = m;
+        let (mx, my) = (self.nodes[m].x, self.nodes[m].y);
+        let mut tan_min = f64::INFINITY;
+        p = m;
+
+        loop {
+            let p_n = &self.nodes[p];
+            if hx >= p_n.x
+                && p_n.x >= mx
+                && hx != p_n.x
+                && point_in_triangle(
+                    if hy < my { hx } else { qx },
+                    hy,
+                    mx,
+                    my,
+                    if hy < my { qx } else { hx },
+                    hy,
+                    p_n.x,
+                    p_n.y,
+                )
+            {
+                let tan = (hy - p_n.y).abs() / (hx - p_n.x); // tangential
+                if self.locally_inside(p, hole)
+                    && (tan < tan_min
+                        || (tan == tan_min
+                            && (p_n.x > self.nodes[m].x
+                                || (p_n.x == self.nodes[m].x
+                                    && self.sector_contains_sector(m, p)))))
+                {
+                    m = p;
+                    tan_min = tan;
+                }
+            }
+            p = p_n.next;
+            if p == stop {
+                break;
+            }
+        }
+        Some(m)
+    }
+
+    /// whether sector in vertex m contains sector in vertex p in the same coordinates
+    fn sector_contains_sector(&self, m: usize, p: usize) -> bool {
+        self.area(self.nodes[m].prev, m, self.nodes[p].prev) < 0.
+            && self.area(self.nodes[p].next, m, self.nodes[m].next) < 0.
+    }
+
+    /// interlink polygon nodes in z-order
+    fn index_curve(&mut self, start: usize) {
+        let mut p = start;
+        loop {
+            self.nodes[p].z = self.z_order(self.nodes[p].x, self.nodes[p].y);
+            self.nodes[p].prev_z = self.nodes[p].prev;
+            self.nodes[p].next_z = self.nodes[p].next;
+            p = self.nodes[p].next;
+            if p == start {
+                break;
+            }
+        }
+        let prev_z = self.nodes[p].prev_z;
+        self.nodes[prev_z].next_z = NIL;
+        self.nodes[p].prev_z = NIL;
+        self.sort_linked(p);
+    }
+
+    /// Simon Tatham's linked list merge sort algorithm
+    /// <http://www.chiark.greenend.org.uk/~sgtatham/algorithms/listsort.html>
+    fn sort_linked(&mut self, mut list: usize) {
+        let mut in_size = 1;
+        loop {
+            let mut p = list;
+            list = NIL;
+            let mut tail = NIL;
+            let mut num_merges = 0;
+
+            while p != NIL {
+                num_merges += 1;
+                let mut q = p;
+                let mut p_size = 0;
+                for _ in 0..in_size {
+                    p_size += 1;
+                    q = self.nodes[q].next_z;
+                    if q == NIL {
+                        break;
+                    }
+                }
+                let mut q_size = in_size;
+
+                while p_size > 0 || (q_size > 0 && q != NIL) {
+                    let e;
+                    if p_size != 0
+                        && (q_size == 0 || q == NIL || self.nodes[p].z <= self.nodes[q].z)
+                    {
+                        e = p;
+                        p = self.nodes[p].next_z;
+                        p_size -= 1;
+                    } else {
+                        e = q;
+                        q = self.nodes[q].next_z;
+                        q_size -= 1;
+                    }
+
+                    if tail != NIL {
+                        self.nodes[tail].next_z = e;
+                    } else {
+                        list = e;
+                    }
+                    self.nodes[e].prev_z = tail;
+                    tail = e;
+                }
+                p = q;
+            }
+
+            self.nodes[tail].next_z = NIL;
+            in_size *= 2;
+            if num_merges <= 1 {
+                break;
+            }
+        }
+    }
+
+    /// z-order of a point given coords and inverse of the longer side of data bbox
+    fn z_order(&self, x: f64, y: f64) -> u32 {
+        // coords are transformed into non-negative 15-bit integer range
+        let mut x = ((x - self.min_x) * self.inv_size) as u32;
+        let mut y = ((y - self.min_y) * self.inv_size) as u32;
+
+        x = (x | (x << 8)) & 0x00FF00FF;
+        x = (x | (x << 4)) & 0x0F0F0F0F;
+        x = (x | (x << 2)) & 0x33333333;
+        x = (x | (x << 1)) & 0x55555555;
+
+        y = (y | (y << 8)) & 0x00FF00FF;
+        y = (y | (y << 4)) & 0x0F0F0F0F;
+        y = (y | (y << 2)) & 0x33333333;
+        y = (y | (y << 1)) & 0x55555555;
+
+        x | (y << 1)
+    }
+
+    /// find the leftmost node of a polygon ring
+    fn get_leftmost(&self, start: usize) -> usize {
+        let mut p = start;
+        let mut leftmost = start;
+        loop {
+            let (p_n, l_n) = (&self.nodes[p], &self.nodes[leftmost]);
+            if p_n.x < l_n.x || (p_n.x == l_n.x && p_n.y < l_n.y) {
+                leftmost = p;
+            }
+            p = p_n.next;
+            if p == start {
+                break;
+            }
+        }
+        leftmost
+    }
+
+    /// check if a diagonal between two polygon nodes is valid (lies in polygon interior)
+    fn is_valid_diagonal(&self, a: usize, b: usize) -> bool {
+        let (a_n, b_n) = (&self.nodes[a], &self.nodes[b]);
+        self.nodes[a_n.next].i != b_n.i
+            && self.nodes[a_n.prev].i != b_n.i
+            && !self.intersects_polygon(a, b)
+            && ((self.locally_inside(a, b)
+                && self.locally_inside(b, a)
+                && self.middle_inside(a, b)
+                && (self.area(a_n.prev, a, b_n.prev) != 0. || self.area(a, b_n.prev, b) != 0.))
+                || (self.equals(a, b)
+                    && self.area(a_n.prev, a, a_n.next) > 0.
+                    && self.area(b_n.prev, b, b_n.next) > 0.))
+    }
+
+    /// signed area of a triangle
+    fn area(&self, p: usize, q: usize, r: usize) -> f64 {
+        let (p, q, r) = (&self.nodes[p], &self.nodes[q], &self.nodes[r]);
+        (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y)
+    }
+
+    /// check if two points are equal
+    fn equals(&self, p1: usize, p2: usize) -> bool {
+        let (p1, p2) = (&self.nodes[p1], &self.nodes[p2]);
+        p1.x == p2.x && p1.y == p2.y
+    }
+
+    /// check if two segments intersect
+    fn intersects(&self, p1: usize, q1: usize, p2: usize, q2: usize) -> bool {
+        let o1 = sign(self.area(p1, q1, p2));
+        let o2 = sign(self.area(p1, q1, q2));
+        let o3 = sign(self.area(p2, q2, p1));
+        let o4 = sign(self.area(p2, q2, q1));
+
+        (o1 != o2 && o3 != o4) // general case
+            || (o1 == 0 && self.on_segment(p1, p2, q1)) // p1, q1 and p2 are collinear and p2 lies on p1q1
+            || (o2 == 0 && self.on_segment(p1, q2, q1)) // p1, q1 and q2 are collinear and q2 lies on p1q1
+            || (o3 == 0 && self.on_segment(p2, p1, q2)) // p2, q2 and p1 are collinear and p1 lies on p2q2
+            || (o4 == 0 && self.on_segment(p2, q1, q2)) // p2, q2 and q1 are collinear and q1 lies on p2q2
+    }
+
+    /// for collinear points p, q, r, check if point q lies on segment pr
+    fn on_segment(&self, p: usize, q: usize, r: usize) -> bool {
+        let (p, q, r) = (&self.nodes[p], &self.nodes[q], &self.nodes[r]);
+        q.x <= p.x.max(r.x) && q.x >= p.x.min(r.x) && q.y <= p.y.max(r.y) && q.y >= p.y.min(r.y)
+    }
+
+    /// check if a polygon diagonal intersects any polygon segments
+    fn intersects_polygon(&self, a: usize, b: usize) -> bool {
+        let (ai, bi) = (self.nodes[a].i, self.nodes[b].i);
+        let mut p = a;
+        loop {
+            let p_n = &self.nodes[p];
+            let next_i = self.nodes[p_n.next].i;
+            if p_n.i != ai
+                && next_i != ai
+                && p_n.i != bi
+                && next_i != bi
+                && self.intersects(p, p_n.next, a, b)
+            {
+                return true;
+            }
+            p = p_n.next;
+            if p == a {
+                break;
+            }
+        }
+        false
+    }
+
+    /// check if a polygon diagonal is locally inside the polygon
+    fn locally_inside(&self, a: usize, b: usize) -> bool {
+        let (prev, next) = (self.nodes[a].prev, self.nodes[a].next);
+        if self.area(prev, a, next) < 0. {
+            self.area(a, b, next) >= 0. && self.area(a, prev, b) >= 0.
+        } else {
+            self.area(a, b, prev) < 0. || self.area(a, next, b) < 0.
+        }
+    }
+
+    /// check if the middle point of a polygon diagonal is inside the polygon
+    fn middle_inside(&self, a: usize, b: usize) -> bool {
+        let mut p = a;
+        let mut inside = false;
+        let px = (self.nodes[a].x + self.nodes[b].x) / 2.;
+        let py = (self.nodes[a].y + self.nodes[b].y) / 2.;
+        loop {
+            let (p_n, next_n) = (&self.nodes[p], &self.nodes[self.nodes[p].next]);
+            if ((p_n.y > py) != (next_n.y > py))
+                && next_n.y != p_n.y
+                && (px < (next_n.x - p_n.x) * (py - p_n.y) / (next_n.y - p_n.y) + p_n.x)
+            {
+                inside = !inside;
+            }
+            p = p_n.next;
+            if p == a {
+                break;
+            }
+        }
+        inside
+    }
+
+    /// link two polygon vertices with a bridge; if the vertices belong to the same ring, it splits polygon into two;
+    /// if one belongs to the outer ring and another to a hole, it merges it into a single ring
+    fn split_polygon(&mut self, a: usize, b: usize) -> usize {
+        let a2 = self.new_node(self.nodes[a].i);
+        let b2 = self.new_node(self.nodes[b].i);
+        let an = self.nodes[a].next;
+        let bp = self.nodes[b].prev;
+
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+
+        self.nodes[a2].next = an;
+        self.nodes[an].prev = a2;
+
+        self.nodes[b2].next = a2;
+        self.nodes[a2].prev = b2;
+
+        self.nodes[bp].next = b2;
+        self.nodes[b2].prev = bp;
+
+        b2
+    }
+
+    fn new_node(&mut self, i: u32) -> usize {
+        let [x, y] = self.coords[i as usize];
+        let idx = self.nodes.len();
+        self.nodes.push(Node {
+            i,
+            x,
+            y,
+            prev: idx,
+            next: idx,
+            z: 0,
+            prev_z: NIL,
+            next_z: NIL,
+            steiner: false,
+        });
+        idx
+    }
+
+    /// create a node and optionally link it with previous one (in a circular doubly linked list)
+    fn insert_node(&mut self, i: u32, last: Option<usize>) -> usize {
+        let p = self.new_node(i);
+        if let Some(last) = last {
+            let last_next = self.nodes[last].next;
+            self.nodes[p].next = last_next;
+            self.nodes[p].prev = last;
+            self.nodes[last_next].prev = p;
+            self.nodes[last].next = p;
+        }
+        p
+    }
+
+    fn remove_node(&mut self, p: usize) {
+        let Node {
+            prev,
+            next,
+            prev_z,
+            next_z,
+            ..
+        } = self.nodes[p];
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+        if prev_z != NIL {
+            self.nodes[prev_z].next_z = next_z;
+        }
+        if next_z != NIL {
+            self.nodes[next_z].prev_z = prev_z;
+        }
+    }
+}
+
+fn min_max3(a: f64, b: f64, c: f64) -> (f64, f64) {
+    (a.min(b).min(c), a.max(b).max(c))
+}
+
+/// check if a point lies within a convex triangle
+#[allow(clippy::too_many_arguments)]
+fn point_in_triangle(
+    ax: f64,
+    ay: f64,
+    bx: f64,
+    by: f64,
+    cx: f64,
+    cy: f64,
+    px: f64,
+    py: f64,
+) -> bool {
+    (cx - px) * (ay - py) >= (ax - px) * (cy - py)
+        && (ax - px) * (by - py) >= (bx - px) * (ay - py)
+        && (bx - px) * (cy - py) >= (cx - px) * (by - py)
+}
+
+fn sign(v: f64) -> i8 {
+    if v > 0. {
+        1
+    } else if v < 0. {
+        -1
+    } else {
+        0
+    }
+}
+
+fn signed_area(coords: &[[f64; 2]]) -> f64 {
+    let Some(&[mut px, mut py]) = coords.last() else {
+        return 0.;
+    };
+    let mut sum = 0.;
+    for &[x, y] in coords {
+        sum += (px - x) * (y + py);
+        (px, py) = (x, y);
+    }
+    sum
+}
@@ -0,0 +1,455 @@
+use crate::{
+    projection::{EARTH_FLATTENING, EARTH_RADIUS},
+    Coord2d, Coord3d, Geometry, Line, LineString, MultiLineString, MultiPolygon, MultiTriangle,
+    Polygon, Triangle,
+};
+
+/// Mean radius of the earth in meters, used for spherical measures
+pub(crate) const MEAN_EARTH_RADIUS: f64 = 6371008.8;
+
+fn path_length<T: Coord2d>(mut coords: impl Iterator<Item = T>) -> f64 {
+    let Some(first) = coords.next() else {
+        return 0.0;
+    };
+    let mut prev = first.xy();
+    coords
+        .map(|c| {
+            let xy = c.xy();
+            let d = libm::hypot(xy.0 - prev.0, xy.1 - prev.1);
+            prev = xy;
+            d
+        })
+        .sum()
+}
+
+fn path_length_3d<T: Coord3d>(mut coords: impl Iterator<Item = T>) -> f64 {
+    let Some(first) = coords.next() else {
+        return 0.0;
+    };
+    let mut prev = first.xyz();
+    coords
+        .map(|c| {
+            let p = c.xyz();
+            let (dx, dy, dz) = (p.0 - prev.0, p.1 - prev.1, p.2 - prev.2);
+            prev = p;
+            libm::sqrt(dx * dx + dy * dy + dz * dz)
+        })
+        .sum()
+}
+
+/// Great-circle distance between two lon/lat coordinates in degrees
+fn haversine(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (a.1.to_radians(), b.1.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (b.0 - a.0).to_radians();
+    let h = libm::pow(libm::sin(dlat / 2.0), 2.0)
+        + libm::cos(lat1) * libm::cos(lat2) * libm::pow(libm::sin(dlon / 2.0), 2.0);
+    2.0 * MEAN_EARTH_RADIUS * libm::asin(libm::sqrt(h).min(1.0))
+}
+
+/// Geodesic distance between two lon/lat coordinates in degrees on the WGS84 ellipsoid
+/// (Vincenty's inverse formula). Returns `None` if the iteration does not converge,
+/// which happens for nearly antipodal points.
+fn vincenty(a: (f64, f64), b: (f64, f64)) -> Option<f64> {
+    let f = EARTH_FLATTENING;
+    let b_axis = EARTH_RADIUS * (1.0 - f);
+    let l = (b.0 - a.0).to_radians();
+    let u1 = libm::atan((1.0 - f) * libm::tan(a.1.to_radians()));
+    let u2 = libm::atan((1.0 - f) * libm::tan(b.1.to_radians()));
+    let (sin_u1, cos_u1) = libm::sincos(u1);
+    let (sin_u2, cos_u2) = libm::sincos(u2);
+
+    let mut lambda = l;
+    for _ in 0..200 {
+        let (sin_lambda, cos_lambda) = libm::sincos(lambda);
+        let sin_sigma = libm::hypot(
+            cos_u2 * sin_lambda,
+            cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda,
+        );
+        if sin_sigma == 0.0 {
+            // coincident points
+            return Some(0.0);
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = libm::atan2(sin_sigma, cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+        let cos_2sigma_m = match cos2_alpha {
+            0.0 => 0.0, // equatorial line
+            _ => cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha,
+        };
+        let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+        let prev = lambda;
+        lambda = l
+            + (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        if (lambda - prev).abs() < 1e-12 {
+            let u2 =
+                cos2_alpha * (EARTH_RADIUS * EARTH_RADIUS - b_axis * b_axis) / (b_axis * b_axis);
+            let big_a = 1.0 + u2 / 16384.0 * (4096.0 + u2 * (-768.0 + u2 * (320.0 - 175.0 * u2)));
+            let big_b = u2 / 1024.0 * (256.0 + u2 * (-128.0 + u2 * (74.0 - 47.0 * u2)));
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                            - big_b / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+            return Some(b_axis * big_a * (sigma - delta_sigma));
+        }
+    }
+    None
+}
+
+fn path_distance<T: Coord2d>(
+    mut coords: impl Iterator<Item = T>,
+    distance: impl Fn((f64, f64), (f64, f64)) -> f64,
+) -> f64 {
+    let Some(first) = coords.next() else {
+        return 0.0;
+    };
+    let mut prev = first.xy();
+    coords
+        .map(|c| {
+            let xy = c.xy();
+            let d = distance(prev, xy);
+            prev = xy;
+            d
+        })
+        .sum()
+}
+
+fn haversine_path<T: Coord2d>(coords: impl Iterator<Item = T>) -> f64 {
+    path_distance(coords, haversine)
+}
+
+fn vincenty_path<T: Coord2d>(coords: impl Iterator<Item = T>) -> f64 {
+    path_distance(coords, |a, b| {
+        vincenty(a, b).unwrap_or_else(|| haversine(a, b))
+    })
+}
+
+/// Area of a lon/lat ring on a sphere (Chamberlain & Duquette)
+fn spherical_ring_area<T: Coord2d>(ring: &LineString<'_, T>) -> f64 {
+    let n = ring.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let coords = ring.raw_coords();
+    let mut total = 0.0;
+    for i in 0..n {
+        let (lon_prev, _) = coords[(i + n - 1) % n].xy();
+        let (_, lat) = coords[i].xy();
+        let (lon_next, _) = coords[(i + 1) % n].xy();
+        total += (lon_next - lon_prev).to_radians() * libm::sin(lat.to_radians());
+    }
+    (total * MEAN_EARTH_RADIUS * MEAN_EARTH_RADIUS / 2.0).abs()
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Line<T> {
+    /// Returns the length of the Line.
+    pub fn length(&self) -> f64 {
+        path_length(self.iter())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> LineString<'_, T> {
+    /// Returns the length of the LineString.
+    pub fn length(&self) -> f64 {
+        path_length(self.iter())
+    }
+
+    /// Returns the great-circle length in meters of the LineString in lon/lat degrees,
+    /// using the haversine formula on a sphere with the mean earth radius.
+    pub fn haversine_length(&self) -> f64 {
+        haversine_path(self.iter())
+    }
+
+    /// Returns the geodesic length in meters of the LineString in lon/lat degrees,
+    /// using Vincenty's formula on the WGS84 ellipsoid.
+    ///
+    /// Falls back to the haversine formula for nearly antipodal points.
+    pub fn vincenty_length(&self) -> f64 {
+        vincenty_path(self.iter())
+    }
+
+    /// Returns the area in square meters of the LineString in lon/lat degrees as a ring,
+    /// on a sphere with the mean earth radius.
+    pub fn spherical_ring_area(&self) -> f64 {
+        spherical_ring_area(self)
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiLineString<'_, T> {
+    /// Returns the total length of the LineStrings.
+    pub fn length(&self) -> f64 {
+        self.iter().map(|ls| ls.length()).sum()
+    }
+
+    /// Returns the total great-circle length in meters. See [`LineString::haversine_length`].
+    pub fn haversine_length(&self) -> f64 {
+        self.iter().map(|ls| ls.haversine_length()).sum()
+    }
+
+    /// Returns the total geodesic length in meters. See [`LineString::vincenty_length`].
+    pub fn vincenty_length(&self) -> f64 {
+        self.iter().map(|ls| ls.vincenty_length()).sum()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Returns the total length of all rings.
+    pub fn perimeter(&self) -> f64 {
+        self.rings()
+            .map(|ring| path_length(ring.iter_closed()))
+            .sum()
+    }
+
+    /// Returns the total great-circle length in meters of all rings in lon/lat degrees.
+    pub fn haversine_perimeter(&self) -> f64 {
+        self.rings()
+            .map(|ring| haversine_path(ring.iter_closed()))
+            .sum()
+    }
+
+    /// Returns the total geodesic length in meters of all rings in lon/lat degrees.
+    pub fn vincenty_perimeter(&self) -> f64 {
+        self.rings()
+            .map(|ring| vincenty_path(ring.iter_closed()))
+            .sum()
+    }
+
+    /// Returns the area in square meters of the Polygon in lon/lat degrees,
+    /// on a sphere with the mean earth radius.
+    pub fn spherical_area(&self) -> f64 {
+        let mut rings = self.rings();
+        let Some(exterior) = rings.next() else {
+            return 0.0;
+        };
+        spherical_ring_area(&exterior) - rings.map(|r| spherical_ring_area(&r)).sum::<f64>()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Returns the total area of the Polygons.
+    pub fn area(&self) -> f64 {
+        self.iter().map(|poly| poly.area()).sum()
+    }
+
+    /// Returns the total length of all rings.
+    pub fn perimeter(&self) -> f64 {
+        self.iter().map(|poly| poly.perimeter()).sum()
+    }
+
+    /// Returns the total great-circle length in meters of all rings in lon/lat degrees.
+    pub fn haversine_perimeter(&self) -> f64 {
+        self.iter().map(|poly| poly.haversine_perimeter()).sum()
+    }
+
+    /// Returns the total geodesic length in meters of all rings in lon/lat degrees.
+    pub fn vincenty_perimeter(&self) -> f64 {
+        self.iter().map(|poly| poly.vincenty_perimeter()).sum()
+    }
+
+    /// Returns the total area in square meters of the Polygons in lon/lat degrees,
+    /// on a sphere with the mean earth radius.
+    pub fn spherical_area(&self) -> f64 {
+        self.iter().map(|poly| poly.spherical_area()).sum()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Triangle<T> {
+    /// Returns the area of the Triangle.
+    pub fn area(&self) -> f64 {
+        let [a, b, c] = [0, 1, 2].map(|i| self.raw_coords()[i].xy());
+        ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0
+    }
+
+    /// Returns the length of the boundary of the Triangle.
+    pub fn perimeter(&self) -> f64 {
+        path_length(self.iter_closed())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiTriangle<'_, T> {
+    /// Returns the total area of the Triangles.
+    pub fn area(&self) -> f64 {
+        self.iter().map(|tri| tri.area()).sum()
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Geometry<'_, T> {
+    /// Returns the total area of the areal parts (Polygons and Triangles) of the Geometry.
+    pub fn area(&self) -> f64 {
+        match self {
+            Geometry::Polygon(geom) => geom.area(),
+            Geometry::MultiPolygon(geom) => geom.area(),
+            Geometry::Triangle(geom) => geom.area(),
+            Geometry::MultiTriangle(geom) => geom.area(),
+            Geometry::GeometryCollection(geoms) => geoms.iter().map(|g| g.area()).sum(),
+            Geometry::Point(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::Line(_)
+            | Geometry::LineString(_)
+            | Geometry::MultiLineString(_) => 0.0,
+        }
+    }
+
+    /// Returns the total length of the linear parts (Lines and LineStrings) of the Geometry.
+    pub fn length(&self) -> f64 {
+        match self {
+            Geometry::Line(geom) => geom.length(),
+            Geometry::LineString(geom) => geom.length(),
+            Geometry::MultiLineString(geom) => geom.length(),
+            Geometry::GeometryCollection(geoms) => geoms.iter().map(|g| g.length()).sum(),
+            Geometry::Point(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::Polygon(_)
+            | Geometry::MultiPolygon(_)
+            | Geometry::Triangle(_)
+            | Geometry::MultiTriangle(_) => 0.0,
+        }
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> Line<T> {
+    /// Returns the length of the Line in 3D space.
+    pub fn length_3d(&self) -> f64 {
+        path_length_3d(self.iter())
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> LineString<'_, T> {
+    /// Returns the length of the LineString in 3D space.
+    pub fn length_3d(&self) -> f64 {
+        path_length_3d(self.iter())
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> MultiLineString<'_, T> {
+    /// Returns the total length of the LineStrings in 3D space.
+    pub fn length_3d(&self) -> f64 {
+        self.iter().map(|ls| ls.length_3d()).sum()
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> Polygon<'_, T> {
+    /// Returns the total length of all rings in 3D space.
+    pub fn perimeter_3d(&self) -> f64 {
+        self.rings()
+            .map(|ring| path_length_3d(ring.iter_closed()))
+            .sum()
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> MultiPolygon<'_, T> {
+    /// Returns the total length of all rings in 3D space.
+    pub fn perimeter_3d(&self) -> f64 {
+        self.iter().map(|poly| poly.perimeter_3d()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Geometry2, LineString2, LineString3, MultiLineString2, MultiPolygon2, MultiTriangle2,
+        Point, Polygon2, Polygon3,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn test_length() {
+        let ls = LineString2::from_raw(vec![[0., 0.], [3., 4.], [3., 0.]].into());
+        assert_eq!(ls.length(), 9.);
+        assert_eq!(LineString2::<f64>::new().length(), 0.);
+        assert_eq!(Line::new([0, 0], [0, 2]).length(), 2.);
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 0.]]);
+        mls.add_linestring([[0., 0.], [0., 2.]]);
+        assert_eq!(mls.length(), 3.);
+
+        let ls = LineString3::from_raw(vec![[0., 0., 0.], [1., 2., 2.], [1., 2., 5.]].into());
+        assert_eq!(ls.length_3d(), 6.);
+    }
+
+    #[test]
+    fn test_area_perimeter() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        poly.add_ring([[1., 1.], [1., 2.], [2., 2.], [2., 1.]]);
+        assert_eq!(poly.perimeter(), 20.);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&poly);
+        mpoly.add_exterior([[10., 0.], [12., 0.], [12., 1.]]);
+        assert_eq!(mpoly.area(), 16.);
+        assert_eq!(mpoly.perimeter(), 20. + 3. + libm::sqrt(5.));
+
+        let tri = Triangle::new([0., 0.], [2., 0.], [0., 2.]);
+        assert_eq!(tri.area(), 2.);
+        let mut mtri = MultiTriangle2::new();
+        mtri.push(&tri);
+        mtri.push(&tri);
+        assert_eq!(mtri.area(), 4.);
+
+        let geom = Geometry2::GeometryCollection(vec![
+            Geometry::MultiPolygon(mpoly),
+            Geometry::MultiTriangle(mtri),
+            Geometry::Point(Point::new([0., 0.])),
+            Geometry::Line(Line::new([0., 0.], [5., 0.])),
+        ]);
+        assert_eq!(geom.area(), 20.);
+        assert_eq!(geom.length(), 5.);
+
+        let mut poly = Polygon3::new();
+        poly.add_ring([[0., 0., 0.], [1., 0., 0.], [1., 0., 1.], [0., 0., 1.]]);
+        assert_eq!(poly.perimeter_3d(), 4.);
+    }
+
+    #[test]
+    fn test_geodesic() {
+        // Tokyo to Osaka
+        let ls = LineString2::from_raw(vec![[139.7671, 35.6812], [135.4959, 34.7024]].into());
+        assert!((ls.haversine_length() - 403_400.).abs() < 1000.);
+        assert!((ls.vincenty_length() - 403_900.).abs() < 1000.);
+
+        // one degree of longitude on the equator
+        let ls = LineString2::from_raw(vec![[0., 0.], [1., 0.]].into());
+        assert!((ls.vincenty_length() - 111_319.491).abs() < 0.01);
+        assert!((ls.haversine_length() - 111_195.08).abs() < 0.01);
+
+        // antipodal points fall back to haversine
+        let ls = LineString2::from_raw(vec![[0., 0.], [180., 0.5]].into());
+        assert!(ls.vincenty_length() > 19_900_000.);
+
+        // one degree square at the equator
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        let area = poly.spherical_area();
+        assert!((area - 1.2364e10).abs() < 1e8, "{}", area);
+        assert!(poly.haversine_perimeter() > 4. * 111_000.);
+        assert!(poly.vincenty_perimeter() > 4. * 110_000.);
+    }
+}
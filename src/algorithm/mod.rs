@@ -0,0 +1,15 @@
+mod bounding_rect;
+mod centroid;
+mod clip;
+mod contains;
+mod earcut;
+mod measure;
+mod quantize;
+mod repair;
+mod simplify;
+mod triangulate;
+mod validate;
+
+pub use contains::{FillRule, PointLocation};
+pub use quantize::Quantizer;
+pub use validate::ValidationError;
@@ -0,0 +1,411 @@
+use alloc::vec::Vec;
+
+use num_traits::float::FloatCore;
+
+use crate::{
+    Coord2d, Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point,
+    Polygon, Rect2,
+};
+
+/// Maps 2-dimensional coordinates between a bounding box and an integer grid.
+///
+/// The `min` corner of the bounding box is mapped to `[0, 0]` and the `max` corner to
+/// `[extent, extent]`. With [`Quantizer::flip_y`], the Y axis points down as in tile coordinates.
+///
+/// If the bounding box has zero width (or height), the X (or Y) axis is mapped with a scale of `1`
+/// relative to the bounding box, i.e. `min + 1` is mapped to `extent`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantizer {
+    bbox: Rect2,
+    extent: u32,
+    flip_y: bool,
+}
+
+impl Quantizer {
+    /// Create a new Quantizer mapping `bbox` onto the grid `0..=extent`.
+    pub fn new(bbox: Rect2, extent: u32) -> Self {
+        Self {
+            bbox,
+            extent,
+            flip_y: false,
+        }
+    }
+
+    /// Sets whether to flip the Y axis so that the top of the bounding box is mapped to `0`.
+    pub fn flip_y(mut self, flip: bool) -> Self {
+        self.flip_y = flip;
+        self
+    }
+
+    pub fn bbox(&self) -> &Rect2 {
+        &self.bbox
+    }
+
+    pub fn extent(&self) -> u32 {
+        self.extent
+    }
+
+    /// Maps a coordinate onto the integer grid, rounding to the nearest grid point.
+    pub fn quantize<T: Coord2d>(&self, coord: &T) -> [i32; 2] {
+        let (x, y) = coord.xy();
+        let [min_x, min_y] = *self.bbox.min();
+        let extent = self.extent as f64;
+        let [width, height] = self.size();
+        let tx = (x - min_x) / width;
+        let ty = (y - min_y) / height;
+        let ty = if self.flip_y { 1.0 - ty } else { ty };
+        [
+            FloatCore::round(tx * extent) as i32,
+            FloatCore::round(ty * extent) as i32,
+        ]
+    }
+
+    /// Maps a grid coordinate back into the bounding box.
+    pub fn dequantize<T: Coord2d>(&self, coord: &T) -> [f64; 2] {
+        let (x, y) = coord.xy();
+        let [min_x, min_y] = *self.bbox.min();
+        let extent = self.extent as f64;
+        let tx = x / extent;
+        let ty = y / extent;
+        let ty = if self.flip_y { 1.0 - ty } else { ty };
+        let [width, height] = self.size();
+        [min_x + tx * width, min_y + ty * height]
+    }
+
+    /// Width and height of the bounding box, where a zero extent is treated as `1`.
+    fn size(&self) -> [f64; 2] {
+        [self.bbox.width(), self.bbox.height()].map(|v| if v == 0. { 1. } else { v })
+    }
+
+    /// Quantizes a sequence of coordinates into `out`, skipping consecutive duplicates.
+    ///
+    /// If the result collapses into a point (or into zero area as a ring),
+    /// nothing is written and `false` is returned.
+    fn quantize_path<T: Coord2d>(
+        &self,
+        coords: impl IntoIterator<Item = T>,
+        ring: bool,
+        out: &mut Vec<[i32; 2]>,
+    ) -> bool {
+        let start = out.len();
+        for coord in coords {
+            let q = self.quantize(&coord);
+            if out.len() == start || out.last() != Some(&q) {
+                out.push(q);
+            }
+        }
+        if ring && out.len() - start > 1 && out.last() == out.get(start) {
+            out.pop();
+        }
+        let path = &out[start..];
+        let collapsed = if ring {
+            path.len() < 3 || ring_area2(path) == 0
+        } else {
+            path.len() < 2
+        };
+        if collapsed {
+            out.truncate(start);
+        }
+        !collapsed
+    }
+}
+
+/// Twice the signed area of a ring
+fn ring_area2(ring: &[[i32; 2]]) -> i128 {
+    let mut prev = ring[ring.len() - 1];
+    let mut area = 0;
+    for &[x1, y1] in ring {
+        let [x0, y0] = prev;
+        area += x0 as i128 * y1 as i128 - x1 as i128 * y0 as i128;
+        prev = [x1, y1];
+    }
+    area
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Point<T> {
+    /// Maps the Point onto the integer grid of the quantizer.
+    pub fn quantize(&self, q: &Quantizer) -> Point<[i32; 2]> {
+        Point::new(q.quantize(self.coord()))
+    }
+
+    /// Maps the Point from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> Point<[f64; 2]> {
+        Point::new(q.dequantize(self.coord()))
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPoint<'_, T> {
+    /// Maps the MultiPoint onto the integer grid of the quantizer.
+    pub fn quantize(&self, q: &Quantizer) -> MultiPoint<'static, [i32; 2]> {
+        let coords: Vec<_> = self.iter().map(|c| q.quantize(&c)).collect();
+        MultiPoint::from_raw(coords.into())
+    }
+
+    /// Maps the MultiPoint from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> MultiPoint<'static, [f64; 2]> {
+        let coords: Vec<_> = self.iter().map(|c| q.dequantize(&c)).collect();
+        MultiPoint::from_raw(coords.into())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> LineString<'_, T> {
+    /// Maps the LineString onto the integer grid of the quantizer.
+    ///
+    /// Consecutive duplicate vertices are removed, and the result is empty
+    /// if the LineString collapses into a single point.
+    pub fn quantize(&self, q: &Quantizer) -> LineString<'static, [i32; 2]> {
+        let mut coords = Vec::with_capacity(self.len());
+        q.quantize_path(self.iter(), false, &mut coords);
+        LineString::from_raw(coords.into())
+    }
+
+    /// Maps the LineString from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> LineString<'static, [f64; 2]> {
+        let coords: Vec<_> = self.iter().map(|c| q.dequantize(&c)).collect();
+        LineString::from_raw(coords.into())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiLineString<'_, T> {
+    /// Maps the MultiLineString onto the integer grid of the quantizer.
+    ///
+    /// Consecutive duplicate vertices are removed, and LineStrings that collapse
+    /// into a single point are dropped.
+    pub fn quantize(&self, q: &Quantizer) -> MultiLineString<'static, [i32; 2]> {
+        let mut mls = MultiLineString::new();
+        let mut buf = Vec::new();
+        for ls in self {
+            if q.quantize_path(ls.iter(), false, &mut buf) {
+                mls.add_linestring(buf.drain(..));
+            }
+        }
+        mls
+    }
+
+    /// Maps the MultiLineString from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> MultiLineString<'static, [f64; 2]> {
+        let mut mls = MultiLineString::new();
+        for ls in self {
+            mls.add_linestring(ls.iter().map(|c| q.dequantize(&c)));
+        }
+        mls
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Maps the Polygon onto the integer grid of the quantizer.
+    ///
+    /// Consecutive duplicate vertices are removed, and rings that collapse to zero area
+    /// are dropped. The result is empty if the exterior ring collapses.
+    pub fn quantize(&self, q: &Quantizer) -> Polygon<'static, [i32; 2]> {
+        let mut poly = Polygon::new();
+        let mut buf = Vec::new();
+        for (i, ring) in self.rings().enumerate() {
+            if q.quantize_path(ring.iter(), true, &mut buf) {
+                poly.add_ring(buf.drain(..));
+            } else if i == 0 {
+                break;
+            }
+        }
+        poly
+    }
+
+    /// Maps the Polygon from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> Polygon<'static, [f64; 2]> {
+        let coords: Vec<_> = self.raw_coords().iter().map(|c| q.dequantize(c)).collect();
+        Polygon::from_raw(coords.into(), self.hole_indices().to_vec().into())
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Maps the MultiPolygon onto the integer grid of the quantizer.
+    ///
+    /// Consecutive duplicate vertices are removed, rings that collapse to zero area
+    /// are dropped, and Polygons whose exterior ring collapses are dropped.
+    pub fn quantize(&self, q: &Quantizer) -> MultiPolygon<'static, [i32; 2]> {
+        let mut mpoly = MultiPolygon::new();
+        let mut buf = Vec::new();
+        for poly in self {
+            for (i, ring) in poly.rings().enumerate() {
+                if !q.quantize_path(ring.iter(), true, &mut buf) {
+                    if i == 0 {
+                        break;
+                    }
+                } else if i == 0 {
+                    mpoly.add_exterior(buf.drain(..));
+                } else {
+                    mpoly.add_interior(buf.drain(..));
+                }
+            }
+        }
+        mpoly
+    }
+
+    /// Maps the MultiPolygon from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> MultiPolygon<'static, [f64; 2]> {
+        let mut mpoly = MultiPolygon::new();
+        for poly in self {
+            mpoly.push(&poly.dequantize(q));
+        }
+        mpoly
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Geometry<'_, T> {
+    /// Maps the Geometry onto the integer grid of the quantizer.
+    ///
+    /// Lines and Triangles are mapped as they are, without removing degenerate vertices.
+    pub fn quantize(&self, q: &Quantizer) -> Geometry<'static, [i32; 2]> {
+        match self {
+            Geometry::Point(geom) => Geometry::Point(geom.quantize(q)),
+            Geometry::MultiPoint(geom) => Geometry::MultiPoint(geom.quantize(q)),
+            Geometry::Line(geom) => Geometry::Line(geom.transform(|c| q.quantize(c))),
+            Geometry::LineString(geom) => Geometry::LineString(geom.quantize(q)),
+            Geometry::MultiLineString(geom) => Geometry::MultiLineString(geom.quantize(q)),
+            Geometry::Polygon(geom) => Geometry::Polygon(geom.quantize(q)),
+            Geometry::MultiPolygon(geom) => Geometry::MultiPolygon(geom.quantize(q)),
+            Geometry::Triangle(geom) => Geometry::Triangle(geom.transform(|c| q.quantize(c))),
+            Geometry::MultiTriangle(geom) => {
+                Geometry::MultiTriangle(MultiTriangle::from_raw_unchecked(
+                    geom.raw_coords().iter().map(|c| q.quantize(c)).collect(),
+                    geom.raw_indices().to_vec().into(),
+                ))
+            }
+            Geometry::GeometryCollection(geoms) => {
+                Geometry::GeometryCollection(geoms.iter().map(|g| g.quantize(q)).collect())
+            }
+        }
+    }
+
+    /// Maps the Geometry from the integer grid back into the bounding box of the quantizer.
+    pub fn dequantize(&self, q: &Quantizer) -> Geometry<'static, [f64; 2]> {
+        match self {
+            Geometry::Point(geom) => Geometry::Point(geom.dequantize(q)),
+            Geometry::MultiPoint(geom) => Geometry::MultiPoint(geom.dequantize(q)),
+            Geometry::Line(geom) => Geometry::Line(geom.transform(|c| q.dequantize(c))),
+            Geometry::LineString(geom) => Geometry::LineString(geom.dequantize(q)),
+            Geometry::MultiLineString(geom) => Geometry::MultiLineString(geom.dequantize(q)),
+            Geometry::Polygon(geom) => Geometry::Polygon(geom.dequantize(q)),
+            Geometry::MultiPolygon(geom) => Geometry::MultiPolygon(geom.dequantize(q)),
+            Geometry::Triangle(geom) => Geometry::Triangle(geom.transform(|c| q.dequantize(c))),
+            Geometry::MultiTriangle(geom) => {
+                Geometry::MultiTriangle(MultiTriangle::from_raw_unchecked(
+                    geom.raw_coords().iter().map(|c| q.dequantize(c)).collect(),
+                    geom.raw_indices().to_vec().into(),
+                ))
+            }
+            Geometry::GeometryCollection(geoms) => {
+                Geometry::GeometryCollection(geoms.iter().map(|g| g.dequantize(q)).collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, MultiLineString2, MultiPolygon2, Polygon2};
+    use alloc::vec;
+
+    fn quantizer() -> Quantizer {
+        Quantizer::new(Rect2::new([0., 0.], [100., 100.]), 10)
+    }
+
+    #[test]
+    fn test_quantize_coord() {
+        let q = quantizer();
+        assert_eq!(q.quantize(&[0., 0.]), [0, 0]);
+        assert_eq!(q.quantize(&[100., 100.]), [10, 10]);
+        assert_eq!(q.quantize(&[14., 16.]), [1, 2]);
+        assert_eq!(q.quantize(&[-20., 120.]), [-2, 12]);
+        assert_eq!(q.dequantize(&[1, 2]), [10., 20.]);
+
+        let q = q.flip_y(true);
+        assert_eq!(q.quantize(&[0., 100.]), [0, 0]);
+        assert_eq!(q.quantize(&[100., 0.]), [10, 10]);
+        assert_eq!(q.dequantize(&[3, 2]), [30., 80.]);
+        assert_eq!(q.flip_y(true), q);
+        assert_eq!(q.flip_y(false), quantizer());
+    }
+
+    #[test]
+    fn test_quantize_zero_extent() {
+        // a bbox of a vertical line
+        let q = Quantizer::new(Rect2::new([5., 0.], [5., 100.]), 10);
+        assert_eq!(q.quantize(&[5., 50.]), [0, 5]);
+        assert_eq!(q.quantize(&[5.5, 50.]), [5, 5]);
+        assert_eq!(q.dequantize(&[5, 5]), [5.5, 50.]);
+
+        let q = Quantizer::new(Rect2::new([5., 5.], [5., 5.]), 10);
+        assert_eq!(q.quantize(&[5., 5.]), [0, 0]);
+        assert_eq!(q.dequantize(&[0, 0]), [5., 5.]);
+    }
+
+    #[test]
+    fn test_quantize_linestrings() {
+        let q = quantizer();
+        let ls =
+            LineString2::from_raw(vec![[0., 0.], [1., 1.], [12., 0.], [14., 1.], [20., 0.]].into());
+        assert_eq!(ls.quantize(&q).raw_coords(), [[0, 0], [1, 0], [2, 0]]);
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [30., 30.]]);
+        mls.add_linestring([[50., 50.], [51., 52.]]); // collapses
+        mls.add_linestring([[60., 60.], [70., 80.]]);
+        let qmls = mls.quantize(&q);
+        assert_eq!(qmls.len(), 2);
+        assert_eq!(qmls.raw_coords(), [[0, 0], [3, 3], [6, 6], [7, 8]]);
+
+        let dmls = qmls.dequantize(&q);
+        assert_eq!(dmls.raw_coords()[1], [30., 30.]);
+    }
+
+    #[test]
+    fn test_quantize_polygons() {
+        let q = quantizer();
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [100., 0.], [100., 100.], [0., 100.]]);
+        poly.add_ring([[10., 10.], [11., 10.], [11., 11.], [10., 11.]]); // collapses
+        poly.add_ring([[20., 20.], [20., 30.], [30., 30.], [31., 29.], [30., 20.]]);
+        let qpoly = poly.quantize(&q);
+        assert_eq!(qpoly.len(), 2);
+        assert_eq!(qpoly.hole_indices(), [4]);
+        assert_eq!(qpoly.raw_coords()[4..], [[2, 2], [2, 3], [3, 3], [3, 2]]);
+
+        let dpoly = qpoly.dequantize(&q);
+        assert_eq!(dpoly.hole_indices(), [4]);
+        assert_eq!(dpoly.raw_coords()[5], [20., 30.]);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [1., 0.], [1., 1.]]); // collapses with its hole
+        mpoly.add_interior([[0.2, 0.2], [0.3, 0.2], [0.3, 0.3]]);
+        mpoly.add_exterior([[0., 0.], [40., 0.], [40., 40.], [0., 40.], [0., 0.]]);
+        mpoly.add_interior([[10., 10.], [10., 30.], [30., 30.], [30., 10.]]);
+        mpoly.add_exterior([[50., 50.], [90., 50.], [90., 50.4]]); // zero area after rounding
+        let qmpoly = mpoly.quantize(&q);
+        assert_eq!(qmpoly.len(), 1);
+        assert_eq!(qmpoly.get(0).len(), 2);
+        assert_eq!(qmpoly.get(0).hole_indices(), [4]);
+    }
+
+    #[test]
+    fn test_quantize_saturated_polygon() {
+        // a polygon far outside the bbox saturates to the i32 range
+        let q = Quantizer::new(Rect2::new([0., 0.], [1., 1.]), 4096);
+        let mut poly = Polygon2::new();
+        poly.add_ring([[-1e7, -1e7], [1e7, -1e7], [1e7, 1e7], [-1e7, 1e7]]);
+        let qpoly = poly.quantize(&q);
+        let (min, max) = (i32::MIN, i32::MAX);
+        assert_eq!(
+            qpoly.raw_coords(),
+            [[min, min], [max, min], [max, max], [min, max]]
+        );
+    }
+}
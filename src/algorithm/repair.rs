@@ -0,0 +1,336 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::contains::{locate_in_ring, orient};
+use crate::{CoordNum, MultiPolygon, Polygon};
+
+fn xy<const D: usize, N: CoordNum>(c: &[N; D]) -> (f64, f64) {
+    (c[0].to_f64().unwrap(), c[1].to_f64().unwrap())
+}
+
+/// Returns `true` if the path `a`-`b`-`c` turns back onto itself at `b`.
+fn is_spike(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> bool {
+    orient(a, b, c) == 0.0 && (b.0 - a.0) * (c.0 - b.0) + (b.1 - a.1) * (c.1 - b.1) < 0.0
+}
+
+/// Removes repeated vertices (including an explicit closing point) and spikes from a ring.
+fn clean_ring<const D: usize, N: CoordNum + Copy>(coords: &[[N; D]]) -> Vec<[N; D]> {
+    let mut out: Vec<[N; D]> = Vec::with_capacity(coords.len());
+    for &c in coords {
+        let p = xy(&c);
+        if out.last().map(xy) == Some(p) {
+            continue;
+        }
+        while out.len() >= 2 && is_spike(xy(&out[out.len() - 2]), xy(&out[out.len() - 1]), p) {
+            out.pop();
+        }
+        if out.last().map(xy) != Some(p) {
+            out.push(c);
+        }
+    }
+    // the same around the start of the ring
+    while out.len() >= 3 {
+        let n = out.len();
+        let (first, last) = (xy(&out[0]), xy(&out[n - 1]));
+        if last == first || is_spike(xy(&out[n - 2]), last, first) {
+            out.pop();
+        } else if is_spike(last, first, xy(&out[1])) {
+            out.remove(0);
+        } else {
+            break;
+        }
+    }
+    out
+}
+
+/// Splits a ring at the vertices it passes through more than once.
+fn split_ring<const D: usize, N: CoordNum + Copy>(ring: Vec<[N; D]>, out: &mut Vec<Vec<[N; D]>>) {
+    let key = |c: &[N; D]| {
+        let (x, y) = xy(c);
+        (x.to_bits(), y.to_bits())
+    };
+    let mut path: Vec<[N; D]> = Vec::with_capacity(ring.len());
+    let mut seen = BTreeMap::new();
+    for c in ring {
+        match seen.get(&key(&c)) {
+            Some(&pos) => {
+                // close the loop that started at the same vertex
+                let piece: Vec<_> = path.drain(pos + 1..).collect();
+                for p in &piece {
+                    seen.remove(&key(p));
+                }
+                out.push(core::iter::once(path[pos]).chain(piece).collect());
+            }
+            None => {
+                seen.insert(key(&c), path.len());
+                path.push(c);
+            }
+        }
+    }
+    out.push(path);
+}
+
+fn signed_area<const D: usize, N: CoordNum>(ring: &[[N; D]]) -> f64 {
+    let Some(last) = ring.last() else {
+        return 0.0;
+    };
+    let mut prev = xy(last);
+    let mut area = 0.0;
+    for c in ring {
+        let p = xy(c);
+        area += prev.0 * p.1 - p.0 * prev.1;
+        prev = p;
+    }
+    area / 2.0
+}
+
+/// A cleaned ring with its signed area
+struct Ring<const D: usize, N> {
+    coords: Vec<[N; D]>,
+    area: f64,
+}
+
+impl<const D: usize, N: CoordNum + Copy> Ring<D, N> {
+    /// Cleans and splits a ring into simple rings with non-zero area.
+    fn repair(coords: &[[N; D]]) -> Vec<Self> {
+        let mut pieces = Vec::new();
+        split_ring(clean_ring(coords), &mut pieces);
+        pieces
+            .into_iter()
+            .map(|piece| clean_ring(&piece))
+            .filter(|piece| piece.len() >= 3)
+            .map(|coords| Ring {
+                area: signed_area(&coords),
+                coords,
+            })
+            .filter(|ring| ring.area != 0.0)
+            .collect()
+    }
+
+    /// Returns the coordinates wound counter-clockwise or clockwise.
+    fn oriented(self, ccw: bool) -> impl Iterator<Item = [N; D]> {
+        let reverse = (self.area > 0.0) != ccw;
+        let mut coords = self.coords;
+        if reverse {
+            coords[1..].reverse();
+        }
+        coords.into_iter()
+    }
+
+    /// Returns `true` if this ring lies inside `other`.
+    fn is_inside(&self, other: &Self) -> bool {
+        let ring: Vec<_> = other.coords.iter().map(xy).collect();
+        self.coords
+            .iter()
+            .find_map(|c| locate_in_ring(&ring, xy(c)))
+            .unwrap_or(false)
+    }
+}
+
+/// Repairs a polygon, appending the resulting polygons to `out`.
+fn repair_polygon<const D: usize, N: CoordNum + Copy>(
+    poly: &Polygon<'_, [N; D]>,
+    out: &mut MultiPolygon<'static, [N; D]>,
+) {
+    if poly.raw_coords().is_empty() {
+        return;
+    }
+    let mut rings = poly.rings();
+    let mut shells = Ring::repair(rings.next().unwrap().raw_coords());
+    let mut holes: Vec<_> = rings
+        .flat_map(|ring| Ring::repair(ring.raw_coords()))
+        .collect();
+
+    // loops of the exterior wound against its main part are holes
+    let Some(main) = shells
+        .iter()
+        .max_by(|a, b| a.area.abs().total_cmp(&b.area.abs()))
+    else {
+        return;
+    };
+    let positive = main.area > 0.0;
+    let (inverted, kept): (Vec<_>, Vec<_>) = shells
+        .into_iter()
+        .partition(|ring| (ring.area > 0.0) != positive);
+    shells = kept;
+    holes.extend(inverted);
+
+    // assign each hole to the smallest shell containing it, dropping the others
+    let mut assigned: Vec<Vec<Ring<D, N>>> = shells.iter().map(|_| Vec::new()).collect();
+    for hole in holes {
+        let shell = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, shell)| hole.is_inside(shell))
+            .min_by(|(_, a), (_, b)| a.area.abs().total_cmp(&b.area.abs()));
+        if let Some((i, _)) = shell {
+            assigned[i].push(hole);
+        }
+    }
+
+    for (shell, holes) in shells.into_iter().zip(assigned) {
+        out.add_exterior(shell.oriented(true));
+        for hole in holes {
+            out.add_interior(hole.oriented(false));
+        }
+    }
+}
+
+impl<const D: usize, N: CoordNum + Copy> Polygon<'_, [N; D]> {
+    /// Repairs common defects of the Polygon, returning the result as a MultiPolygon.
+    ///
+    /// Repeated vertices (including explicit closing points) and spikes are removed,
+    /// rings that pass through the same vertex more than once are split into separate rings,
+    /// and rings without area are dropped. Holes outside the exterior are dropped, and
+    /// loops of the exterior wound the other way become holes. The result is wound with
+    /// exterior rings counter-clockwise and interior rings clockwise.
+    ///
+    /// Crossing edges and rings touching at non-vertex points are not repaired.
+    /// Values other than X and Y (such as Z) are kept as they are.
+    pub fn make_valid(&self) -> MultiPolygon<'static, [N; D]> {
+        let mut mpoly = MultiPolygon::new();
+        repair_polygon(self, &mut mpoly);
+        mpoly
+    }
+}
+
+impl<const D: usize, N: CoordNum + Copy> MultiPolygon<'_, [N; D]> {
+    /// Repairs common defects of each Polygon.
+    ///
+    /// See [`Polygon::make_valid`] for details. Overlapping Polygons are not merged.
+    pub fn make_valid(&self) -> MultiPolygon<'static, [N; D]> {
+        let mut mpoly = MultiPolygon::new();
+        for poly in self {
+            repair_polygon(&poly, &mut mpoly);
+        }
+        mpoly
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiPolygon2, Orientation, Polygon2, Polygon3};
+    use alloc::vec;
+
+    #[test]
+    fn test_clean_ring() {
+        // repeated points, closing point and spikes
+        let ring = [
+            [0., 0.],
+            [0., 0.],
+            [4., 0.],
+            [6., 0.],
+            [4., 0.],
+            [4., 4.],
+            [4., 6.],
+            [5., 8.],
+            [4., 6.],
+            [4., 4.],
+            [0., 4.],
+            [0., 0.],
+        ];
+        assert_eq!(clean_ring(&ring), [[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+
+        // spike at the start
+        let ring = [[-2., 0.], [0., 0.], [0., 4.], [4., 4.], [4., 0.], [0., 0.]];
+        assert_eq!(clean_ring(&ring), [[0., 0.], [0., 4.], [4., 4.], [4., 0.]]);
+    }
+
+    #[test]
+    fn test_make_valid_polygon() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([
+            [0., 0.],
+            [0., 10.],
+            [10., 10.],
+            [10., 10.],
+            [10., 0.],
+            [12., 0.],
+            [10., 0.],
+        ]);
+        // zero-area hole
+        poly.add_ring([[1., 1.], [2., 2.], [3., 3.]]);
+        // hole outside
+        poly.add_ring([[20., 20.], [21., 20.], [21., 21.]]);
+        // valid hole
+        poly.add_ring([[1., 1.], [1., 2.], [2., 2.], [2., 1.]]);
+        let repaired = poly.make_valid();
+        assert_eq!(repaired.len(), 1);
+        let poly = repaired.get(0);
+        assert_eq!(
+            poly.exterior().raw_coords(),
+            [[0., 0.], [10., 0.], [10., 10.], [0., 10.]]
+        );
+        assert_eq!(poly.interiors().count(), 1);
+        assert!(poly.is_oriented(Orientation::CounterClockwise));
+        assert!(poly.is_valid());
+        assert_eq!(poly.area(), 99.);
+    }
+
+    #[test]
+    fn test_make_valid_split() {
+        // two squares touching at a vertex
+        let mut poly = Polygon2::new();
+        poly.add_ring([
+            [0., 0.],
+            [2., 0.],
+            [2., 2.],
+            [4., 2.],
+            [4., 4.],
+            [2., 4.],
+            [2., 2.],
+            [0., 2.],
+        ]);
+        assert!(!poly.is_valid());
+        let repaired = poly.make_valid();
+        assert_eq!(repaired.len(), 2);
+        assert!(repaired.is_valid());
+        assert_eq!(repaired.area(), 8.);
+
+        // an inverted loop becomes a hole
+        let mut poly = Polygon2::new();
+        poly.add_ring([
+            [0., 0.],
+            [10., 0.],
+            [10., 10.],
+            [5., 10.],
+            [6., 8.],
+            [4., 8.],
+            [5., 10.],
+            [0., 10.],
+        ]);
+        let repaired = poly.make_valid();
+        assert_eq!(repaired.len(), 1);
+        assert_eq!(repaired.get(0).interiors().count(), 1);
+        assert_eq!(repaired.area(), 98.);
+        assert!(repaired.is_oriented(Orientation::CounterClockwise));
+    }
+
+    #[test]
+    fn test_make_valid_multipolygon() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [1., 1.], [2., 2.]]);
+        mpoly.add_exterior([[0., 0.], [0., 1.], [1., 1.], [1., 0.], [0., 0.]]);
+        mpoly.add_interior([[0.2, 0.2], [0.2, 0.2], [0.4, 0.2], [0.4, 0.4]]);
+        let repaired = mpoly.make_valid();
+        assert_eq!(repaired.len(), 1);
+        assert_eq!(repaired.get(0).exterior().len(), 4);
+        assert_eq!(repaired.get(0).interiors().next().unwrap().len(), 3);
+        assert!(repaired.is_valid());
+
+        assert!(MultiPolygon2::<f64>::new().make_valid().is_empty());
+    }
+
+    #[test]
+    fn test_make_valid_3d() {
+        let poly = Polygon3::from_raw(
+            vec![[0., 0., 1.], [1., 0., 2.], [1., 0., 3.], [1., 1., 4.]].into(),
+            vec![].into(),
+        );
+        let repaired = poly.make_valid();
+        assert_eq!(
+            repaired.raw_coords(),
+            [[0., 0., 1.], [1., 0., 2.], [1., 1., 4.]]
+        );
+    }
+}
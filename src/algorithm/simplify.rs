@@ -0,0 +1,470 @@
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::{CoordNum, LineString, MultiLineString, MultiPolygon, Polygon};
+
+#[derive(Clone, Copy)]
+enum Method {
+    DouglasPeucker(f64),
+    Visvalingam(f64),
+}
+
+/// How a path is treated by the simplification
+#[derive(Clone, Copy, PartialEq)]
+enum Path {
+    /// Open path; both endpoints are kept
+    Line,
+    /// Closed ring that may degenerate
+    Ring,
+    /// Closed ring that keeps at least 3 vertices
+    ValidRing,
+}
+
+fn xy<const D: usize, N: CoordNum>(c: &[N; D]) -> (f64, f64) {
+    (c[0].to_f64().unwrap(), c[1].to_f64().unwrap())
+}
+
+/// Squared distance from `p` to the segment `a`-`b`
+fn segment_dist2(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = match len2 {
+        0.0 => 0.0,
+        _ => (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0),
+    };
+    let (ex, ey) = (a.0 + dx * t - p.0, a.1 + dy * t - p.1);
+    ex * ex + ey * ey
+}
+
+fn triangle_area(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0
+}
+
+/// Douglas–Peucker on `pts`, processing the given ranges and marking kept vertices.
+fn douglas_peucker(
+    pts: &[(f64, f64)],
+    epsilon: f64,
+    keep: &mut [bool],
+    mut stack: Vec<(usize, usize)>,
+) {
+    let eps2 = epsilon * epsilon;
+    while let Some((start, end)) = stack.pop() {
+        keep[start] = true;
+        keep[end] = true;
+        let mut max = (0.0, 0);
+        for (i, &p) in pts.iter().enumerate().take(end).skip(start + 1) {
+            let d = segment_dist2(p, pts[start], pts[end]);
+            if d > max.0 {
+                max = (d, i);
+            }
+        }
+        if max.0 > eps2 {
+            stack.push((start, max.1));
+            stack.push((max.1, end));
+        }
+    }
+}
+
+/// Heap entry of Visvalingam–Whyatt ordered by ascending area
+struct Entry {
+    area: f64,
+    index: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+/// Visvalingam–Whyatt on `pts`, marking kept vertices.
+fn visvalingam(pts: &[(f64, f64)], threshold: f64, ring: bool, min_len: usize, keep: &mut [bool]) {
+    let n = pts.len();
+    keep.fill(true);
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let removable = |i: usize| ring || (i != 0 && i != n - 1);
+    let mut areas = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::with_capacity(n);
+    for i in (0..n).filter(|&i| removable(i)) {
+        areas[i] = triangle_area(pts[prev[i]], pts[i], pts[next[i]]);
+        heap.push(Entry {
+            area: areas[i],
+            index: i,
+        });
+    }
+
+    let mut len = n;
+    while let Some(Entry { area, index: i }) = heap.pop() {
+        if !keep[i] || area != areas[i] {
+            // stale entry
+            continue;
+        }
+        if area >= threshold || len <= min_len {
+            break;
+        }
+        keep[i] = false;
+        len -= 1;
+        let (p, q) = (prev[i], next[i]);
+        next[p] = q;
+        prev[q] = p;
+        for j in [p, q] {
+            if removable(j) && keep[j] {
+                // areas never decrease, so that vertices are removed in a stable order
+                areas[j] = triangle_area(pts[prev[j]], pts[j], pts[next[j]]).max(area);
+                heap.push(Entry {
+                    area: areas[j],
+                    index: j,
+                });
+            }
+        }
+    }
+}
+
+/// Simplifies a path and appends the kept coordinates to `out`.
+fn simplify_path<const D: usize, N: CoordNum + Copy>(
+    coords: &[[N; D]],
+    method: Method,
+    path: Path,
+    out: &mut Vec<[N; D]>,
+) {
+    let n = coords.len();
+    let min_len = match path {
+        Path::Line | Path::Ring => 2,
+        Path::ValidRing => 3,
+    };
+    if n <= min_len {
+        out.extend_from_slice(coords);
+        return;
+    }
+    let mut pts: Vec<_> = coords.iter().map(xy).collect();
+    let mut keep = vec![false; n];
+
+    match (method, path) {
+        (Method::DouglasPeucker(epsilon), Path::Line) => {
+            douglas_peucker(&pts, epsilon, &mut keep, vec![(0, n - 1)]);
+        }
+        (Method::DouglasPeucker(epsilon), _) => {
+            // split the closed ring at the vertex farthest from the first one
+            let far = (1..n)
+                .max_by(|&a, &b| {
+                    let da = segment_dist2(pts[a], pts[0], pts[0]);
+                    let db = segment_dist2(pts[b], pts[0], pts[0]);
+                    da.total_cmp(&db)
+                })
+                .unwrap();
+            pts.push(pts[0]);
+            keep.push(false);
+            douglas_peucker(&pts, epsilon, &mut keep, vec![(far, n), (0, far)]);
+            keep.pop();
+            pts.pop();
+            if path == Path::ValidRing && keep.iter().filter(|&&k| k).count() < 3 {
+                let third = (1..n)
+                    .filter(|&i| i != far)
+                    .max_by(|&a, &b| {
+                        let da = segment_dist2(pts[a], pts[0], pts[far]);
+                        let db = segment_dist2(pts[b], pts[0], pts[far]);
+                        da.total_cmp(&db)
+                    })
+                    .unwrap();
+                keep[third] = true;
+            }
+        }
+        (Method::Visvalingam(threshold), path) => {
+            visvalingam(&pts, threshold, path != Path::Line, min_len, &mut keep);
+        }
+    }
+
+    out.extend(
+        coords
+            .iter()
+            .zip(&keep)
+            .filter_map(|(c, &k)| k.then_some(*c)),
+    );
+}
+
+fn simplify_linestring<const D: usize, N: CoordNum + Copy>(
+    ls: &LineString<'_, [N; D]>,
+    method: Method,
+) -> LineString<'static, [N; D]> {
+    let mut coords = Vec::new();
+    simplify_path(ls.raw_coords(), method, Path::Line, &mut coords);
+    LineString::from_raw(coords.into())
+}
+
+fn simplify_multilinestring<const D: usize, N: CoordNum + Copy>(
+    mls: &MultiLineString<'_, [N; D]>,
+    method: Method,
+) -> MultiLineString<'static, [N; D]> {
+    let mut simplified = MultiLineString::new();
+    let mut buf = Vec::new();
+    for ls in mls {
+        simplify_path(ls.raw_coords(), method, Path::Line, &mut buf);
+        simplified.add_linestring(buf.drain(..));
+    }
+    simplified
+}
+
+/// Simplifies the rings of a polygon, calling `add_ring` with the index and coordinates of each.
+fn simplify_rings<const D: usize, N: CoordNum + Copy>(
+    poly: &Polygon<'_, [N; D]>,
+    method: Method,
+    valid: bool,
+    buf: &mut Vec<[N; D]>,
+    mut add_ring: impl FnMut(usize, &mut Vec<[N; D]>),
+) {
+    for (i, ring) in poly.rings().enumerate() {
+        let path = match (valid, i) {
+            (true, 0) => Path::ValidRing,
+            _ => Path::Ring,
+        };
+        buf.clear();
+        simplify_path(ring.raw_coords(), method, path, buf);
+        if valid && i > 0 && buf.len() < 3 {
+            // collapsed hole
+            continue;
+        }
+        add_ring(i, buf);
+    }
+}
+
+fn simplify_polygon<const D: usize, N: CoordNum + Copy>(
+    poly: &Polygon<'_, [N; D]>,
+    method: Method,
+    valid: bool,
+) -> Polygon<'static, [N; D]> {
+    let mut simplified = Polygon::new();
+    if poly.raw_coords().is_empty() {
+        return simplified;
+    }
+    simplify_rings(poly, method, valid, &mut Vec::new(), |_, ring| {
+        simplified.add_ring(ring.drain(..))
+    });
+    simplified
+}
+
+fn simplify_multipolygon<const D: usize, N: CoordNum + Copy>(
+    mpoly: &MultiPolygon<'_, [N; D]>,
+    method: Method,
+    valid: bool,
+) -> MultiPolygon<'static, [N; D]> {
+    let mut simplified = MultiPolygon::new();
+    let mut buf = Vec::new();
+    for poly in mpoly {
+        simplify_rings(&poly, method, valid, &mut buf, |i, ring| match i {
+            0 => simplified.add_exterior(ring.drain(..)),
+            _ => simplified.add_interior(ring.drain(..)),
+        });
+    }
+    simplified
+}
+
+impl<const D: usize, N: CoordNum + Copy> LineString<'_, [N; D]> {
+    /// Simplifies the LineString with the Douglas–Peucker algorithm.
+    ///
+    /// Vertices closer than `epsilon` to the simplified line are removed.
+    /// Distances are measured in the XY plane.
+    pub fn simplify(&self, epsilon: f64) -> LineString<'static, [N; D]> {
+        simplify_linestring(self, Method::DouglasPeucker(epsilon))
+    }
+
+    /// Simplifies the LineString with the Visvalingam–Whyatt algorithm.
+    ///
+    /// Vertices forming a triangle smaller than `area_threshold` with their neighbors are removed.
+    /// Areas are measured in the XY plane.
+    pub fn simplify_vw(&self, area_threshold: f64) -> LineString<'static, [N; D]> {
+        simplify_linestring(self, Method::Visvalingam(area_threshold))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Copy> MultiLineString<'_, [N; D]> {
+    /// Simplifies each LineString with the Douglas–Peucker algorithm.
+    ///
+    /// See [`LineString::simplify`] for details.
+    pub fn simplify(&self, epsilon: f64) -> MultiLineString<'static, [N; D]> {
+        simplify_multilinestring(self, Method::DouglasPeucker(epsilon))
+    }
+
+    /// Simplifies each LineString with the Visvalingam–Whyatt algorithm.
+    ///
+    /// See [`LineString::simplify_vw`] for details.
+    pub fn simplify_vw(&self, area_threshold: f64) -> MultiLineString<'static, [N; D]> {
+        simplify_multilinestring(self, Method::Visvalingam(area_threshold))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Copy> Polygon<'_, [N; D]> {
+    /// Simplifies each ring with the Douglas–Peucker algorithm.
+    ///
+    /// Rings may degenerate into fewer than 3 vertices. Use [`Polygon::simplify_valid`]
+    /// to keep the rings valid.
+    pub fn simplify(&self, epsilon: f64) -> Polygon<'static, [N; D]> {
+        simplify_polygon(self, Method::DouglasPeucker(epsilon), false)
+    }
+
+    /// Simplifies each ring with the Visvalingam–Whyatt algorithm.
+    ///
+    /// Rings may degenerate into fewer than 3 vertices. Use [`Polygon::simplify_vw_valid`]
+    /// to keep the rings valid.
+    pub fn simplify_vw(&self, area_threshold: f64) -> Polygon<'static, [N; D]> {
+        simplify_polygon(self, Method::Visvalingam(area_threshold), false)
+    }
+
+    /// Same as [`Polygon::simplify`], but the exterior ring keeps at least 3 vertices
+    /// and interior rings that collapse are dropped.
+    pub fn simplify_valid(&self, epsilon: f64) -> Polygon<'static, [N; D]> {
+        simplify_polygon(self, Method::DouglasPeucker(epsilon), true)
+    }
+
+    /// Same as [`Polygon::simplify_vw`], but the exterior ring keeps at least 3 vertices
+    /// and interior rings that collapse are dropped.
+    pub fn simplify_vw_valid(&self, area_threshold: f64) -> Polygon<'static, [N; D]> {
+        simplify_polygon(self, Method::Visvalingam(area_threshold), true)
+    }
+}
+
+impl<const D: usize, N: CoordNum + Copy> MultiPolygon<'_, [N; D]> {
+    /// Simplifies each ring with the Douglas–Peucker algorithm.
+    ///
+    /// See [`Polygon::simplify`] for details.
+    pub fn simplify(&self, epsilon: f64) -> MultiPolygon<'static, [N; D]> {
+        simplify_multipolygon(self, Method::DouglasPeucker(epsilon), false)
+    }
+
+    /// Simplifies each ring with the Visvalingam–Whyatt algorithm.
+    ///
+    /// See [`Polygon::simplify_vw`] for details.
+    pub fn simplify_vw(&self, area_threshold: f64) -> MultiPolygon<'static, [N; D]> {
+        simplify_multipolygon(self, Method::Visvalingam(area_threshold), false)
+    }
+
+    /// Same as [`MultiPolygon::simplify`], but exterior rings keep at least 3 vertices
+    /// and interior rings that collapse are dropped.
+    pub fn simplify_valid(&self, epsilon: f64) -> MultiPolygon<'static, [N; D]> {
+        simplify_multipolygon(self, Method::DouglasPeucker(epsilon), true)
+    }
+
+    /// Same as [`MultiPolygon::simplify_vw`], but exterior rings keep at least 3 vertices
+    /// and interior rings that collapse are dropped.
+    pub fn simplify_vw_valid(&self, area_threshold: f64) -> MultiPolygon<'static, [N; D]> {
+        simplify_multipolygon(self, Method::Visvalingam(area_threshold), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, LineString3, MultiLineString2, MultiPolygon2, Polygon2};
+
+    #[test]
+    fn test_simplify_linestring() {
+        let ls = LineString2::from_raw(
+            vec![[0., 0.], [5., 4.], [11., 5.5], [17.3, 3.2], [27.8, 0.1]].into(),
+        );
+        assert_eq!(
+            ls.simplify(1.0).raw_coords(),
+            [[0., 0.], [5., 4.], [11., 5.5], [27.8, 0.1]]
+        );
+        assert_eq!(ls.simplify(10.0).raw_coords(), [[0., 0.], [27.8, 0.1]]);
+
+        let ls = LineString2::from_raw(
+            vec![[5., 2.], [3., 8.], [6., 20.], [7., 25.], [10., 10.]].into(),
+        );
+        assert_eq!(
+            ls.simplify_vw(30.).raw_coords(),
+            [[5., 2.], [7., 25.], [10., 10.]]
+        );
+        assert_eq!(ls.simplify_vw(0.).raw_coords(), ls.raw_coords());
+
+        // extra dimensions are kept
+        let ls = LineString3::from_raw(vec![[0., 0., 1.], [1., 0.01, 2.], [2., 0., 3.]].into());
+        assert_eq!(ls.simplify(0.1).raw_coords(), [[0., 0., 1.], [2., 0., 3.]]);
+    }
+
+    #[test]
+    fn test_simplify_multilinestring() {
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 0.1], [2., 0.]]);
+        mls.add_linestring([[0., 5.], [1., 5.], [2., 5.], [3., 5.]]);
+        let simplified = mls.simplify(0.5);
+        assert_eq!(simplified.len(), 2);
+        assert_eq!(
+            simplified.raw_coords(),
+            [[0., 0.], [2., 0.], [0., 5.], [3., 5.]]
+        );
+        assert_eq!(mls.simplify_vw(1.).raw_coords().len(), 4);
+    }
+
+    #[test]
+    fn test_simplify_polygon() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([
+            [0., 0.],
+            [5., 0.1],
+            [10., 0.],
+            [10.1, 5.],
+            [10., 10.],
+            [0., 10.],
+        ]);
+        poly.add_ring([[2., 2.], [2., 2.2], [2.2, 2.2], [2.2, 2.]]);
+        poly.add_ring([[5., 5.], [5., 8.], [8., 8.], [8., 5.]]);
+
+        let simplified = poly.simplify(0.5);
+        assert_eq!(
+            simplified.exterior().raw_coords(),
+            [[0., 0.], [10., 0.], [10., 10.], [0., 10.]]
+        );
+        assert_eq!(simplified.len(), 3);
+        assert_eq!(simplified.interiors().next().unwrap().len(), 2);
+
+        let simplified = poly.simplify_valid(0.5);
+        assert_eq!(simplified.len(), 2);
+        assert_eq!(simplified.hole_indices(), [4]);
+        assert_eq!(simplified.interiors().next().unwrap().len(), 4);
+
+        let simplified = poly.simplify_vw_valid(1.0);
+        assert_eq!(simplified.exterior().len(), 4);
+        assert_eq!(simplified.hole_indices(), [4]);
+
+        // the exterior ring keeps at least 3 vertices
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        assert_eq!(poly.simplify_valid(100.).exterior().len(), 3);
+        assert_eq!(poly.simplify_vw_valid(100.).exterior().len(), 3);
+        assert!(poly.simplify(100.).exterior().len() < 3);
+    }
+
+    #[test]
+    fn test_simplify_multipolygon() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.1], [10., 0.], [10., 10.], [0., 10.]]);
+        mpoly.add_interior([[4., 4.], [4., 4.1], [4.1, 4.1]]);
+        mpoly.add_exterior([[20., 0.], [30., 0.], [30., 10.], [25., 10.1], [20., 10.]]);
+        let simplified = mpoly.simplify_valid(0.5);
+        assert_eq!(simplified.len(), 2);
+        assert_eq!(simplified.get(0).len(), 1);
+        assert_eq!(simplified.get(0).exterior().len(), 4);
+        assert_eq!(simplified.get(1).exterior().len(), 4);
+
+        let simplified = mpoly.simplify_vw(1.0);
+        assert_eq!(simplified.len(), 2);
+        assert_eq!(simplified.get(0).len(), 2);
+    }
+}
@@ -0,0 +1,259 @@
+use alloc::vec::Vec;
+
+use super::earcut::earcut;
+use crate::{Coord2d, Coord3d, MultiPolygon, Polygon};
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Triangulates the polygon with the earcut algorithm.
+    ///
+    /// Returns vertex indices into [`Polygon::raw_coords`], three per triangle.
+    pub fn triangulate(&self) -> Vec<u32> {
+        let coords: Vec<[f64; 2]> = self
+            .raw_coords()
+            .iter()
+            .map(|c| {
+                let (x, y) = c.xy();
+                [x, y]
+            })
+            .collect();
+        let mut triangles = Vec::new();
+        earcut(&coords, self.hole_indices(), 0, &mut triangles);
+        triangles
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Triangulates all polygons with the earcut algorithm.
+    ///
+    /// Returns vertex indices into [`MultiPolygon::raw_coords`], three per triangle.
+    pub fn triangulate(&self) -> Vec<u32> {
+        let coords: Vec<[f64; 2]> = self
+            .raw_coords()
+            .iter()
+            .map(|c| {
+                let (x, y) = c.xy();
+                [x, y]
+            })
+            .collect();
+        let mut triangles = Vec::new();
+        let mut offset = 0;
+        for poly in self {
+            let len = poly.raw_coords().len();
+            earcut(
+                &coords[offset..offset + len],
+                poly.hole_indices(),
+                offset as u32,
+                &mut triangles,
+            );
+            offset += len;
+        }
+        triangles
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> Polygon<'_, T> {
+    /// Triangulates the polygon with the earcut algorithm after projecting it onto its best-fit plane.
+    ///
+    /// Returns vertex indices into [`Polygon::raw_coords`], three per triangle.
+    pub fn triangulate_3d(&self) -> Vec<u32> {
+        let mut coords = Vec::new();
+        let mut triangles = Vec::new();
+        project_to_plane(self, &mut coords);
+        earcut(&coords, self.hole_indices(), 0, &mut triangles);
+        triangles
+    }
+}
+
+// 3-dimensional only
+impl<T: Coord3d> MultiPolygon<'_, T> {
+    /// Triangulates all polygons with the earcut algorithm after projecting each of them onto its best-fit plane.
+    ///
+    /// Returns vertex indices into [`MultiPolygon::raw_coords`], three per triangle.
+    pub fn triangulate_3d(&self) -> Vec<u32> {
+        let mut coords = Vec::new();
+        let mut triangles = Vec::new();
+        let mut offset = 0;
+        for poly in self {
+            project_to_plane(&poly, &mut coords);
+            earcut(&coords, poly.hole_indices(), offset, &mut triangles);
+            offset += poly.raw_coords().len() as u32;
+        }
+        triangles
+    }
+}
+
+/// Projects the polygon onto its best-fit plane, whose normal is computed with Newell's method,
+/// preserving the orientation of the rings as seen from the direction of the normal.
+fn project_to_plane<T: Coord3d>(poly: &Polygon<T>, out: &mut Vec<[f64; 2]>) {
+    out.clear();
+    let Some(origin) = poly.raw_coords().first().map(|c| c.xyz()) else {
+        return;
+    };
+
+    // Newell's method
+    let (mut nx, mut ny, mut nz) = (0., 0., 0.);
+    let ext = poly.exterior();
+    let mut iter = ext.iter_closed();
+    if let Some(first) = iter.next() {
+        let mut prev = first.xyz();
+        for coord in iter {
+            let (x, y, z) = coord.xyz();
+            nx += (prev.1 - y) * (prev.2 + z);
+            ny += (prev.2 - z) * (prev.0 + x);
+            nz += (prev.0 - x) * (prev.1 + y);
+            prev = (x, y, z);
+        }
+    }
+    let len = libm::sqrt(nx * nx + ny * ny + nz * nz);
+    let n = match len > 0. {
+        true => [nx / len, ny / len, nz / len],
+        false => [0., 0., 1.], // degenerate ring
+    };
+
+    // orthonormal basis (u, v) of the plane such that u × v = n
+    let axis = if n[0].abs() <= n[1].abs() && n[0].abs() <= n[2].abs() {
+        [1., 0., 0.]
+    } else if n[1].abs() <= n[2].abs() {
+        [0., 1., 0.]
+    } else {
+        [0., 0., 1.]
+    };
+    let d = dot(axis, n);
+    let u = normalize([axis[0] - d * n[0], axis[1] - d * n[1], axis[2] - d * n[2]]);
+    let v = cross(n, u);
+
+    out.extend(poly.raw_coords().iter().map(|c| {
+        let (x, y, z) = c.xyz();
+        let p = [x - origin.0, y - origin.1, z - origin.2];
+        [dot(p, u), dot(p, v)]
+    }));
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let len = libm::sqrt(dot(a, a));
+    [a[0] / len, a[1] / len, a[2] / len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiPolygon2, MultiPolygon3, Polygon2, Polygon3};
+
+    /// Sums up the area of the triangles
+    fn triangles_area(coords: &[[f64; 2]], triangles: &[u32]) -> f64 {
+        triangles
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [
+                    coords[t[0] as usize],
+                    coords[t[1] as usize],
+                    coords[t[2] as usize],
+                ];
+                ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_triangulate_polygon() {
+        let mut poly = Polygon2::new();
+        assert!(poly.triangulate().is_empty());
+
+        poly.add_ring([[0., 0.], [10., 0.], [10., 10.], [0., 10.]]);
+        let triangles = poly.triangulate();
+        assert_eq!(triangles.len(), 2 * 3);
+        assert_eq!(triangles_area(poly.raw_coords(), &triangles), 100.);
+
+        poly.add_ring([[2., 2.], [2., 4.], [4., 4.], [4., 2.]]);
+        poly.add_ring([[6., 6.], [8., 6.], [8., 8.], [6., 8.]]);
+        let triangles = poly.triangulate();
+        assert_eq!(triangles.len(), 14 * 3);
+        assert_eq!(triangles_area(poly.raw_coords(), &triangles), poly.area());
+    }
+
+    #[test]
+    fn test_triangulate_many_vertices() {
+        // more than 80 vertices to exercise the z-order hashing
+        let mut poly = Polygon2::new();
+        poly.add_ring(
+            [[0., 0.], [100., 0.]]
+                .into_iter()
+                .chain((0..=100).rev().map(|x| [x as f64, (10 - x % 2) as f64])),
+        );
+        poly.add_ring([[40., 4.], [40., 6.], [60., 6.], [60., 4.]]);
+        let triangles = poly.triangulate();
+        assert_eq!(triangles.len(), 107 * 3);
+        assert_eq!(triangles_area(poly.raw_coords(), &triangles), poly.area());
+    }
+
+    #[test]
+    fn test_triangulate_multipolygon() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        mpoly.add_interior([[1., 1.], [1., 2.], [2., 2.], [2., 1.]]);
+        mpoly.add_exterior([[10., 0.], [13., 0.], [13., 3.]]);
+        let triangles = mpoly.triangulate();
+        assert_eq!(triangles.len(), (8 + 1) * 3);
+        assert!(triangles[24..].iter().all(|&i| (8..11).contains(&i)));
+        assert_eq!(triangles_area(mpoly.raw_coords(), &triangles), 24. + 4.5);
+    }
+
+    #[test]
+    fn test_triangulate_3d() {
+        // vertical wall on the x-z plane
+        let mut poly = Polygon3::new();
+        poly.add_ring([[0., 0., 0.], [10., 0., 0.], [10., 0., 10.], [0., 0., 10.]]);
+        poly.add_ring([[2., 0., 2.], [2., 0., 4.], [4., 0., 4.], [4., 0., 2.]]);
+        let triangles = poly.triangulate_3d();
+        assert_eq!(triangles.len(), 8 * 3);
+
+        let projected: Vec<[f64; 2]> = poly.raw_coords().iter().map(|c| [c[0], c[2]]).collect();
+        assert_eq!(triangles_area(&projected, &triangles), 96.);
+
+        let mut mpoly = MultiPolygon3::new();
+        mpoly.push(&poly);
+        mpoly.add_exterior([[0., 0., 5.], [1., 0., 5.], [0., 1., 5.]]);
+        let triangles = mpoly.triangulate_3d();
+        assert_eq!(triangles.len(), 9 * 3);
+        assert!(triangles[24..].iter().all(|&i| (8..11).contains(&i)));
+    }
+
+    #[test]
+    fn test_triangulate_3d_tilted() {
+        // a 10x10 square with a 2x2 hole on the tilted plane z = x + y
+        let lift = |[x, y]: [f64; 2]| [x, y, x + y];
+        let mut poly = Polygon3::new();
+        poly.add_ring([[0., 0.], [10., 0.], [10., 10.], [0., 10.]].map(lift));
+        poly.add_ring([[2., 2.], [2., 4.], [4., 4.], [4., 2.]].map(lift));
+        let triangles = poly.triangulate_3d();
+        assert_eq!(triangles.len(), 8 * 3);
+
+        // the triangles cover the polygon on its own plane (area scaled by sqrt(3))
+        let coords = poly.raw_coords();
+        let area: f64 = triangles
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| coords[t[i] as usize]);
+                let ab = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+                let ac = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+                libm::sqrt(dot(cross(ab, ac), cross(ab, ac))) / 2.
+            })
+            .sum();
+        assert!((area - 96. * libm::sqrt(3.)).abs() < 1e-9);
+    }
+}
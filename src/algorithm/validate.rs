@@ -0,0 +1,681 @@
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+use core::fmt;
+
+use super::contains::{locate_in_ring, orient, segments_intersect, within_segment};
+use crate::{Coord2d, LineString, MultiLineString, MultiPolygon, Polygon, Rect2};
+
+/// A reason why a geometry is invalid, as reported by `validate()`.
+///
+/// `part` is the index of the LineString or Polygon within a multi-geometry (0 for single
+/// geometries), and `ring` is the index of the ring within a Polygon, where 0 is the exterior
+/// (0 for LineStrings). Vertex and segment indices refer to the coordinates of the ring or
+/// LineString, with segment `i` starting at vertex `i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A coordinate is NaN or infinite.
+    NonFinite {
+        part: usize,
+        ring: usize,
+        index: usize,
+    },
+    /// A vertex is equal to the previous one. For rings, this includes an explicit closing point.
+    RepeatedPoint {
+        part: usize,
+        ring: usize,
+        index: usize,
+    },
+    /// Fewer than 2 distinct vertices for a LineString or 3 for a ring.
+    TooFewPoints { part: usize, ring: usize },
+    /// Two segments of the same ring or LineString touch or cross.
+    SelfIntersection {
+        part: usize,
+        ring: usize,
+        segments: (usize, usize),
+    },
+    /// Two rings of the same Polygon cross, overlap or touch at more than one point.
+    RingsIntersect {
+        part: usize,
+        ring: usize,
+        other: usize,
+    },
+    /// An interior ring is not inside the exterior ring.
+    HoleOutsideShell { part: usize, ring: usize },
+    /// An interior ring is inside another interior ring.
+    NestedHole {
+        part: usize,
+        ring: usize,
+        other: usize,
+    },
+    /// The interiors of two Polygons of a MultiPolygon overlap.
+    OverlappingPolygons { part: usize, other: usize },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFinite { part, ring, index } => write!(
+                f,
+                "non-finite coordinate at vertex {} (part {}, ring {})",
+                index, part, ring
+            ),
+            Self::RepeatedPoint { part, ring, index } => write!(
+                f,
+                "repeated point at vertex {} (part {}, ring {})",
+                index, part, ring
+            ),
+            Self::TooFewPoints { part, ring } => {
+                write!(f, "too few points (part {}, ring {})", part, ring)
+            }
+            Self::SelfIntersection {
+                part,
+                ring,
+                segments,
+            } => write!(
+                f,
+                "self-intersection between segments {} and {} (part {}, ring {})",
+                segments.0, segments.1, part, ring
+            ),
+            Self::RingsIntersect { part, ring, other } => {
+                write!(f, "rings {} and {} intersect (part {})", other, ring, part)
+            }
+            Self::HoleOutsideShell { part, ring } => {
+                write!(f, "hole outside shell (part {}, ring {})", part, ring)
+            }
+            Self::NestedHole { part, ring, other } => {
+                write!(f, "hole {} is inside hole {} (part {})", ring, other, part)
+            }
+            Self::OverlappingPolygons { part, other } => {
+                write!(f, "polygons {} and {} overlap", other, part)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// Vertices of a ring or LineString with consecutive duplicates removed,
+/// together with their original indices.
+struct Path {
+    points: Vec<(f64, f64)>,
+    indices: Vec<usize>,
+    closed: bool,
+}
+
+impl Path {
+    fn segment(&self, i: usize) -> ((f64, f64), (f64, f64)) {
+        (self.points[i], self.points[(i + 1) % self.points.len()])
+    }
+
+    fn num_segments(&self) -> usize {
+        match self.closed {
+            true => self.points.len(),
+            false => self.points.len() - 1,
+        }
+    }
+
+    /// Returns the location of the first vertex not on `other`.
+    fn locate_in(&self, other: &Path) -> Option<bool> {
+        self.points
+            .iter()
+            .find_map(|&p| locate_in_ring(&other.points, p))
+    }
+}
+
+/// Checks a ring or LineString, returning it for further checks if it is usable.
+fn check_path<T: Coord2d>(
+    coords: &[T],
+    closed: bool,
+    part: usize,
+    ring: usize,
+    errors: &mut Vec<ValidationError>,
+) -> Option<Path> {
+    let points: Vec<_> = coords.iter().map(|c| c.xy()).collect();
+    if let Some(index) = points
+        .iter()
+        .position(|p| !p.0.is_finite() || !p.1.is_finite())
+    {
+        errors.push(ValidationError::NonFinite { part, ring, index });
+        return None;
+    }
+
+    let mut path = Path {
+        points: Vec::with_capacity(points.len()),
+        indices: Vec::with_capacity(points.len()),
+        closed,
+    };
+    for (index, &p) in points.iter().enumerate() {
+        if path.points.last() == Some(&p) {
+            errors.push(ValidationError::RepeatedPoint { part, ring, index });
+        } else {
+            path.points.push(p);
+            path.indices.push(index);
+        }
+    }
+    if closed && path.points.len() > 1 && path.points.first() == path.points.last() {
+        errors.push(ValidationError::RepeatedPoint {
+            part,
+            ring,
+            index: path.indices.pop().unwrap(),
+        });
+        path.points.pop();
+    }
+
+    if path.points.len() < if closed { 3 } else { 2 } {
+        errors.push(ValidationError::TooFewPoints { part, ring });
+        return None;
+    }
+
+    if let Some((i, j)) = find_self_intersection(&path) {
+        errors.push(ValidationError::SelfIntersection {
+            part,
+            ring,
+            segments: (path.indices[i], path.indices[j]),
+        });
+    }
+    Some(path)
+}
+
+fn find_self_intersection(path: &Path) -> Option<(usize, usize)> {
+    let n = path.num_segments();
+    // an open LineString ending at its start is closed, but not a ring
+    let ends_meet = !path.closed && path.points.first() == path.points.last();
+    for i in 0..n {
+        let (a, b) = path.segment(i);
+        for j in i + 1..n {
+            let (c, d) = path.segment(j);
+            let adjacent = j == i + 1 || ((path.closed || ends_meet) && i == 0 && j == n - 1);
+            if adjacent {
+                // segments sharing a vertex only intersect if they fold back onto each other
+                let (p, q, r) = if j == i + 1 { (a, b, d) } else { (c, d, b) };
+                let folds = orient(p, q, r) == 0.0
+                    && (q.0 - p.0) * (r.0 - q.0) + (q.1 - p.1) * (r.1 - q.1) < 0.0;
+                if folds || (ends_meet && n == 2) {
+                    return Some((i, j));
+                }
+            } else if segments_intersect(a, b, c, d) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+/// Returns `true` if the boundaries cross, overlap, or touch at more than one point.
+fn rings_intersect(a: &Path, b: &Path) -> bool {
+    let mut touches: Vec<(f64, f64)> = Vec::new();
+    for i in 0..a.num_segments() {
+        let (p, q) = a.segment(i);
+        for j in 0..b.num_segments() {
+            let (r, s) = b.segment(j);
+            if !segments_intersect(p, q, r, s) {
+                continue;
+            }
+            let (o1, o2) = (orient(p, q, r), orient(p, q, s));
+            let (o3, o4) = (orient(r, s, p), orient(r, s, q));
+            if o1 == 0.0 && o2 == 0.0 {
+                // collinear: overlapping unless they only share an endpoint
+                let touch = if p == s || p == r {
+                    p
+                } else if q == r || q == s {
+                    q
+                } else {
+                    return true;
+                };
+                let overlap = within_segment(r, p, q) && r != touch
+                    || within_segment(s, p, q) && s != touch
+                    || within_segment(p, r, s) && p != touch
+                    || within_segment(q, r, s) && q != touch;
+                if overlap {
+                    return true;
+                }
+                touches.push(touch);
+            } else if o1 != 0.0 && o2 != 0.0 && o3 != 0.0 && o4 != 0.0 {
+                // proper crossing
+                return true;
+            } else {
+                let touch = match (o1, o2, o3) {
+                    (0.0, _, _) => r,
+                    (_, 0.0, _) => s,
+                    (_, _, 0.0) => p,
+                    _ => q,
+                };
+                touches.push(touch);
+            }
+            if touches.iter().any(|t| *t != touches[0]) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Checks a Polygon, returning its exterior ring if it is usable.
+fn check_polygon<T: Coord2d>(
+    poly: &Polygon<'_, T>,
+    part: usize,
+    errors: &mut Vec<ValidationError>,
+) -> Option<Path> {
+    if poly.raw_coords().is_empty() {
+        return None;
+    }
+    let rings: Vec<_> = poly
+        .rings()
+        .enumerate()
+        .map(|(i, ring)| check_path(ring.raw_coords(), true, part, i, errors))
+        .collect();
+
+    for (i, a) in rings.iter().enumerate() {
+        let Some(a) = a else { continue };
+        for (j, b) in rings.iter().enumerate().skip(i + 1) {
+            let Some(b) = b else { continue };
+            if rings_intersect(a, b) {
+                errors.push(ValidationError::RingsIntersect {
+                    part,
+                    ring: j,
+                    other: i,
+                });
+            }
+        }
+    }
+
+    let mut rings = rings.into_iter();
+    let exterior = rings.next().flatten();
+    let holes: Vec<_> = rings.collect();
+    for (i, hole) in holes.iter().enumerate() {
+        let Some(hole) = hole else { continue };
+        if let Some(exterior) = &exterior {
+            if hole.locate_in(exterior) == Some(false) {
+                errors.push(ValidationError::HoleOutsideShell { part, ring: i + 1 });
+                continue;
+            }
+        }
+        for (j, other) in holes.iter().enumerate() {
+            let Some(other) = other else { continue };
+            if i != j && hole.locate_in(other) == Some(true) {
+                errors.push(ValidationError::NestedHole {
+                    part,
+                    ring: i + 1,
+                    other: j + 1,
+                });
+            }
+        }
+    }
+    exterior
+}
+
+// 2-dimensional only
+impl<T: Coord2d> LineString<'_, T> {
+    /// Returns `true` if [`LineString::validate`] finds no problems.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Checks the LineString for non-finite coordinates, repeated points,
+    /// too few points and self-intersections.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        check_path(self.raw_coords(), false, 0, 0, &mut errors);
+        errors
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiLineString<'_, T> {
+    /// Returns `true` if [`MultiLineString::validate`] finds no problems.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Checks each LineString as in [`LineString::validate`].
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (part, ls) in self.iter().enumerate() {
+            check_path(ls.raw_coords(), false, part, 0, &mut errors);
+        }
+        errors
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> Polygon<'_, T> {
+    /// Returns `true` if [`Polygon::validate`] finds no problems.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Checks the Polygon for non-finite coordinates, repeated points, rings with too few
+    /// points, self-intersecting rings, intersecting rings, holes outside the exterior
+    /// and nested holes.
+    ///
+    /// Rings with non-finite coordinates or too few points are excluded from the other checks.
+    /// An empty Polygon is valid.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        check_polygon(self, 0, &mut errors);
+        errors
+    }
+}
+
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Returns `true` if [`MultiPolygon::validate`] finds no problems.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+
+    /// Checks each Polygon as in [`Polygon::validate`], and whether the interiors of
+    /// any two Polygons overlap. Polygons may touch at their boundaries.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        let polys: Vec<_> = self.iter().collect();
+        let exteriors: Vec<_> = polys
+            .iter()
+            .enumerate()
+            .map(|(part, poly)| check_polygon(poly, part, &mut errors))
+            .collect();
+
+        let rects: Vec<_> = exteriors
+            .iter()
+            .map(|ext| Rect2::from_coords(ext.as_ref()?.points.iter().map(|&(x, y)| [x, y])))
+            .collect();
+        // sample points are computed only for polygons whose bounding boxes intersect another one
+        let samples: Vec<OnceCell<Vec<_>>> = polys.iter().map(|_| OnceCell::new()).collect();
+        let sample =
+            |k: usize, ext: &Path| samples[k].get_or_init(|| sample_points(&polys[k], ext));
+
+        for (i, (a, rect_a)) in exteriors.iter().zip(&rects).enumerate() {
+            let (Some(a), Some(rect_a)) = (a, rect_a) else {
+                continue;
+            };
+            for (j, (b, rect_b)) in exteriors.iter().zip(&rects).enumerate().skip(i + 1) {
+                let (Some(b), Some(rect_b)) = (b, rect_b) else {
+                    continue;
+                };
+                if !rect_a.intersects(rect_b) {
+                    continue;
+                }
+                if polygons_overlap((&polys[i], a, sample(i, a)), (&polys[j], b, sample(j, b))) {
+                    errors.push(ValidationError::OverlappingPolygons { part: j, other: i });
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// Points of the Polygon to test against the other polygons: the exterior vertices, the midpoints
+/// of the exterior edges and the centroids of its triangles, so that an overlap is found even if
+/// all vertices lie on the boundary of the other polygon.
+fn sample_points<T: Coord2d>(poly: &Polygon<'_, T>, exterior: &Path) -> Vec<(f64, f64)> {
+    let mut points = exterior.points.clone();
+    points.extend((0..exterior.num_segments()).map(|i| {
+        let (p, q) = exterior.segment(i);
+        ((p.0 + q.0) / 2., (p.1 + q.1) / 2.)
+    }));
+    let coords = poly.raw_coords();
+    points.extend(poly.triangulate().chunks_exact(3).map(|t| {
+        let [a, b, c] = [0, 1, 2].map(|k| coords[t[k] as usize].xy());
+        ((a.0 + b.0 + c.0) / 3., (a.1 + b.1 + c.1) / 3.)
+    }));
+    points
+}
+
+/// A Polygon with its exterior ring and its sample points
+type PolygonParts<'a, 'b, T> = (&'a Polygon<'b, T>, &'a Path, &'a [(f64, f64)]);
+
+fn polygons_overlap<T: Coord2d>(a: PolygonParts<'_, '_, T>, b: PolygonParts<'_, '_, T>) -> bool {
+    // the exteriors cross
+    for i in 0..a.1.num_segments() {
+        let (p, q) = a.1.segment(i);
+        for j in 0..b.1.num_segments() {
+            let (r, s) = b.1.segment(j);
+            let (o1, o2) = (orient(p, q, r), orient(p, q, s));
+            let (o3, o4) = (orient(r, s, p), orient(r, s, q));
+            if o1 * o2 < 0.0 && o3 * o4 < 0.0 {
+                return true;
+            }
+        }
+    }
+    // a sample point of one lies in the interior of the other (holes excluded)
+    let inside = |points: &[(f64, f64)], poly: &Polygon<'_, T>| {
+        let rings: Vec<_> = poly.rings().collect();
+        points.iter().any(|&p| {
+            let mut crossings = 0;
+            for ring in &rings {
+                let coords = ring.raw_coords();
+                for k in 0..coords.len() {
+                    let (a, b) = (coords[k].xy(), coords[(k + 1) % coords.len()].xy());
+                    if orient(a, b, p) == 0.0 && within_segment(p, a, b) {
+                        return false;
+                    }
+                    if (a.1 > p.1) != (b.1 > p.1)
+                        && p.0 < a.0 + (p.1 - a.1) * (b.0 - a.0) / (b.1 - a.1)
+                    {
+                        crossings += 1;
+                    }
+                }
+            }
+            crossings % 2 == 1
+        })
+    };
+    inside(a.2, b.0) || inside(b.2, a.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, MultiLineString2, MultiPolygon2, Polygon2};
+    use alloc::vec;
+
+    #[test]
+    fn test_validate_linestring() {
+        let ls = LineString2::from_raw(vec![[0., 0.], [1., 0.], [1., 1.]].into());
+        assert!(ls.is_valid());
+
+        let ls = LineString2::from_raw(vec![[0., 0.], [1., 0.], [1., 0.], [1., 1.]].into());
+        assert_eq!(
+            ls.validate(),
+            [ValidationError::RepeatedPoint {
+                part: 0,
+                ring: 0,
+                index: 2
+            }]
+        );
+
+        let ls = LineString2::from_raw(vec![[0., 0.], [f64::NAN, 0.]].into());
+        assert_eq!(
+            ls.validate(),
+            [ValidationError::NonFinite {
+                part: 0,
+                ring: 0,
+                index: 1
+            }]
+        );
+
+        let ls = LineString2::from_raw(vec![[0., 0.], [0., 0.]].into());
+        assert!(ls
+            .validate()
+            .contains(&ValidationError::TooFewPoints { part: 0, ring: 0 }));
+
+        // bowtie
+        let ls = LineString2::from_raw(vec![[0., 0.], [2., 2.], [2., 0.], [0., 2.]].into());
+        assert_eq!(
+            ls.validate(),
+            [ValidationError::SelfIntersection {
+                part: 0,
+                ring: 0,
+                segments: (0, 2)
+            }]
+        );
+
+        // closed LineStrings are valid
+        let ls = LineString2::from_raw(vec![[0., 0.], [2., 0.], [2., 2.], [0., 0.]].into());
+        assert!(ls.is_valid());
+
+        // going back along itself
+        let ls = LineString2::from_raw(vec![[0., 0.], [2., 0.], [1., 0.]].into());
+        assert!(!ls.is_valid());
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 1.]]);
+        mls.add_linestring([[0., 0.]]);
+        assert_eq!(
+            mls.validate(),
+            [ValidationError::TooFewPoints { part: 1, ring: 0 }]
+        );
+    }
+
+    fn square(min: f64, max: f64) -> [[f64; 2]; 4] {
+        [[min, min], [max, min], [max, max], [min, max]]
+    }
+
+    #[test]
+    fn test_validate_polygon() {
+        let mut poly = Polygon2::new();
+        assert!(poly.is_valid());
+        poly.add_ring(square(0., 10.));
+        poly.add_ring(square(1., 2.));
+        assert!(poly.is_valid());
+
+        // a hole touching the exterior at one point is fine
+        let mut poly = Polygon2::new();
+        poly.add_ring(square(0., 10.));
+        poly.add_ring([[0., 5.], [2., 4.], [2., 6.]]);
+        assert!(poly.is_valid(), "{:?}", poly.validate());
+
+        // too few points and a closing point
+        let mut poly = Polygon2::new();
+        poly.add_ring(square(0., 10.));
+        poly.add_ring([[1., 1.], [2., 2.]]);
+        assert_eq!(
+            poly.validate(),
+            [ValidationError::TooFewPoints { part: 0, ring: 1 }]
+        );
+        let poly = Polygon2::from_raw(
+            vec![[0., 0.], [1., 0.], [1., 1.], [0., 0.]].into(),
+            vec![].into(),
+        );
+        assert_eq!(
+            poly.validate(),
+            [ValidationError::RepeatedPoint {
+                part: 0,
+                ring: 0,
+                index: 3
+            }]
+        );
+
+        // bowtie exterior
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [2., 2.], [2., 0.], [0., 2.]]);
+        assert_eq!(
+            poly.validate(),
+            [ValidationError::SelfIntersection {
+                part: 0,
+                ring: 0,
+                segments: (0, 2)
+            }]
+        );
+
+        // holes outside, crossing and nested
+        let mut poly = Polygon2::new();
+        poly.add_ring(square(0., 10.));
+        poly.add_ring(square(20., 30.));
+        poly.add_ring(square(9., 11.));
+        poly.add_ring(square(1., 5.));
+        poly.add_ring(square(2., 3.));
+        assert_eq!(
+            poly.validate(),
+            [
+                ValidationError::RingsIntersect {
+                    part: 0,
+                    ring: 2,
+                    other: 0
+                },
+                ValidationError::HoleOutsideShell { part: 0, ring: 1 },
+                ValidationError::NestedHole {
+                    part: 0,
+                    ring: 4,
+                    other: 3
+                },
+            ]
+        );
+
+        // rings sharing an edge
+        let mut poly = Polygon2::new();
+        poly.add_ring(square(0., 10.));
+        poly.add_ring([[0., 2.], [0., 4.], [2., 4.], [2., 2.]]);
+        assert!(!poly.is_valid());
+    }
+
+    #[test]
+    fn test_validate_multipolygon() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior(square(0., 10.));
+        mpoly.add_interior(square(2., 8.));
+        // inside the hole of the first
+        mpoly.add_exterior(square(3., 7.));
+        // touching the first
+        mpoly.add_exterior(square(10., 12.));
+        assert!(mpoly.is_valid(), "{:?}", mpoly.validate());
+
+        // overlapping the second and the first
+        mpoly.add_exterior(square(6., 9.));
+        // nested in the third
+        mpoly.add_exterior(square(10.5, 11.5));
+        // with a non-finite coordinate
+        mpoly.add_exterior([[0., 0.], [1., f64::INFINITY], [1., 1.]]);
+        assert_eq!(
+            mpoly.validate(),
+            [
+                ValidationError::NonFinite {
+                    part: 5,
+                    ring: 0,
+                    index: 1
+                },
+                ValidationError::OverlappingPolygons { part: 3, other: 0 },
+                ValidationError::OverlappingPolygons { part: 3, other: 1 },
+                ValidationError::OverlappingPolygons { part: 4, other: 2 },
+            ]
+        );
+
+        // identical polygons
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior(square(0., 10.));
+        mpoly.add_exterior(square(0., 10.));
+        assert_eq!(
+            mpoly.validate(),
+            [ValidationError::OverlappingPolygons { part: 1, other: 0 }]
+        );
+
+        // all vertices on the boundary of the other
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior(square(0., 10.));
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 10.], [0., 10.]]);
+        assert_eq!(
+            mpoly.validate(),
+            [ValidationError::OverlappingPolygons { part: 1, other: 0 }]
+        );
+
+        // sharing an edge only
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 10.], [0., 10.]]);
+        mpoly.add_exterior([[5., 0.], [10., 0.], [10., 10.], [5., 10.]]);
+        assert!(mpoly.is_valid(), "{:?}", mpoly.validate());
+
+        // many parts, most of which are far apart
+        let mut mpoly = MultiPolygon2::new();
+        for i in 0..300 {
+            let o = (i * 2) as f64;
+            mpoly.add_exterior(square(o, o + 1.));
+        }
+        mpoly.add_exterior(square(100.5, 101.5));
+        assert_eq!(
+            mpoly.validate(),
+            [ValidationError::OverlappingPolygons {
+                part: 300,
+                other: 50
+            }]
+        );
+    }
+}
@@ -0,0 +1,712 @@
+//! Conversions between flatgeom geometries and [GeoArrow] arrays.
+//!
+//! Slices of 2-dimensional geometries are converted into the GeoArrow native encoding with
+//! [`ToGeoArrow`], and read back with [`FromGeoArrow`]:
+//!
+//! | Type             | Extension name          | Array type                  |
+//! |------------------|-------------------------|-----------------------------|
+//! | [`MultiPoint`]   | `geoarrow.multipoint`   | `List<coords>`              |
+//! | [`LineString`]   | `geoarrow.linestring`   | `List<coords>`              |
+//! | [`Polygon`]      | `geoarrow.polygon`      | `List<List<coords>>`        |
+//! | [`MultiPolygon`] | `geoarrow.multipolygon` | `List<List<List<coords>>>`  |
+//!
+//! Coordinates are stored either interleaved (`FixedSizeList<Float64>[2]`) or in separate
+//! `x` and `y` columns (`Struct<x: Float64, y: Float64>`), as selected by [`CoordType`].
+//! Arrow buffers own their memory, so exporting copies each coordinate once. The offsets of
+//! MultiPoints and LineStrings are computed from their lengths, but those of Polygons and
+//! MultiPolygons cannot reuse the hole indices and spans, as rings are closed when exporting
+//! (GeoArrow follows the WKB convention) and every offset shifts by the closing points.
+//!
+//! When importing, MultiPoints and LineStrings borrow interleaved coordinates from the array
+//! without copying (separated coordinates are zipped into a new buffer). Polygons and
+//! MultiPolygons are copied to remove the closing points of their rings.
+//!
+//! Null geometries are read as empty geometries.
+//!
+//! [GeoArrow]: https://geoarrow.org/format.html
+
+use alloc::borrow::Cow;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, Float64Array, ListArray, StructArray};
+use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field, Fields};
+
+use crate::{LineString, MultiPoint, MultiPolygon, Polygon};
+
+/// Metadata key of the Arrow extension type name
+const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Errors reported when reading GeoArrow arrays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The array (or one of its children) does not have the expected data type.
+    UnexpectedDataType {
+        expected: &'static str,
+        found: DataType,
+    },
+    /// The coordinates contain null values.
+    NullCoordinates,
+    /// The list offsets do not form valid geometries.
+    InvalidSpans(crate::Error),
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedDataType { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+            Error::NullCoordinates => write!(f, "coordinates must not be null"),
+            Error::InvalidSpans(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<crate::Error> for Error {
+    fn from(err: crate::Error) -> Self {
+        Error::InvalidSpans(err)
+    }
+}
+
+/// Memory layout of GeoArrow coordinates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CoordType {
+    /// `FixedSizeList<Float64>[2]`, e.g. `[x0, y0, x1, y1, ...]`
+    #[default]
+    Interleaved,
+    /// `Struct<x: Float64, y: Float64>`, e.g. `[x0, x1, ...]` and `[y0, y1, ...]`
+    Separated,
+}
+
+/// Encodes slices of geometries into GeoArrow arrays.
+pub trait ToGeoArrow: Sized {
+    /// The GeoArrow extension type name (e.g. `geoarrow.polygon`)
+    const EXTENSION_NAME: &'static str;
+
+    /// Converts the geometries into a GeoArrow array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an offset does not fit in `i32`.
+    fn to_geoarrow(geoms: &[Self], coord_type: CoordType) -> ListArray;
+
+    /// Returns a nullable field for the arrays returned by [`to_geoarrow`](Self::to_geoarrow),
+    /// tagged with the GeoArrow extension type name.
+    fn geoarrow_field(name: &str, coord_type: CoordType) -> Field {
+        let data_type = Self::to_geoarrow(&[], coord_type).data_type().clone();
+        Field::new(name, data_type, true).with_metadata(
+            [(
+                EXTENSION_NAME_KEY.to_string(),
+                Self::EXTENSION_NAME.to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+/// Decodes geometries from GeoArrow arrays.
+pub trait FromGeoArrow<'a>: Sized {
+    /// Reads all geometries from a GeoArrow array of either [`CoordType`].
+    fn from_geoarrow(array: &'a dyn Array) -> Result<Vec<Self>>;
+}
+
+fn offset(n: usize) -> i32 {
+    i32::try_from(n).expect("offset exceeds i32::MAX")
+}
+
+/// Coordinate buffers being built in the layout of a [`CoordType`]
+enum CoordsBuilder {
+    Interleaved(Vec<f64>),
+    Separated(Vec<f64>, Vec<f64>),
+}
+
+impl CoordsBuilder {
+    fn with_capacity(coord_type: CoordType, capacity: usize) -> Self {
+        match coord_type {
+            CoordType::Interleaved => Self::Interleaved(Vec::with_capacity(2 * capacity)),
+            CoordType::Separated => {
+                Self::Separated(Vec::with_capacity(capacity), Vec::with_capacity(capacity))
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Interleaved(xy) => xy.len() / 2,
+            Self::Separated(x, _) => x.len(),
+        }
+    }
+
+    fn push(&mut self, [x, y]: [f64; 2]) {
+        match self {
+            Self::Interleaved(xy) => xy.extend_from_slice(&[x, y]),
+            Self::Separated(xs, ys) => {
+                xs.push(x);
+                ys.push(y);
+            }
+        }
+    }
+
+    fn extend_from_slice(&mut self, coords: &[[f64; 2]]) {
+        match self {
+            Self::Interleaved(xy) => xy.extend_from_slice(coords.as_flattened()),
+            Self::Separated(xs, ys) => {
+                xs.extend(coords.iter().map(|c| c[0]));
+                ys.extend(coords.iter().map(|c| c[1]));
+            }
+        }
+    }
+
+    fn finish(self) -> ArrayRef {
+        match self {
+            Self::Interleaved(xy) => Arc::new(FixedSizeListArray::new(
+                Arc::new(Field::new("xy", DataType::Float64, false)),
+                2,
+                Arc::new(Float64Array::from(xy)),
+                None,
+            )),
+            Self::Separated(x, y) => Arc::new(StructArray::new(
+                Fields::from(vec![
+                    Field::new("x", DataType::Float64, false),
+                    Field::new("y", DataType::Float64, false),
+                ]),
+                vec![
+                    Arc::new(Float64Array::from(x)),
+                    Arc::new(Float64Array::from(y)),
+                ],
+                None,
+            )),
+        }
+    }
+}
+
+fn list_array(name: &str, offsets: Vec<i32>, values: ArrayRef) -> ListArray {
+    ListArray::new(
+        Arc::new(Field::new(name, values.data_type().clone(), false)),
+        OffsetBuffer::new(ScalarBuffer::from(offsets)),
+        values,
+        None,
+    )
+}
+
+/// Encodes geometries without rings, whose offsets are the lengths of their coordinates.
+fn coords_list_array<'a>(
+    name: &str,
+    geoms: impl Iterator<Item = &'a [[f64; 2]]> + Clone,
+    coord_type: CoordType,
+) -> ListArray {
+    let coords = match coord_type {
+        CoordType::Interleaved => CoordsBuilder::Interleaved(
+            geoms
+                .clone()
+                .flat_map(|c| c.as_flattened())
+                .copied()
+                .collect(),
+        ),
+        CoordType::Separated => CoordsBuilder::Separated(
+            geoms.clone().flatten().map(|c| c[0]).collect(),
+            geoms.clone().flatten().map(|c| c[1]).collect(),
+        ),
+    };
+    let offsets = OffsetBuffer::from_lengths(geoms.map(|c| c.len()));
+    let values = coords.finish();
+    ListArray::new(
+        Arc::new(Field::new(name, values.data_type().clone(), false)),
+        offsets,
+        values,
+        None,
+    )
+}
+
+/// Appends the closed rings of a polygon to `out`, pushing the start offset of each ring.
+fn push_rings(
+    coords: &[[f64; 2]],
+    hole_indices: &[u32],
+    out: &mut CoordsBuilder,
+    ring_offsets: &mut Vec<i32>,
+) {
+    if coords.is_empty() {
+        return;
+    }
+    let starts = core::iter::once(0).chain(hole_indices.iter().map(|&i| i as usize));
+    let ends = hole_indices
+        .iter()
+        .map(|&i| i as usize)
+        .chain(core::iter::once(coords.len()));
+    for (start, end) in starts.zip(ends) {
+        ring_offsets.push(offset(out.len()));
+        out.extend_from_slice(&coords[start..end]);
+        if start < end {
+            out.push(coords[start]);
+        }
+    }
+}
+
+fn as_list(array: &dyn Array) -> Result<&ListArray> {
+    array
+        .as_list_opt::<i32>()
+        .ok_or_else(|| Error::UnexpectedDataType {
+            expected: "List",
+            found: array.data_type().clone(),
+        })
+}
+
+/// Coordinates borrowed from a GeoArrow array
+enum Coords<'a> {
+    Interleaved(&'a [f64]),
+    Separated(&'a [f64], &'a [f64]),
+}
+
+impl<'a> Coords<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<Self> {
+        if let Some(list) = array.as_fixed_size_list_opt() {
+            if let (2, Some(values)) = (
+                list.value_length(),
+                list.values().as_primitive_opt::<Float64Type>(),
+            ) {
+                if list.null_count() > 0 || values.null_count() > 0 {
+                    return Err(Error::NullCoordinates);
+                }
+                return Ok(Coords::Interleaved(values.values()));
+            }
+        } else if let Some(st) = array.as_struct_opt() {
+            if let [x, y] = st.columns() {
+                if let (Some(x), Some(y)) = (
+                    x.as_primitive_opt::<Float64Type>(),
+                    y.as_primitive_opt::<Float64Type>(),
+                ) {
+                    if st.null_count() > 0 || x.null_count() > 0 || y.null_count() > 0 {
+                        return Err(Error::NullCoordinates);
+                    }
+                    return Ok(Coords::Separated(x.values(), y.values()));
+                }
+            }
+        }
+        Err(Error::UnexpectedDataType {
+            expected: "2-dimensional Float64 coordinates",
+            found: array.data_type().clone(),
+        })
+    }
+
+    /// Appends the coordinates in the given range to `out`.
+    fn extend_into(&self, range: Range<usize>, out: &mut Vec<[f64; 2]>) {
+        match self {
+            Coords::Interleaved(xy) => out.extend(
+                xy[2 * range.start..2 * range.end]
+                    .chunks_exact(2)
+                    .map(|c| [c[0], c[1]]),
+            ),
+            Coords::Separated(x, y) => out.extend(
+                x[range.clone()]
+                    .iter()
+                    .zip(&y[range])
+                    .map(|(&x, &y)| [x, y]),
+            ),
+        }
+    }
+
+    /// Returns the coordinates in the given range, borrowing them if they are interleaved.
+    fn slice(&self, range: Range<usize>) -> Cow<'a, [[f64; 2]]> {
+        match self {
+            Coords::Interleaved(xy) => {
+                Cow::Borrowed(xy[2 * range.start..2 * range.end].as_chunks().0)
+            }
+            Coords::Separated(..) => {
+                let mut out = Vec::with_capacity(range.len());
+                self.extend_into(range, &mut out);
+                Cow::Owned(out)
+            }
+        }
+    }
+
+    /// Appends a ring to `out` without its closing point, in the same way as
+    /// [`Polygon::add_ring`]. Returns `false` if the ring is empty.
+    fn extend_ring(&self, range: Range<usize>, out: &mut Vec<[f64; 2]>) -> bool {
+        let head = out.len();
+        self.extend_into(range, out);
+        let tail = out.len();
+        if tail > head + 2 && out[head] == out[tail - 1] {
+            out.truncate(tail - 1);
+        }
+        tail > head
+    }
+}
+
+/// Returns the range of child indices of the `i`-th list
+fn range(offsets: &[i32], i: usize) -> Range<usize> {
+    offsets[i] as usize..offsets[i + 1] as usize
+}
+
+impl ToGeoArrow for MultiPoint<'_, [f64; 2]> {
+    const EXTENSION_NAME: &'static str = "geoarrow.multipoint";
+
+    fn to_geoarrow(geoms: &[Self], coord_type: CoordType) -> ListArray {
+        coords_list_array("points", geoms.iter().map(|g| g.raw_coords()), coord_type)
+    }
+}
+
+impl<'a> FromGeoArrow<'a> for MultiPoint<'a, [f64; 2]> {
+    fn from_geoarrow(array: &'a dyn Array) -> Result<Vec<Self>> {
+        let points = as_list(array)?;
+        let coords = Coords::try_new(points.values())?;
+        let offsets = points.value_offsets();
+        Ok((0..points.len())
+            .map(|i| match points.is_valid(i) {
+                true => MultiPoint::from_raw(coords.slice(range(offsets, i))),
+                false => MultiPoint::new(),
+            })
+            .collect())
+    }
+}
+
+impl ToGeoArrow for LineString<'_, [f64; 2]> {
+    const EXTENSION_NAME: &'static str = "geoarrow.linestring";
+
+    fn to_geoarrow(geoms: &[Self], coord_type: CoordType) -> ListArray {
+        coords_list_array("vertices", geoms.iter().map(|g| g.raw_coords()), coord_type)
+    }
+}
+
+impl<'a> FromGeoArrow<'a> for LineString<'a, [f64; 2]> {
+    fn from_geoarrow(array: &'a dyn Array) -> Result<Vec<Self>> {
+        let vertices = as_list(array)?;
+        let coords = Coords::try_new(vertices.values())?;
+        let offsets = vertices.value_offsets();
+        Ok((0..vertices.len())
+            .map(|i| match vertices.is_valid(i) {
+                true => LineString::from_raw(coords.slice(range(offsets, i))),
+                false => LineString::new(),
+            })
+            .collect())
+    }
+}
+
+impl ToGeoArrow for Polygon<'_, [f64; 2]> {
+    const EXTENSION_NAME: &'static str = "geoarrow.polygon";
+
+    fn to_geoarrow(geoms: &[Self], coord_type: CoordType) -> ListArray {
+        // each ring gets a closing point
+        let num_rings: usize = geoms.iter().map(|p| p.len()).sum();
+        let len = geoms.iter().map(|p| p.raw_coords().len()).sum::<usize>() + num_rings;
+        let mut coords = CoordsBuilder::with_capacity(coord_type, len);
+        let mut vertex_offsets = Vec::with_capacity(num_rings + 1);
+        let mut ring_offsets = Vec::with_capacity(geoms.len() + 1);
+        ring_offsets.push(0);
+        for poly in geoms {
+            push_rings(
+                poly.raw_coords(),
+                poly.hole_indices(),
+                &mut coords,
+                &mut vertex_offsets,
+            );
+            ring_offsets.push(offset(vertex_offsets.len()));
+        }
+        vertex_offsets.push(offset(coords.len()));
+        let rings = list_array("vertices", vertex_offsets, coords.finish());
+        list_array("rings", ring_offsets, Arc::new(rings))
+    }
+}
+
+impl FromGeoArrow<'_> for Polygon<'static, [f64; 2]> {
+    fn from_geoarrow(array: &dyn Array) -> Result<Vec<Self>> {
+        let rings = as_list(array)?;
+        let vertices = as_list(rings.values())?;
+        let coords = Coords::try_new(vertices.values())?;
+        let (ring_offsets, vertex_offsets) = (rings.value_offsets(), vertices.value_offsets());
+        (0..rings.len())
+            .map(|i| {
+                if rings.is_null(i) {
+                    return Ok(Polygon::new());
+                }
+                let (mut poly_coords, mut hole_indices) = (Vec::new(), Vec::new());
+                for r in range(ring_offsets, i) {
+                    let start = poly_coords.len();
+                    if coords.extend_ring(range(vertex_offsets, r), &mut poly_coords) && start > 0 {
+                        hole_indices.push(start as u32);
+                    }
+                }
+                Ok(Polygon::try_from_raw(
+                    poly_coords.into(),
+                    hole_indices.into(),
+                )?)
+            })
+            .collect()
+    }
+}
+
+impl ToGeoArrow for MultiPolygon<'_, [f64; 2]> {
+    const EXTENSION_NAME: &'static str = "geoarrow.multipolygon";
+
+    fn to_geoarrow(geoms: &[Self], coord_type: CoordType) -> ListArray {
+        // each ring gets a closing point
+        let num_polygons: usize = geoms.iter().map(|m| m.len()).sum();
+        let num_rings = num_polygons
+            + geoms
+                .iter()
+                .map(|m| m.all_hole_indices().len())
+                .sum::<usize>();
+        let len = geoms.iter().map(|m| m.raw_coords().len()).sum::<usize>() + num_rings;
+        let mut coords = CoordsBuilder::with_capacity(coord_type, len);
+        let mut vertex_offsets = Vec::with_capacity(num_rings + 1);
+        let mut ring_offsets = Vec::with_capacity(num_polygons + 1);
+        let mut polygon_offsets = Vec::with_capacity(geoms.len() + 1);
+        ring_offsets.push(0);
+        polygon_offsets.push(0);
+        for mpoly in geoms {
+            for poly in mpoly {
+                push_rings(
+                    poly.raw_coords(),
+                    poly.hole_indices(),
+                    &mut coords,
+                    &mut vertex_offsets,
+                );
+                ring_offsets.push(offset(vertex_offsets.len()));
+            }
+            polygon_offsets.push(offset(ring_offsets.len() - 1));
+        }
+        vertex_offsets.push(offset(coords.len()));
+        let rings = list_array("vertices", vertex_offsets, coords.finish());
+        let polygons = list_array("rings", ring_offsets, Arc::new(rings));
+        list_array("polygons", polygon_offsets, Arc::new(polygons))
+    }
+}
+
+impl FromGeoArrow<'_> for MultiPolygon<'static, [f64; 2]> {
+    fn from_geoarrow(array: &dyn Array) -> Result<Vec<Self>> {
+        let polygons = as_list(array)?;
+        let rings = as_list(polygons.values())?;
+        let vertices = as_list(rings.values())?;
+        let coords = Coords::try_new(vertices.values())?;
+        let (polygon_offsets, ring_offsets, vertex_offsets) = (
+            polygons.value_offsets(),
+            rings.value_offsets(),
+            vertices.value_offsets(),
+        );
+        (0..polygons.len())
+            .map(|i| {
+                if polygons.is_null(i) {
+                    return Ok(MultiPolygon::new());
+                }
+                let mut all_coords = Vec::new();
+                let (mut coords_spans, mut holes_spans) = (Vec::new(), Vec::new());
+                let mut all_hole_indices = Vec::new();
+                for p in range(polygon_offsets, i) {
+                    let poly_start = all_coords.len();
+                    for r in range(ring_offsets, p) {
+                        let start = all_coords.len();
+                        if !coords.extend_ring(range(vertex_offsets, r), &mut all_coords) {
+                            continue;
+                        }
+                        if start > poly_start {
+                            all_hole_indices.push((start - poly_start) as u32);
+                        } else if start > 0 {
+                            coords_spans.push(start as u32);
+                            holes_spans.push(all_hole_indices.len() as u32);
+                        }
+                    }
+                }
+                Ok(MultiPolygon::try_from_raw(
+                    all_coords.into(),
+                    coords_spans.into(),
+                    all_hole_indices.into(),
+                    holes_spans.into(),
+                )?)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, MultiPoint2, MultiPolygon2, Polygon2};
+    use arrow_buffer::NullBuffer;
+
+    fn mpolys() -> Vec<MultiPolygon2<'static>> {
+        let mut a = MultiPolygon2::new();
+        a.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        a.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        a.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        let mut b = MultiPolygon2::new();
+        b.add_exterior([[10., 10.], [11., 10.], [11., 11.]]);
+        vec![a, MultiPolygon2::new(), b]
+    }
+
+    #[test]
+    fn test_multipolygon() {
+        let mpolys = mpolys();
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let array = MultiPolygon2::to_geoarrow(&mpolys, coord_type);
+            assert_eq!(array.len(), 3);
+            assert_eq!(array.value_offsets(), [0, 2, 2, 3]);
+            let rings = array.values().as_list::<i32>();
+            assert_eq!(rings.value_offsets(), [0, 2, 3, 4]);
+            let vertices = rings.values().as_list::<i32>();
+            assert_eq!(vertices.value_offsets(), [0, 5, 10, 14, 18]);
+
+            let decoded = MultiPolygon2::from_geoarrow(&array).unwrap();
+            assert_eq!(decoded, mpolys);
+        }
+
+        let array = MultiPolygon2::to_geoarrow(&mpolys, CoordType::Separated);
+        let decoded = MultiPolygon2::from_geoarrow(&array.slice(2, 1)).unwrap();
+        assert_eq!(decoded, mpolys[2..]);
+    }
+
+    #[test]
+    fn test_polygon() {
+        let mut a = Polygon2::new();
+        a.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        a.add_ring([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        let mut b = Polygon2::new();
+        b.add_ring([[6., 0.], [7., 0.], [7., 1.], [6., 0.]]);
+        let polys = vec![a, Polygon2::new(), b];
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let array = Polygon2::to_geoarrow(&polys, coord_type);
+            assert_eq!(array.value_offsets(), [0, 2, 2, 3]);
+            let vertices = array.values().as_list::<i32>();
+            assert_eq!(vertices.value_offsets(), [0, 5, 10, 14]);
+            assert_eq!(Polygon2::from_geoarrow(&array).unwrap(), polys);
+        }
+    }
+
+    #[test]
+    fn test_linestring_and_multipoint() {
+        let lss = vec![
+            LineString2::from_raw(vec![[0., 0.], [1., 1.]].into()),
+            LineString2::from_raw(vec![[2., 2.], [3., 3.], [4., 4.]].into()),
+        ];
+        let mpoints = vec![
+            MultiPoint2::from_raw(vec![[0., 0.]].into()),
+            MultiPoint2::new(),
+            MultiPoint2::from_raw(vec![[1., 2.], [3., 4.]].into()),
+        ];
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let array = LineString2::to_geoarrow(&lss, coord_type);
+            assert_eq!(array.value_offsets(), [0, 2, 5]);
+            assert_eq!(LineString2::from_geoarrow(&array).unwrap(), lss);
+
+            let array = MultiPoint2::to_geoarrow(&mpoints, coord_type);
+            assert_eq!(array.value_offsets(), [0, 1, 1, 3]);
+            assert_eq!(MultiPoint2::from_geoarrow(&array).unwrap(), mpoints);
+        }
+    }
+
+    #[test]
+    fn test_borrow_coords() {
+        let lss = vec![
+            LineString2::from_raw(vec![[0., 0.], [1., 1.]].into()),
+            LineString2::from_raw(vec![[2., 2.], [3., 3.], [4., 4.]].into()),
+        ];
+        let array = LineString2::to_geoarrow(&lss, CoordType::Interleaved);
+        let decoded = LineString2::from_geoarrow(&array).unwrap();
+        assert_eq!(decoded, lss);
+        let values = array.values().as_fixed_size_list().values();
+        let xy = values.as_primitive::<Float64Type>().values();
+        // the interleaved coordinates are borrowed from the array
+        assert_eq!(
+            decoded[1].raw_coords().as_ptr() as *const f64,
+            xy[4..].as_ptr()
+        );
+
+        let array = MultiPoint2::to_geoarrow(
+            &[MultiPoint2::from_raw(vec![[1., 2.]].into())],
+            CoordType::Separated,
+        );
+        let decoded = MultiPoint2::from_geoarrow(&array).unwrap();
+        assert_eq!(decoded[0].raw_coords(), [[1., 2.]]);
+    }
+
+    #[test]
+    fn test_coords_layout() {
+        let ls = [LineString2::from_raw(vec![[1., 2.], [3., 4.]].into())];
+
+        let array = LineString2::to_geoarrow(&ls, CoordType::Interleaved);
+        let xy = array.values().as_fixed_size_list();
+        assert_eq!(
+            &xy.values().as_primitive::<Float64Type>().values()[..],
+            [1., 2., 3., 4.]
+        );
+
+        let array = LineString2::to_geoarrow(&ls, CoordType::Separated);
+        let columns = array.values().as_struct();
+        assert_eq!(columns.column_names(), ["x", "y"]);
+        assert_eq!(
+            &columns.column(1).as_primitive::<Float64Type>().values()[..],
+            [2., 4.]
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        let field = MultiPolygon2::geoarrow_field("geometry", CoordType::Separated);
+        assert_eq!(field.name(), "geometry");
+        assert!(field.is_nullable());
+        assert_eq!(
+            field.metadata()[EXTENSION_NAME_KEY],
+            "geoarrow.multipolygon"
+        );
+        let array = MultiPolygon2::to_geoarrow(&mpolys(), CoordType::Separated);
+        assert_eq!(field.data_type(), array.data_type());
+    }
+
+    #[test]
+    fn test_nulls() {
+        let array = LineString2::to_geoarrow(
+            &[LineString2::from_raw(vec![[0., 0.], [1., 1.]].into())],
+            CoordType::Interleaved,
+        );
+        let (field, offsets, values, _) = array.into_parts();
+        let array = ListArray::new(field, offsets, values, Some(NullBuffer::new_null(1)));
+        let decoded = LineString2::from_geoarrow(&array).unwrap();
+        assert!(decoded[0].is_empty());
+
+        let coords = FixedSizeListArray::new(
+            Arc::new(Field::new("xy", DataType::Float64, true)),
+            2,
+            Arc::new(Float64Array::from(vec![Some(0.), None])),
+            None,
+        );
+        let array = list_array("vertices", vec![0, 1], Arc::new(coords));
+        assert_eq!(
+            LineString2::from_geoarrow(&array),
+            Err(Error::NullCoordinates)
+        );
+    }
+
+    #[test]
+    fn test_unexpected_data_type() {
+        let array = LineString2::to_geoarrow(&[], CoordType::Interleaved);
+        assert!(matches!(
+            Polygon2::from_geoarrow(&array),
+            Err(Error::UnexpectedDataType {
+                expected: "List",
+                ..
+            })
+        ));
+
+        let array = list_array(
+            "vertices",
+            vec![0, 2],
+            Arc::new(Float64Array::from(vec![0., 1.])),
+        );
+        let err = LineString2::from_geoarrow(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected 2-dimensional Float64 coordinates but found Float64"
+        );
+    }
+}
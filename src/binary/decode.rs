@@ -0,0 +1,482 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::mem::{align_of, size_of};
+
+use super::{code, Error, Result, Scalar, ALIGNMENT, HEADER_SIZE, MAGIC, VERSION};
+use crate::{
+    Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point,
+    Polygon, Triangle,
+};
+
+/// Maximum nesting depth of GeometryCollections
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Decodes geometries from the binary format described in the [module documentation](super).
+///
+/// The buffers of the returned geometry borrow from `bytes`. Data after the geometry is ignored.
+pub trait FromBytes<'a>: Sized {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self>;
+}
+
+/// Element types of the buffers
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value, and the type must have no padding.
+unsafe trait Element: Clone {
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+unsafe impl Element for u8 {
+    fn read_le(bytes: &[u8]) -> Self {
+        bytes[0]
+    }
+}
+
+unsafe impl Element for u32 {
+    fn read_le(bytes: &[u8]) -> Self {
+        u32::from_le_bytes(bytes.try_into().unwrap())
+    }
+}
+
+unsafe impl<const D: usize, N: Scalar> Element for [N; D] {
+    fn read_le(bytes: &[u8]) -> Self {
+        core::array::from_fn(|i| N::read_le(&bytes[i * size_of::<N>()..][..size_of::<N>()]))
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    lens: Vec<usize>,
+    next: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Reads the header, returning the geometry type code.
+    fn header<const D: usize, N: Scalar>(bytes: &'a [u8]) -> Result<(Self, u8)> {
+        let header = bytes.get(..HEADER_SIZE).ok_or(Error::UnexpectedEof)?;
+        if &header[..4] != MAGIC {
+            return Err(Error::InvalidMagic);
+        }
+        let [version, kind, dim, tag] = [header[4], header[5], header[6], header[7]];
+        if version != VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+        if dim as usize != D {
+            return Err(Error::MismatchedDimension {
+                expected: D,
+                found: dim as usize,
+            });
+        }
+        if tag != N::TAG {
+            return Err(Error::MismatchedCoordType {
+                expected: N::TAG,
+                found: tag,
+            });
+        }
+        let num_buffers = match kind {
+            code::POINT | code::MULTIPOINT | code::LINE | code::LINESTRING | code::TRIANGLE => 1,
+            code::MULTILINESTRING
+            | code::POLYGON
+            | code::MULTITRIANGLE
+            | code::GEOMETRYCOLLECTION => 2,
+            code::MULTIPOLYGON => 4,
+            code => return Err(Error::UnknownGeometryType { code }),
+        };
+        let lens_end = HEADER_SIZE + 4 * num_buffers;
+        let lens = bytes
+            .get(HEADER_SIZE..lens_end)
+            .ok_or(Error::UnexpectedEof)?
+            .chunks_exact(4)
+            .map(|b| <u32 as Element>::read_le(b) as usize)
+            .collect();
+        let reader = Self {
+            bytes,
+            pos: lens_end.next_multiple_of(ALIGNMENT),
+            lens,
+            next: 0,
+        };
+        Ok((reader, kind))
+    }
+
+    /// Reads the raw bytes of the next buffer, whose elements are `elem_size` bytes long.
+    ///
+    /// Returns the bytes and their offset in the input.
+    fn raw_buffer(&mut self, elem_size: usize) -> Result<(&'a [u8], usize)> {
+        let len = self.lens[self.next];
+        self.next += 1;
+        let offset = self.pos;
+        if len == 0 {
+            return Ok((&[], offset));
+        }
+        let size = len.checked_mul(elem_size).ok_or(Error::UnexpectedEof)?;
+        let data = self
+            .bytes
+            .get(self.pos..)
+            .and_then(|rest| rest.get(..size))
+            .ok_or(Error::UnexpectedEof)?;
+        self.pos = (self.pos + size).next_multiple_of(ALIGNMENT);
+        Ok((data, offset))
+    }
+
+    /// Reads the next buffer of bytes.
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        self.raw_buffer(1).map(|(data, _)| data)
+    }
+
+    /// Reads the next buffer, borrowing it if possible.
+    fn buffer<T: Element>(&mut self) -> Result<Cow<'a, [T]>> {
+        let (data, offset) = self.raw_buffer(size_of::<T>())?;
+        if data.is_empty() {
+            return Ok(Cow::Borrowed(&[]));
+        }
+        let len = data.len() / size_of::<T>();
+        if cfg!(target_endian = "big") {
+            return Ok(Cow::Owned(
+                data.chunks_exact(size_of::<T>()).map(T::read_le).collect(),
+            ));
+        }
+        if data.as_ptr().align_offset(align_of::<T>()) != 0 {
+            return Err(Error::Misaligned { offset });
+        }
+        // SAFETY: `data` holds `len` little-endian values of `T`, for which any bit pattern is
+        // valid, and its pointer is aligned for `T`.
+        let slice = unsafe { core::slice::from_raw_parts(data.as_ptr().cast::<T>(), len) };
+        Ok(Cow::Borrowed(slice))
+    }
+
+    /// Reads the coordinate buffer of a geometry with a fixed number of coordinates.
+    fn fixed_coords<T: Element>(&mut self, expected: usize) -> Result<Cow<'a, [T]>> {
+        let coords = self.buffer::<T>()?;
+        if coords.len() != expected {
+            return Err(Error::InvalidCoordCount {
+                expected,
+                found: coords.len(),
+            });
+        }
+        Ok(coords)
+    }
+}
+
+/// Reads the header and checks the geometry type.
+fn reader<'a, const D: usize, N: Scalar>(bytes: &'a [u8], expected: u8) -> Result<Reader<'a>> {
+    let (reader, kind) = Reader::header::<D, N>(bytes)?;
+    if kind != expected {
+        return Err(Error::MismatchedGeometryType {
+            expected: code::name(expected),
+            found: code::name(kind),
+        });
+    }
+    Ok(reader)
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for Point<[N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let coords = reader::<D, N>(bytes, code::POINT)?.fixed_coords::<[N; D]>(1)?;
+        Ok(Point::new(coords[0]))
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for MultiPoint<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let coords = reader::<D, N>(bytes, code::MULTIPOINT)?.buffer()?;
+        Ok(MultiPoint::from_raw(coords))
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for Line<[N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let coords = reader::<D, N>(bytes, code::LINE)?.fixed_coords::<[N; D]>(2)?;
+        Ok(Line::new(coords[0], coords[1]))
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for LineString<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let coords = reader::<D, N>(bytes, code::LINESTRING)?.buffer()?;
+        Ok(LineString::from_raw(coords))
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for MultiLineString<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = reader::<D, N>(bytes, code::MULTILINESTRING)?;
+        let spans = reader.buffer()?;
+        let coords = reader.buffer()?;
+        Ok(MultiLineString::try_from_raw(coords, spans)?)
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for Polygon<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = reader::<D, N>(bytes, code::POLYGON)?;
+        let hole_indices = reader.buffer()?;
+        let coords = reader.buffer()?;
+        Ok(Polygon::try_from_raw(coords, hole_indices)?)
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for MultiPolygon<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = reader::<D, N>(bytes, code::MULTIPOLYGON)?;
+        let coords_spans = reader.buffer()?;
+        let all_hole_indices = reader.buffer()?;
+        let holes_spans = reader.buffer()?;
+        let coords = reader.buffer()?;
+        Ok(MultiPolygon::try_from_raw(
+            coords,
+            coords_spans,
+            all_hole_indices,
+            holes_spans,
+        )?)
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for Triangle<[N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let coords = reader::<D, N>(bytes, code::TRIANGLE)?.fixed_coords::<[N; D]>(3)?;
+        Ok(Triangle::new(coords[0], coords[1], coords[2]))
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for MultiTriangle<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = reader::<D, N>(bytes, code::MULTITRIANGLE)?;
+        let indices = reader.buffer()?;
+        let coords = reader.buffer()?;
+        Ok(MultiTriangle::try_from_raw(coords, indices)?)
+    }
+}
+
+impl<'a, const D: usize, N: Scalar> FromBytes<'a> for Geometry<'a, [N; D]> {
+    fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        geometry(bytes, 0)
+    }
+}
+
+/// Reads a geometry of any type, nested in GeometryCollections `depth` times.
+fn geometry<'a, const D: usize, N: Scalar>(
+    bytes: &'a [u8],
+    depth: usize,
+) -> Result<Geometry<'a, [N; D]>> {
+    let (mut reader, kind) = Reader::header::<D, N>(bytes)?;
+    Ok(match kind {
+        code::POINT => Geometry::Point(FromBytes::from_bytes(bytes)?),
+        code::MULTIPOINT => Geometry::MultiPoint(FromBytes::from_bytes(bytes)?),
+        code::LINE => Geometry::Line(FromBytes::from_bytes(bytes)?),
+        code::LINESTRING => Geometry::LineString(FromBytes::from_bytes(bytes)?),
+        code::MULTILINESTRING => Geometry::MultiLineString(FromBytes::from_bytes(bytes)?),
+        code::POLYGON => Geometry::Polygon(FromBytes::from_bytes(bytes)?),
+        code::MULTIPOLYGON => Geometry::MultiPolygon(FromBytes::from_bytes(bytes)?),
+        code::TRIANGLE => Geometry::Triangle(FromBytes::from_bytes(bytes)?),
+        code::MULTITRIANGLE => Geometry::MultiTriangle(FromBytes::from_bytes(bytes)?),
+        _ => {
+            if depth >= MAX_NESTING_DEPTH {
+                return Err(Error::NestingTooDeep);
+            }
+            let sizes = reader.buffer::<u32>()?;
+            let members = reader.bytes()?;
+            let mut pos: usize = 0;
+            let mut geoms = Vec::with_capacity(sizes.len());
+            for (index, &size) in sizes.iter().enumerate() {
+                let size = size as usize;
+                if !size.is_multiple_of(ALIGNMENT) {
+                    return Err(Error::InvalidMemberSize { index });
+                }
+                let end = pos.checked_add(size).ok_or(Error::UnexpectedEof)?;
+                let member = members.get(pos..end).ok_or(Error::UnexpectedEof)?;
+                geoms.push(geometry(member, depth + 1)?);
+                pos = end;
+            }
+            Geometry::GeometryCollection(geoms)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ToBytes;
+    use super::*;
+    use crate::{
+        Geometry2, Geometry3, LineString2, MultiLineString3, MultiPoint2, MultiPolygon2,
+        MultiTriangle3, Polygon2,
+    };
+    use alloc::{string::ToString, vec};
+
+    /// Copies the bytes into an 8-byte aligned location, optionally shifted by `shift` bytes.
+    fn aligned(bytes: &[u8], shift: usize, buf: &mut Vec<u8>) -> core::ops::Range<usize> {
+        buf.clear();
+        buf.resize(bytes.len() + 16, 0);
+        let start = buf.as_ptr().align_offset(8) + shift;
+        buf[start..start + bytes.len()].copy_from_slice(bytes);
+        start..start + bytes.len()
+    }
+
+    #[test]
+    fn test_layout() {
+        let ls = LineString2::from_raw(vec![[1., 2.], [3., 4.]].into());
+        let bytes = ls.to_bytes();
+        assert_eq!(&bytes[..8], [b'F', b'G', b'E', b'O', 1, 4, 2, 10]);
+        assert_eq!(&bytes[8..12], 2u32.to_le_bytes());
+        assert_eq!(&bytes[12..16], [0; 4]);
+        assert_eq!(&bytes[16..24], 1f64.to_le_bytes());
+        assert_eq!(bytes.len(), 16 + 32);
+
+        let mut poly = Polygon2::<f32>::new();
+        poly.add_ring([[0., 0.], [4., 0.], [4., 4.]]);
+        poly.add_ring([[1., 1.], [2., 1.], [2., 2.]]);
+        let bytes = poly.to_bytes();
+        assert_eq!(&bytes[..8], [b'F', b'G', b'E', b'O', 1, 6, 2, 9]);
+        assert_eq!(&bytes[8..16], [1, 0, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(&bytes[16..24], [3, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes.len(), 24 + 6 * 8);
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        mpoly.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        mpoly.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        let bytes = mpoly.to_bytes();
+
+        let mut buf = Vec::new();
+        let range = aligned(&bytes, 0, &mut buf);
+        let input = &buf[range.clone()];
+        let decoded = MultiPolygon2::from_bytes(input).unwrap();
+        assert_eq!(decoded, mpoly);
+        // the coordinates point into the input
+        let coords = decoded.raw_coords().as_ptr() as usize;
+        assert!(input.as_ptr_range().contains(&(coords as *const u8)));
+
+        let range = aligned(&bytes, 4, &mut buf);
+        assert!(matches!(
+            MultiPolygon2::<f64>::from_bytes(&buf[range]),
+            Err(Error::Misaligned { .. })
+        ));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        poly.add_ring([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        let geoms = vec![
+            Geometry2::Point([1., 2.].into()),
+            Geometry2::MultiPoint(MultiPoint2::from_raw(vec![[1., 2.], [3., 4.]].into())),
+            Geometry2::Line(Line::new([0., 0.], [1., 1.])),
+            Geometry2::LineString(LineString2::from_raw(vec![[1., 2.], [3., 4.]].into())),
+            Geometry2::Polygon(poly.clone()),
+            Geometry2::Triangle(Triangle::new([0., 0.], [1., 0.], [0., 1.])),
+            Geometry2::GeometryCollection(vec![
+                Geometry2::Polygon(poly),
+                Geometry2::GeometryCollection(vec![]),
+                Geometry2::Point([5., 6.].into()),
+            ]),
+        ];
+        let mut buf = Vec::new();
+        for geom in geoms {
+            let bytes = geom.to_bytes();
+            let range = aligned(&bytes, 0, &mut buf);
+            let decoded = Geometry2::<f64>::from_bytes(&buf[range]).unwrap();
+            assert_eq!(decoded.to_string(), geom.to_string());
+            assert_eq!(decoded.to_bytes(), bytes);
+        }
+
+        let mut mls = MultiLineString3::new();
+        mls.add_linestring([[0., 0., 1.], [5., 0., 2.]]);
+        mls.add_linestring([[1., 0., 3.], [5., 0., 4.], [5., 5., 5.]]);
+        let mut mtri = MultiTriangle3::new();
+        mtri.push(&Triangle::new([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]));
+        for geom in [
+            Geometry3::MultiLineString(mls),
+            Geometry3::MultiTriangle(mtri),
+        ] {
+            let bytes = geom.to_bytes();
+            let range = aligned(&bytes, 0, &mut buf);
+            let decoded = Geometry3::<f64>::from_bytes(&buf[range]).unwrap();
+            assert_eq!(decoded.to_string(), geom.to_string());
+        }
+
+        // integer coordinates
+        let ls = LineString::from_raw(vec![[1u16, 2], [3, 4], [5, 6]].into());
+        let bytes = ls.to_bytes();
+        let range = aligned(&bytes, 0, &mut buf);
+        assert_eq!(LineString::from_bytes(&buf[range]).unwrap(), ls);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of dimensions exceeds u8::MAX")]
+    fn test_too_many_dimensions() {
+        Point::new([0f64; 256]).to_bytes();
+    }
+
+    #[test]
+    fn test_nesting_depth() {
+        let nested = |n: usize| {
+            let mut geom = Geometry2::<f64>::GeometryCollection(vec![]);
+            for _ in 0..n {
+                geom = Geometry2::GeometryCollection(vec![geom]);
+            }
+            geom.to_bytes()
+        };
+        let mut buf = Vec::new();
+        let bytes = nested(63);
+        let range = aligned(&bytes, 0, &mut buf);
+        assert!(Geometry2::<f64>::from_bytes(&buf[range]).is_ok());
+        let bytes = nested(64);
+        let range = aligned(&bytes, 0, &mut buf);
+        assert!(matches!(
+            Geometry2::<f64>::from_bytes(&buf[range]),
+            Err(Error::NestingTooDeep)
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        let bytes = Point::new([1., 2.]).to_bytes();
+        let mut buf = Vec::new();
+        let range = aligned(&bytes, 0, &mut buf);
+        let input = &buf[range];
+        assert_eq!(
+            LineString2::<f64>::from_bytes(input),
+            Err(Error::MismatchedGeometryType {
+                expected: "LineString",
+                found: "Point"
+            })
+        );
+        assert_eq!(
+            Point::<[f64; 3]>::from_bytes(input),
+            Err(Error::MismatchedDimension {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Point::<[f32; 2]>::from_bytes(input),
+            Err(Error::MismatchedCoordType {
+                expected: 9,
+                found: 10
+            })
+        );
+        assert_eq!(
+            Point::<[f64; 2]>::from_bytes(&input[..20]),
+            Err(Error::UnexpectedEof)
+        );
+        assert_eq!(
+            Point::<[f64; 2]>::from_bytes(b"GEOM0000"),
+            Err(Error::InvalidMagic)
+        );
+
+        // invalid spans are rejected
+        let mut bytes =
+            Polygon2::from_raw(vec![[0., 0.], [1., 0.], [1., 1.]].into(), vec![1].into())
+                .to_bytes();
+        bytes[16] = 5;
+        let range = aligned(&bytes, 0, &mut buf);
+        assert!(matches!(
+            Polygon2::<f64>::from_bytes(&buf[range]),
+            Err(Error::InvalidSpans(_))
+        ));
+    }
+}
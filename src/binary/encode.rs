@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use super::{code, Scalar, ALIGNMENT, HEADER_SIZE, MAGIC, VERSION};
+use crate::{
+    Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point,
+    Polygon, Triangle,
+};
+
+/// Encodes geometries into the binary format described in the [module documentation](super).
+pub trait ToBytes {
+    /// Appends the binary representation to `out`.
+    ///
+    /// Alignment is relative to the current end of `out`, so it should be a multiple
+    /// of 8 bytes long for the result to be readable in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a buffer length does not fit in `u32`, or the number of dimensions in `u8`.
+    fn write_bytes(&self, out: &mut Vec<u8>);
+
+    /// Returns the binary representation.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_bytes(&mut out);
+        out
+    }
+}
+
+fn length(n: usize) -> u32 {
+    u32::try_from(n).expect("length exceeds u32::MAX")
+}
+
+struct Writer<'o> {
+    out: &'o mut Vec<u8>,
+    start: usize,
+}
+
+impl<'o> Writer<'o> {
+    fn new<const D: usize, N: Scalar>(out: &'o mut Vec<u8>, kind: u8, lens: &[usize]) -> Self {
+        let start = out.len();
+        out.extend(MAGIC);
+        let dims = u8::try_from(D).expect("number of dimensions exceeds u8::MAX");
+        out.extend([VERSION, kind, dims, N::TAG]);
+        debug_assert_eq!(out.len() - start, HEADER_SIZE);
+        for &len in lens {
+            out.extend(length(len).to_le_bytes());
+        }
+        let mut writer = Self { out, start };
+        writer.pad();
+        writer
+    }
+
+    fn pad(&mut self) {
+        let len = self.out.len() - self.start;
+        self.out.resize(
+            self.out.len() + (ALIGNMENT - len % ALIGNMENT) % ALIGNMENT,
+            0,
+        );
+    }
+
+    fn spans(&mut self, spans: &[u32]) -> &mut Self {
+        for &v in spans {
+            self.out.extend(v.to_le_bytes());
+        }
+        self.pad();
+        self
+    }
+
+    fn coords<const D: usize, N: Scalar>(&mut self, coords: &[[N; D]]) -> &mut Self {
+        for coord in coords {
+            for &v in coord {
+                v.write_le(self.out);
+            }
+        }
+        self.pad();
+        self
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for Point<[N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        Writer::new::<D, N>(out, code::POINT, &[1]).coords(&[*self.coord()]);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for MultiPoint<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let coords = self.raw_coords();
+        Writer::new::<D, N>(out, code::MULTIPOINT, &[coords.len()]).coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for Line<[N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        Writer::new::<D, N>(out, code::LINE, &[2]).coords(self.raw_coords());
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for LineString<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let coords = self.raw_coords();
+        Writer::new::<D, N>(out, code::LINESTRING, &[coords.len()]).coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for MultiLineString<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let (spans, coords) = (self.coords_spans(), self.raw_coords());
+        Writer::new::<D, N>(out, code::MULTILINESTRING, &[spans.len(), coords.len()])
+            .spans(spans)
+            .coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for Polygon<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let (holes, coords) = (self.hole_indices(), self.raw_coords());
+        Writer::new::<D, N>(out, code::POLYGON, &[holes.len(), coords.len()])
+            .spans(holes)
+            .coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for MultiPolygon<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let (coords_spans, all_hole_indices, holes_spans) = (
+            self.coords_spans(),
+            self.all_hole_indices(),
+            self.holes_spans(),
+        );
+        let coords = self.raw_coords();
+        let lens = [
+            coords_spans.len(),
+            all_hole_indices.len(),
+            holes_spans.len(),
+            coords.len(),
+        ];
+        Writer::new::<D, N>(out, code::MULTIPOLYGON, &lens)
+            .spans(coords_spans)
+            .spans(all_hole_indices)
+            .spans(holes_spans)
+            .coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for Triangle<[N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        Writer::new::<D, N>(out, code::TRIANGLE, &[3]).coords(self.raw_coords());
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for MultiTriangle<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        let (indices, coords) = (self.raw_indices(), self.raw_coords());
+        Writer::new::<D, N>(out, code::MULTITRIANGLE, &[indices.len(), coords.len()])
+            .spans(indices)
+            .coords(coords);
+    }
+}
+
+impl<const D: usize, N: Scalar> ToBytes for Geometry<'_, [N; D]> {
+    fn write_bytes(&self, out: &mut Vec<u8>) {
+        match self {
+            Geometry::Point(geom) => geom.write_bytes(out),
+            Geometry::MultiPoint(geom) => geom.write_bytes(out),
+            Geometry::Line(geom) => geom.write_bytes(out),
+            Geometry::LineString(geom) => geom.write_bytes(out),
+            Geometry::MultiLineString(geom) => geom.write_bytes(out),
+            Geometry::Polygon(geom) => geom.write_bytes(out),
+            Geometry::MultiPolygon(geom) => geom.write_bytes(out),
+            Geometry::Triangle(geom) => geom.write_bytes(out),
+            Geometry::MultiTriangle(geom) => geom.write_bytes(out),
+            Geometry::GeometryCollection(geoms) => {
+                let mut members = Vec::new();
+                let sizes: Vec<u32> = geoms
+                    .iter()
+                    .map(|geom| {
+                        let start = members.len();
+                        geom.write_bytes(&mut members);
+                        length(members.len() - start)
+                    })
+                    .collect();
+                let lens = [sizes.len(), members.len()];
+                let mut writer = Writer::new::<D, N>(out, code::GEOMETRYCOLLECTION, &lens);
+                writer.spans(&sizes);
+                writer.out.extend(members);
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Zero-copy binary serialization.
+//!
+//! Geometries are written with [`ToBytes`] and read back with [`FromBytes`]. Reading borrows
+//! the coordinate and span buffers directly from the input (as [`Cow::Borrowed`]), so
+//! geometries can be used straight from memory-mapped files without copying.
+//!
+//! # Layout (version 1)
+//!
+//! All values are little-endian.
+//!
+//! | Offset | Size    | Content                                               |
+//! |--------|---------|-------------------------------------------------------|
+//! | 0      | 4       | Magic bytes `FGEO`                                    |
+//! | 4      | 1       | Format version (`1`)                                  |
+//! | 5      | 1       | Geometry type (see below)                             |
+//! | 6      | 1       | Dimension of the coordinates                          |
+//! | 7      | 1       | Coordinate type (see below)                           |
+//! | 8      | 4 × _k_ | `u32` length of each of the _k_ buffers (in elements) |
+//!
+//! The header is followed by the _k_ buffers of the geometry type, each starting at
+//! an offset that is a multiple of 8 from the start of the geometry (padded with zeros).
+//! Span buffers are arrays of `u32`, and the coordinate buffer is an array of coordinates,
+//! each consisting of _dimension_ numbers of the coordinate type.
+//!
+//! | Code | Geometry type      | Buffers                                                        |
+//! |------|--------------------|----------------------------------------------------------------|
+//! | 1    | Point              | coords (1)                                                     |
+//! | 2    | MultiPoint         | coords                                                         |
+//! | 3    | Line               | coords (2)                                                     |
+//! | 4    | LineString         | coords                                                         |
+//! | 5    | MultiLineString    | coords_spans, coords                                           |
+//! | 6    | Polygon            | hole_indices, coords                                           |
+//! | 7    | MultiPolygon       | coords_spans, all_hole_indices, holes_spans, coords            |
+//! | 8    | Triangle           | coords (3)                                                     |
+//! | 9    | MultiTriangle      | indices, coords                                                |
+//! | 10   | GeometryCollection | member sizes in bytes, members (each an encoded geometry)      |
+//!
+//! Members of a GeometryCollection have the same dimension and coordinate type as
+//! the collection, and their sizes are multiples of 8.
+//!
+//! | Code | Coordinate type | Code | Coordinate type |
+//! |------|-----------------|------|-----------------|
+//! | 1    | `u8`            | 6    | `i32`           |
+//! | 2    | `i8`            | 7    | `u64`           |
+//! | 3    | `u16`           | 8    | `i64`           |
+//! | 4    | `i16`           | 9    | `f32`           |
+//! | 5    | `u32`           | 10   | `f64`           |
+//!
+//! # Alignment
+//!
+//! Borrowing requires the buffers to be aligned for their element types, which holds if
+//! the input starts at an 8-byte aligned address (as memory-mapped files and most
+//! allocations do). Misaligned input is rejected with [`Error::Misaligned`].
+//! On big-endian targets the buffers are copied and converted instead of borrowed.
+//!
+//! [`Cow::Borrowed`]: alloc::borrow::Cow::Borrowed
+
+mod decode;
+mod encode;
+
+pub use decode::FromBytes;
+pub use encode::ToBytes;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::CoordNum;
+
+const MAGIC: &[u8; 4] = b"FGEO";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 8;
+const ALIGNMENT: usize = 8;
+
+pub(crate) mod code {
+    pub const POINT: u8 = 1;
+    pub const MULTIPOINT: u8 = 2;
+    pub const LINE: u8 = 3;
+    pub const LINESTRING: u8 = 4;
+    pub const MULTILINESTRING: u8 = 5;
+    pub const POLYGON: u8 = 6;
+    pub const MULTIPOLYGON: u8 = 7;
+    pub const TRIANGLE: u8 = 8;
+    pub const MULTITRIANGLE: u8 = 9;
+    pub const GEOMETRYCOLLECTION: u8 = 10;
+
+    pub fn name(code: u8) -> &'static str {
+        match code {
+            POINT => "Point",
+            MULTIPOINT => "MultiPoint",
+            LINE => "Line",
+            LINESTRING => "LineString",
+            MULTILINESTRING => "MultiLineString",
+            POLYGON => "Polygon",
+            MULTIPOLYGON => "MultiPolygon",
+            TRIANGLE => "Triangle",
+            MULTITRIANGLE => "MultiTriangle",
+            GEOMETRYCOLLECTION => "GeometryCollection",
+            _ => "unknown",
+        }
+    }
+}
+
+/// Errors reported when reading the binary format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input ended before the geometry was complete.
+    UnexpectedEof,
+    /// The input does not start with the magic bytes.
+    InvalidMagic,
+    /// The format version is not supported.
+    UnsupportedVersion { version: u8 },
+    /// The geometry type code is unknown.
+    UnknownGeometryType { code: u8 },
+    /// The geometry is not of the requested type.
+    MismatchedGeometryType {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// The coordinates do not have the requested dimension.
+    MismatchedDimension { expected: usize, found: usize },
+    /// The coordinates are not of the requested type.
+    MismatchedCoordType { expected: u8, found: u8 },
+    /// A Point, Line or Triangle does not have the required number of coordinates.
+    InvalidCoordCount { expected: usize, found: usize },
+    /// A buffer at the given byte offset is not aligned for its element type.
+    Misaligned { offset: usize },
+    /// A member of a GeometryCollection has a size that is not a multiple of 8.
+    InvalidMemberSize { index: usize },
+    /// The span buffers are inconsistent.
+    InvalidSpans(crate::Error),
+    /// GeometryCollections are nested too deeply.
+    NestingTooDeep,
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEof => write!(f, "unexpected end of input"),
+            Error::InvalidMagic => write!(f, "invalid magic bytes"),
+            Error::UnsupportedVersion { version } => {
+                write!(f, "unsupported format version {}", version)
+            }
+            Error::UnknownGeometryType { code } => write!(f, "unknown geometry type {}", code),
+            Error::MismatchedGeometryType { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+            Error::MismatchedDimension { expected, found } => write!(
+                f,
+                "expected {}-dimensional coordinates but found {}",
+                expected, found
+            ),
+            Error::MismatchedCoordType { expected, found } => write!(
+                f,
+                "expected coordinate type {} but found {}",
+                expected, found
+            ),
+            Error::InvalidCoordCount { expected, found } => {
+                write!(f, "expected {} coordinate(s) but found {}", expected, found)
+            }
+            Error::Misaligned { offset } => write!(f, "misaligned buffer at offset {}", offset),
+            Error::InvalidMemberSize { index } => {
+                write!(f, "invalid size of collection member {}", index)
+            }
+            Error::InvalidSpans(err) => write!(f, "{}", err),
+            Error::NestingTooDeep => write!(f, "geometry collections are nested too deeply"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<crate::Error> for Error {
+    fn from(err: crate::Error) -> Self {
+        Error::InvalidSpans(err)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive number types that can be stored in the binary format.
+///
+/// This trait is sealed: any bit pattern must be a valid value for the buffers to be borrowed.
+pub trait Scalar: CoordNum + Copy + sealed::Sealed {
+    /// Coordinate type code in the header
+    const TAG: u8;
+
+    #[doc(hidden)]
+    fn write_le(self, out: &mut Vec<u8>);
+
+    #[doc(hidden)]
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty => $tag:expr),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl Scalar for $ty {
+                const TAG: u8 = $tag;
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend(self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(
+    u8 => 1,
+    i8 => 2,
+    u16 => 3,
+    i16 => 4,
+    u32 => 5,
+    i32 => 6,
+    u64 => 7,
+    i64 => 8,
+    f32 => 9,
+    f64 => 10,
+);
@@ -0,0 +1,201 @@
+//! Raw byte access to the coordinate and span buffers.
+//!
+//! Bytes are in native byte order. Constructing from bytes borrows them without copying,
+//! failing if they are not aligned for the element type or not a whole number of elements.
+
+use core::mem::size_of;
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::error::{Error, Result};
+use crate::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+
+fn cast<T: Pod>(bytes: &[u8]) -> Result<&[T]> {
+    if bytes.is_empty() {
+        return Ok(&[]);
+    }
+    bytemuck::try_cast_slice(bytes).map_err(|err| match err {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned => Error::MisalignedBytes,
+        _ => Error::InvalidByteLength {
+            len: bytes.len(),
+            elem_size: size_of::<T>(),
+        },
+    })
+}
+
+impl<'a, T: Coord + Pod> LineString<'a, T> {
+    /// Returns the coordinate buffer as bytes.
+    pub fn coords_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.raw_coords())
+    }
+
+    /// Creates a LineString borrowing the coordinates from bytes.
+    pub fn try_from_bytes(coords: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_raw(cast(coords)?.into()))
+    }
+}
+
+impl<'a, T: Coord + Pod> MultiPoint<'a, T> {
+    /// Returns the coordinate buffer as bytes.
+    pub fn coords_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.raw_coords())
+    }
+
+    /// Creates a MultiPoint borrowing the coordinates from bytes.
+    pub fn try_from_bytes(coords: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_raw(cast(coords)?.into()))
+    }
+}
+
+impl<'a, T: Coord + Pod> MultiLineString<'a, T> {
+    /// Returns the coordinate buffer as bytes.
+    pub fn coords_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.raw_coords())
+    }
+
+    /// Returns the `coords_spans` buffer as bytes.
+    pub fn coords_spans_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.coords_spans())
+    }
+
+    /// Creates a MultiLineString borrowing the buffers from bytes, checking for validity.
+    pub fn try_from_bytes(coords: &'a [u8], coords_spans: &'a [u8]) -> Result<Self> {
+        Self::try_from_raw(cast(coords)?.into(), cast(coords_spans)?.into())
+    }
+}
+
+impl<'a, T: Coord + Pod> Polygon<'a, T> {
+    /// Returns the coordinate buffer as bytes.
+    pub fn coords_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.raw_coords())
+    }
+
+    /// Returns the `hole_indices` buffer as bytes.
+    pub fn hole_indices_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.hole_indices())
+    }
+
+    /// Creates a Polygon borrowing the buffers from bytes, checking for validity.
+    pub fn try_from_bytes(coords: &'a [u8], hole_indices: &'a [u8]) -> Result<Self> {
+        Self::try_from_raw(cast(coords)?.into(), cast(hole_indices)?.into())
+    }
+}
+
+impl<'a, T: Coord + Pod> MultiPolygon<'a, T> {
+    /// Returns the coordinate buffer as bytes.
+    pub fn coords_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.raw_coords())
+    }
+
+    /// Returns the `coords_spans` buffer as bytes.
+    pub fn coords_spans_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.coords_spans())
+    }
+
+    /// Returns the `all_hole_indices` buffer as bytes.
+    pub fn all_hole_indices_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.all_hole_indices())
+    }
+
+    /// Returns the `holes_spans` buffer as bytes.
+    pub fn holes_spans_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.holes_spans())
+    }
+
+    /// Creates a MultiPolygon borrowing the buffers from bytes, checking for validity.
+    pub fn try_from_bytes(
+        coords: &'a [u8],
+        coords_spans: &'a [u8],
+        all_hole_indices: &'a [u8],
+        holes_spans: &'a [u8],
+    ) -> Result<Self> {
+        Self::try_from_raw(
+            cast(coords)?.into(),
+            cast(coords_spans)?.into(),
+            cast(all_hole_indices)?.into(),
+            cast(holes_spans)?.into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::SpanKind, LineString3, MultiPolygon2, Polygon2};
+    use alloc::{borrow::Cow, vec, vec::Vec};
+
+    #[test]
+    fn test_linestring() {
+        let ls = LineString3::<f32>::from_raw(vec![[1., 2., 3.], [4., 5., 6.]].into());
+        let bytes = ls.coords_bytes();
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[4..8], 2f32.to_ne_bytes());
+
+        // copy into an aligned buffer
+        let aligned: Vec<f32> = bytemuck::cast_slice(bytes).to_vec();
+        let bytes = bytemuck::cast_slice(&aligned);
+        let decoded = LineString3::<f32>::try_from_bytes(bytes).unwrap();
+        assert_eq!(decoded, ls);
+        assert_eq!(
+            LineString3::<f32>::try_from_bytes(&bytes[..20]),
+            Err(Error::InvalidByteLength {
+                len: 20,
+                elem_size: 12
+            })
+        );
+        assert_eq!(
+            LineString3::<f32>::try_from_bytes(&bytes[1..13]),
+            Err(Error::MisalignedBytes)
+        );
+        assert!(LineString3::<f32>::try_from_bytes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_multipolygon() {
+        let mut mpoly = MultiPolygon2::<u32>::new();
+        mpoly.add_exterior([[0, 0], [5, 0], [5, 5], [0, 5]]);
+        mpoly.add_interior([[1, 1], [2, 1], [2, 2], [1, 2]]);
+        mpoly.add_exterior([[6, 0], [7, 0], [7, 1]]);
+        assert_eq!(mpoly.coords_spans_bytes(), 8u32.to_ne_bytes());
+        assert_eq!(mpoly.holes_spans_bytes(), 1u32.to_ne_bytes());
+        assert_eq!(mpoly.all_hole_indices_bytes(), 4u32.to_ne_bytes());
+
+        let decoded = MultiPolygon2::<u32>::try_from_bytes(
+            mpoly.coords_bytes(),
+            mpoly.coords_spans_bytes(),
+            mpoly.all_hole_indices_bytes(),
+            mpoly.holes_spans_bytes(),
+        )
+        .unwrap();
+        assert_eq!(decoded, mpoly);
+
+        // spans are checked
+        let spans = [20u32];
+        assert!(matches!(
+            MultiPolygon2::<u32>::try_from_bytes(
+                mpoly.coords_bytes(),
+                bytemuck::cast_slice(&spans),
+                mpoly.all_hole_indices_bytes(),
+                mpoly.holes_spans_bytes(),
+            ),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::CoordsSpans,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_polygon() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [5., 0.], [5., 5.]]);
+        poly.add_ring([[1., 1.], [2., 1.], [2., 2.]]);
+        let decoded =
+            Polygon2::try_from_bytes(poly.coords_bytes(), poly.hole_indices_bytes()).unwrap();
+        assert_eq!(decoded, poly);
+        assert!(matches!(
+            Cow::from(decoded.raw_coords()),
+            Cow::Borrowed(coords) if coords.as_ptr() == poly.raw_coords().as_ptr()
+        ));
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+
+/// Identifies which raw index buffer an [`Error`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    /// `hole_indices` of a `Polygon`, or `all_hole_indices` of a `MultiPolygon`
+    HoleIndices,
+    /// `coords_spans` of a `MultiLineString` or `MultiPolygon`
+    CoordsSpans,
+    /// `holes_spans` of a `MultiPolygon`
+    HolesSpans,
+    /// `ends` of a `MultiPolygonBatch`
+    Ends,
+}
+
+impl SpanKind {
+    fn name(&self) -> &'static str {
+        match self {
+            SpanKind::HoleIndices => "hole_indices",
+            SpanKind::CoordsSpans => "coords_spans",
+            SpanKind::HolesSpans => "holes_spans",
+            SpanKind::Ends => "ends",
+        }
+    }
+}
+
+/// Errors reported when constructing geometries from raw buffers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A span value points outside of the buffer it indexes into.
+    SpanOutOfRange {
+        kind: SpanKind,
+        /// Position of the offending value in the span buffer
+        index: usize,
+        value: u32,
+        /// Length of the buffer the span indexes into
+        len: usize,
+    },
+    /// Span values are not monotonically increasing.
+    NonMonotonicSpans {
+        kind: SpanKind,
+        /// Position of the offending value in the span buffer
+        index: usize,
+    },
+    /// `coords_spans` and `holes_spans` of a `MultiPolygon` have different lengths.
+    SpanLengthMismatch {
+        coords_spans: usize,
+        holes_spans: usize,
+    },
+    /// The last entry of `ends` of a `MultiPolygonBatch` does not match the buffer lengths.
+    BatchLengthMismatch { ends: [usize; 4], lens: [usize; 4] },
+    /// The length of triangle indices is not a multiple of three.
+    IncompleteTriangle { len: usize },
+    /// A triangle vertex index points outside of the coordinate buffer.
+    TriangleIndexOutOfRange {
+        /// Position of the offending value in the index buffer
+        index: usize,
+        value: u32,
+        /// Length of the coordinate buffer
+        len: usize,
+    },
+    /// A byte buffer is not aligned for its element type.
+    MisalignedBytes,
+    /// The length of a byte buffer is not a multiple of the element size.
+    InvalidByteLength { len: usize, elem_size: usize },
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::SpanOutOfRange {
+                kind,
+                index,
+                value,
+                len,
+            } => write!(
+                f,
+                "invalid {}: value {} at index {} is out of range (len: {})",
+                kind.name(),
+                value,
+                index,
+                len
+            ),
+            Error::NonMonotonicSpans { kind, index } => write!(
+                f,
+                "invalid {}: value at index {} is not monotonically increasing",
+                kind.name(),
+                index
+            ),
+            Error::SpanLengthMismatch {
+                coords_spans,
+                holes_spans,
+            } => write!(
+                f,
+                "coords_spans and holes_spans must have the same length ({} != {})",
+                coords_spans, holes_spans
+            ),
+            Error::BatchLengthMismatch { ends, lens } => write!(
+                f,
+                "the last entry of ends {:?} must match the buffer lengths {:?}",
+                ends, lens
+            ),
+            Error::IncompleteTriangle { len } => write!(
+                f,
+                "invalid triangle indices: length {} is not a multiple of 3",
+                len
+            ),
+            Error::TriangleIndexOutOfRange { index, value, len } => write!(
+                f,
+                "invalid triangle indices: value {} at index {} is out of range (len: {})",
+                value, index, len
+            ),
+            Error::MisalignedBytes => write!(f, "byte buffer is misaligned"),
+            Error::InvalidByteLength { len, elem_size } => write!(
+                f,
+                "byte length {} is not a multiple of the element size {}",
+                len, elem_size
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Checks that all span values are less than `len` and strictly increasing.
+pub(crate) fn check_spans<S: Copy + Into<u32>>(
+    kind: SpanKind,
+    spans: &[S],
+    offset: usize,
+    len: usize,
+) -> Result<()> {
+    let mut prev = None;
+    for (i, value) in spans.iter().enumerate() {
+        let value: u32 = (*value).into();
+        if value as usize >= len {
+            return Err(Error::SpanOutOfRange {
+                kind,
+                index: offset + i,
+                value,
+                len,
+            });
+        }
+        if prev.is_some_and(|prev| prev >= value) {
+            return Err(Error::NonMonotonicSpans {
+                kind,
+                index: offset + i,
+            });
+        }
+        prev = Some(value);
+    }
+    Ok(())
+}
+
+/// Reports the result of a validity check as a `bytecheck` verification error.
+#[cfg(feature = "rkyv")]
+pub(crate) fn verify<C>(result: Result<()>) -> core::result::Result<(), C::Error>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+{
+    result.map_err(<C::Error as rkyv::rancor::Source>::new)
+}
@@ -0,0 +1,514 @@
+//! Conversions between flatgeom geometries and [`geo_types`] geometries.
+//!
+//! Conversions are provided in both directions for 2-dimensional geometries, by value and by
+//! reference, so that `geo` algorithms can be applied without going through geozero.
+//!
+//! Rings are closed when converting to `geo_types`, and their closing points are removed
+//! when converting back. Since `geo_types` has no MultiTriangle, it is converted to a
+//! GeometryCollection of Triangles, and `geo_types::Rect` is converted to a Polygon.
+//! Converting from `geo_types::Geometry` into a specific type fails if the variant does not match.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle,
+    Point, Polygon, Rect, Triangle,
+};
+
+/// Errors reported when converting from `geo_types`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The geometry is not of the expected type.
+    MismatchedGeometry {
+        expected: &'static str,
+        found: &'static str,
+    },
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MismatchedGeometry { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+fn geometry_name<N: geo_types::CoordNum>(geom: &geo_types::Geometry<N>) -> &'static str {
+    match geom {
+        geo_types::Geometry::Point(_) => "Point",
+        geo_types::Geometry::Line(_) => "Line",
+        geo_types::Geometry::LineString(_) => "LineString",
+        geo_types::Geometry::Polygon(_) => "Polygon",
+        geo_types::Geometry::MultiPoint(_) => "MultiPoint",
+        geo_types::Geometry::MultiLineString(_) => "MultiLineString",
+        geo_types::Geometry::MultiPolygon(_) => "MultiPolygon",
+        geo_types::Geometry::GeometryCollection(_) => "GeometryCollection",
+        geo_types::Geometry::Rect(_) => "Rect",
+        geo_types::Geometry::Triangle(_) => "Triangle",
+    }
+}
+
+fn to_coord<N: geo_types::CoordNum>(c: [N; 2]) -> geo_types::Coord<N> {
+    geo_types::Coord { x: c[0], y: c[1] }
+}
+
+fn from_coord<N: geo_types::CoordNum>(c: &geo_types::Coord<N>) -> [N; 2] {
+    [c.x, c.y]
+}
+
+fn to_ring<N: CoordNum + geo_types::CoordNum>(
+    ring: &LineString<'_, [N; 2]>,
+) -> geo_types::LineString<N> {
+    ring.iter_closed().map(to_coord).collect()
+}
+
+// flatgeom -> geo_types
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Point<[N; 2]>> for geo_types::Point<N> {
+    fn from(point: &Point<[N; 2]>) -> Self {
+        geo_types::Point(to_coord(*point.coord()))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&MultiPoint<'_, [N; 2]>> for geo_types::MultiPoint<N> {
+    fn from(mpoint: &MultiPoint<'_, [N; 2]>) -> Self {
+        mpoint
+            .iter()
+            .map(|c| geo_types::Point(to_coord(c)))
+            .collect()
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Line<[N; 2]>> for geo_types::Line<N> {
+    fn from(line: &Line<[N; 2]>) -> Self {
+        geo_types::Line::new(to_coord(*line.start()), to_coord(*line.end()))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&LineString<'_, [N; 2]>> for geo_types::LineString<N> {
+    fn from(ls: &LineString<'_, [N; 2]>) -> Self {
+        ls.iter().map(to_coord).collect()
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&MultiLineString<'_, [N; 2]>>
+    for geo_types::MultiLineString<N>
+{
+    fn from(mls: &MultiLineString<'_, [N; 2]>) -> Self {
+        mls.iter()
+            .map(|ls| geo_types::LineString::from(&ls))
+            .collect()
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Polygon<'_, [N; 2]>> for geo_types::Polygon<N> {
+    fn from(poly: &Polygon<'_, [N; 2]>) -> Self {
+        if poly.raw_coords().is_empty() {
+            return geo_types::Polygon::new(geo_types::LineString::new(Vec::new()), Vec::new());
+        }
+        geo_types::Polygon::new(
+            to_ring(&poly.exterior()),
+            poly.interiors().map(|ring| to_ring(&ring)).collect(),
+        )
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&MultiPolygon<'_, [N; 2]>>
+    for geo_types::MultiPolygon<N>
+{
+    fn from(mpoly: &MultiPolygon<'_, [N; 2]>) -> Self {
+        mpoly
+            .iter()
+            .map(|poly| geo_types::Polygon::from(&poly))
+            .collect()
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Triangle<[N; 2]>> for geo_types::Triangle<N> {
+    fn from(tri: &Triangle<[N; 2]>) -> Self {
+        let c = tri.raw_coords();
+        geo_types::Triangle::new(to_coord(c[0]), to_coord(c[1]), to_coord(c[2]))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&MultiTriangle<'_, [N; 2]>>
+    for geo_types::GeometryCollection<N>
+{
+    fn from(mtri: &MultiTriangle<'_, [N; 2]>) -> Self {
+        mtri.iter()
+            .map(|tri| geo_types::Geometry::Triangle((&tri).into()))
+            .collect()
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Rect<[N; 2]>> for geo_types::Rect<N> {
+    fn from(rect: &Rect<[N; 2]>) -> Self {
+        geo_types::Rect::new(to_coord(*rect.min()), to_coord(*rect.max()))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&Geometry<'_, [N; 2]>> for geo_types::Geometry<N> {
+    fn from(geom: &Geometry<'_, [N; 2]>) -> Self {
+        match geom {
+            Geometry::Point(geom) => geo_types::Geometry::Point(geom.into()),
+            Geometry::MultiPoint(geom) => geo_types::Geometry::MultiPoint(geom.into()),
+            Geometry::Line(geom) => geo_types::Geometry::Line(geom.into()),
+            Geometry::LineString(geom) => geo_types::Geometry::LineString(geom.into()),
+            Geometry::MultiLineString(geom) => geo_types::Geometry::MultiLineString(geom.into()),
+            Geometry::Polygon(geom) => geo_types::Geometry::Polygon(geom.into()),
+            Geometry::MultiPolygon(geom) => geo_types::Geometry::MultiPolygon(geom.into()),
+            Geometry::Triangle(geom) => geo_types::Geometry::Triangle(geom.into()),
+            Geometry::MultiTriangle(geom) => geo_types::Geometry::GeometryCollection(geom.into()),
+            Geometry::GeometryCollection(geoms) => geo_types::Geometry::GeometryCollection(
+                geoms.iter().map(geo_types::Geometry::from).collect(),
+            ),
+        }
+    }
+}
+
+// geo_types -> flatgeom
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Point<N>> for Point<[N; 2]> {
+    fn from(point: &geo_types::Point<N>) -> Self {
+        Point::new(from_coord(&point.0))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::MultiPoint<N>>
+    for MultiPoint<'static, [N; 2]>
+{
+    fn from(mpoint: &geo_types::MultiPoint<N>) -> Self {
+        MultiPoint::from_raw(mpoint.iter().map(|p| from_coord(&p.0)).collect())
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Line<N>> for Line<[N; 2]> {
+    fn from(line: &geo_types::Line<N>) -> Self {
+        Line::new(from_coord(&line.start), from_coord(&line.end))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::LineString<N>>
+    for LineString<'static, [N; 2]>
+{
+    fn from(ls: &geo_types::LineString<N>) -> Self {
+        LineString::from_raw(ls.0.iter().map(from_coord).collect())
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::MultiLineString<N>>
+    for MultiLineString<'static, [N; 2]>
+{
+    fn from(mls: &geo_types::MultiLineString<N>) -> Self {
+        let mut out = MultiLineString::new();
+        for ls in mls {
+            out.add_linestring(ls.0.iter().map(from_coord));
+        }
+        out
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Polygon<N>> for Polygon<'static, [N; 2]> {
+    fn from(poly: &geo_types::Polygon<N>) -> Self {
+        let mut out = Polygon::new();
+        if poly.exterior().0.is_empty() {
+            return out;
+        }
+        out.add_ring(poly.exterior().0.iter().map(from_coord));
+        for ring in poly.interiors().iter().filter(|ring| !ring.0.is_empty()) {
+            out.add_ring(ring.0.iter().map(from_coord));
+        }
+        out
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::MultiPolygon<N>>
+    for MultiPolygon<'static, [N; 2]>
+{
+    fn from(mpoly: &geo_types::MultiPolygon<N>) -> Self {
+        let mut out = MultiPolygon::new();
+        for poly in mpoly {
+            if poly.exterior().0.is_empty() {
+                continue;
+            }
+            out.add_exterior(poly.exterior().0.iter().map(from_coord));
+            for ring in poly.interiors().iter().filter(|ring| !ring.0.is_empty()) {
+                out.add_interior(ring.0.iter().map(from_coord));
+            }
+        }
+        out
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Triangle<N>> for Triangle<[N; 2]> {
+    fn from(tri: &geo_types::Triangle<N>) -> Self {
+        let [a, b, c] = tri.to_array();
+        Triangle::new(from_coord(&a), from_coord(&b), from_coord(&c))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> TryFrom<&geo_types::GeometryCollection<N>>
+    for MultiTriangle<'static, [N; 2]>
+{
+    type Error = Error;
+
+    fn try_from(collection: &geo_types::GeometryCollection<N>) -> Result<Self> {
+        let mut out = MultiTriangle::new();
+        for geom in collection {
+            match geom {
+                geo_types::Geometry::Triangle(tri) => out.push(&tri.into()),
+                other => {
+                    return Err(Error::MismatchedGeometry {
+                        expected: "Triangle",
+                        found: geometry_name(other),
+                    })
+                }
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Rect<N>> for Rect<[N; 2]> {
+    fn from(rect: &geo_types::Rect<N>) -> Self {
+        Rect::new(from_coord(&rect.min()), from_coord(&rect.max()))
+    }
+}
+
+impl<N: CoordNum + geo_types::CoordNum> From<&geo_types::Geometry<N>>
+    for Geometry<'static, [N; 2]>
+{
+    fn from(geom: &geo_types::Geometry<N>) -> Self {
+        match geom {
+            geo_types::Geometry::Point(geom) => Geometry::Point(geom.into()),
+            geo_types::Geometry::Line(geom) => Geometry::Line(geom.into()),
+            geo_types::Geometry::LineString(geom) => Geometry::LineString(geom.into()),
+            geo_types::Geometry::Polygon(geom) => Geometry::Polygon(geom.into()),
+            geo_types::Geometry::MultiPoint(geom) => Geometry::MultiPoint(geom.into()),
+            geo_types::Geometry::MultiLineString(geom) => Geometry::MultiLineString(geom.into()),
+            geo_types::Geometry::MultiPolygon(geom) => Geometry::MultiPolygon(geom.into()),
+            geo_types::Geometry::GeometryCollection(geoms) => {
+                Geometry::GeometryCollection(geoms.iter().map(Geometry::from).collect())
+            }
+            geo_types::Geometry::Rect(geom) => Geometry::Polygon((&geom.to_polygon()).into()),
+            geo_types::Geometry::Triangle(geom) => Geometry::Triangle(geom.into()),
+        }
+    }
+}
+
+/// Implements the by-value conversions in terms of the by-reference ones.
+macro_rules! impl_owned {
+    ($($flat:ty => $geo:ty),* $(,)?) => {
+        $(
+            impl<N: CoordNum + geo_types::CoordNum> From<$flat> for $geo {
+                fn from(geom: $flat) -> Self {
+                    (&geom).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_owned!(
+    Point<[N; 2]> => geo_types::Point<N>,
+    MultiPoint<'_, [N; 2]> => geo_types::MultiPoint<N>,
+    Line<[N; 2]> => geo_types::Line<N>,
+    LineString<'_, [N; 2]> => geo_types::LineString<N>,
+    MultiLineString<'_, [N; 2]> => geo_types::MultiLineString<N>,
+    Polygon<'_, [N; 2]> => geo_types::Polygon<N>,
+    MultiPolygon<'_, [N; 2]> => geo_types::MultiPolygon<N>,
+    Triangle<[N; 2]> => geo_types::Triangle<N>,
+    MultiTriangle<'_, [N; 2]> => geo_types::GeometryCollection<N>,
+    Rect<[N; 2]> => geo_types::Rect<N>,
+    Geometry<'_, [N; 2]> => geo_types::Geometry<N>,
+    geo_types::Point<N> => Point<[N; 2]>,
+    geo_types::MultiPoint<N> => MultiPoint<'static, [N; 2]>,
+    geo_types::Line<N> => Line<[N; 2]>,
+    geo_types::LineString<N> => LineString<'static, [N; 2]>,
+    geo_types::MultiLineString<N> => MultiLineString<'static, [N; 2]>,
+    geo_types::Polygon<N> => Polygon<'static, [N; 2]>,
+    geo_types::MultiPolygon<N> => MultiPolygon<'static, [N; 2]>,
+    geo_types::Triangle<N> => Triangle<[N; 2]>,
+    geo_types::Rect<N> => Rect<[N; 2]>,
+    geo_types::Geometry<N> => Geometry<'static, [N; 2]>,
+);
+
+/// Implements fallible conversions from `geo_types::Geometry` into specific types.
+macro_rules! impl_try_from_geometry {
+    ($($variant:ident => $flat:ty),* $(,)?) => {
+        $(
+            impl<N: CoordNum + geo_types::CoordNum> TryFrom<geo_types::Geometry<N>> for $flat {
+                type Error = Error;
+
+                fn try_from(geom: geo_types::Geometry<N>) -> Result<Self> {
+                    match geom {
+                        geo_types::Geometry::$variant(geom) => Ok((&geom).into()),
+                        other => Err(Error::MismatchedGeometry {
+                            expected: stringify!($variant),
+                            found: geometry_name(&other),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_geometry!(
+    Point => Point<[N; 2]>,
+    MultiPoint => MultiPoint<'static, [N; 2]>,
+    Line => Line<[N; 2]>,
+    LineString => LineString<'static, [N; 2]>,
+    MultiLineString => MultiLineString<'static, [N; 2]>,
+    Polygon => Polygon<'static, [N; 2]>,
+    MultiPolygon => MultiPolygon<'static, [N; 2]>,
+    Triangle => Triangle<[N; 2]>,
+    Rect => Rect<[N; 2]>,
+);
+
+impl<N: CoordNum + geo_types::CoordNum> TryFrom<geo_types::Geometry<N>>
+    for MultiTriangle<'static, [N; 2]>
+{
+    type Error = Error;
+
+    fn try_from(geom: geo_types::Geometry<N>) -> Result<Self> {
+        match geom {
+            geo_types::Geometry::GeometryCollection(geoms) => (&geoms).try_into(),
+            other => Err(Error::MismatchedGeometry {
+                expected: "GeometryCollection",
+                found: geometry_name(&other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, LineString2, MultiPoint2, MultiPolygon2, MultiTriangle2, Polygon2};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn test_polygon() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        poly.add_ring([[1., 1.], [1., 2.], [2., 2.]]);
+        let geo: geo_types::Polygon<f64> = (&poly).into();
+        assert_eq!(geo.exterior().0.len(), 5);
+        assert_eq!(geo.interiors()[0].0[3], geo_types::Coord { x: 1., y: 1. });
+        let back: Polygon2 = geo.into();
+        assert_eq!(back, poly);
+
+        let geo: geo_types::Polygon<f64> = Polygon2::new().into();
+        assert!(geo.exterior().0.is_empty());
+        assert!(Polygon2::from(geo).raw_coords().is_empty());
+    }
+
+    #[test]
+    fn test_empty_interiors() {
+        let ext = geo_types::LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)]);
+        let hole = geo_types::LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)]);
+        let empty = geo_types::LineString::new(vec![]);
+        let geo = geo_types::Polygon::new(ext, vec![empty.clone(), hole, empty]);
+
+        let poly = Polygon2::from(&geo);
+        assert_eq!(poly.hole_indices(), [3]);
+        assert!(
+            Polygon2::try_from_raw(poly.raw_coords().into(), poly.hole_indices().into()).is_ok()
+        );
+
+        let mpoly = MultiPolygon2::from(&geo_types::MultiPolygon::new(vec![geo.clone(), geo]));
+        assert_eq!(mpoly.all_hole_indices(), [3, 3]);
+        assert!(MultiPolygon2::try_from_raw(
+            mpoly.raw_coords().into(),
+            mpoly.coords_spans().into(),
+            mpoly.all_hole_indices().into(),
+            mpoly.holes_spans().into(),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [4., 0.], [4., 4.], [0., 4.]]);
+        mpoly.add_interior([[1., 1.], [1., 2.], [2., 2.]]);
+        mpoly.add_exterior([[5., 0.], [6., 0.], [6., 1.]]);
+        let mut mls = crate::MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 1.]]);
+        mls.add_linestring([[2., 2.], [3., 3.], [4., 2.]]);
+
+        let geoms = vec![
+            Geometry2::Point(Point::new([1., 2.])),
+            Geometry2::MultiPoint(MultiPoint2::from_raw(vec![[1., 2.], [3., 4.]].into())),
+            Geometry2::Line(Line::new([0., 0.], [1., 1.])),
+            Geometry2::LineString(LineString2::from_raw(vec![[0., 0.], [1., 1.]].into())),
+            Geometry2::MultiLineString(mls),
+            Geometry2::MultiPolygon(mpoly),
+            Geometry2::Triangle(Triangle::new([0., 0.], [1., 0.], [0., 1.])),
+        ];
+        let collection = Geometry2::GeometryCollection(geoms.clone());
+        let geo = geo_types::Geometry::from(&collection);
+        let geo_types::Geometry::GeometryCollection(ref members) = geo else {
+            panic!("GeometryCollection is expected");
+        };
+        assert_eq!(members.len(), geoms.len());
+        let back = Geometry2::from(geo);
+        assert_eq!(back.to_string(), collection.to_string());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let geo = geo_types::Geometry::Polygon(geo_types::Polygon::new(
+            vec![(0., 0.), (1., 0.), (1., 1.)].into(),
+            vec![],
+        ));
+        let poly = Polygon2::try_from(geo.clone()).unwrap();
+        assert_eq!(poly.raw_coords(), [[0., 0.], [1., 0.], [1., 1.]]);
+        assert_eq!(
+            LineString2::try_from(geo).unwrap_err(),
+            Error::MismatchedGeometry {
+                expected: "LineString",
+                found: "Polygon"
+            }
+        );
+
+        let rect = geo_types::Rect::new((0., 0.), (2., 1.));
+        let Geometry2::Polygon(poly) = Geometry2::from(geo_types::Geometry::Rect(rect)) else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(poly.area(), 2.);
+        assert_eq!(
+            Rect::try_from(geo_types::Geometry::Rect(rect)).unwrap(),
+            Rect::new([0., 0.], [2., 1.])
+        );
+
+        let mut mtri = MultiTriangle2::new();
+        mtri.push(&Triangle::new([0., 0.], [1., 0.], [0., 1.]));
+        let geo =
+            geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection::from(&mtri));
+        assert_eq!(
+            MultiTriangle2::try_from(geo).unwrap().raw_coords(),
+            mtri.raw_coords()
+        );
+        let geo =
+            geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection::from(vec![
+                geo_types::Geometry::Point((0., 0.).into()),
+            ]));
+        assert!(MultiTriangle2::try_from(geo).is_err());
+    }
+
+    #[test]
+    fn test_integer() {
+        let ls = LineString::from_raw(vec![[1i32, 2], [3, 4]].into());
+        let geo: geo_types::LineString<i32> = (&ls).into();
+        assert_eq!(LineString::from(geo), ls);
+    }
+}
@@ -0,0 +1,491 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+use super::GeoJson;
+use crate::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+/// Geometries that can be deserialized from GeoJSON geometry objects.
+///
+/// This is implemented for `[f64; D]` coordinates only, as GeoJSON positions are
+/// floating-point numbers.
+pub trait FromGeoJson: Sized {
+    fn deserialize_geojson<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Nesting level of the positions in a MultiPolygon (the deepest geometry)
+const MAX_LEVEL: usize = 3;
+
+/// `coordinates` member read into flat buffers
+///
+/// The nesting level of an array is counted from the top-level array (level 0). As the
+/// `type` member may come after `coordinates`, the nesting is recorded as end offsets
+/// (like GeoArrow) and assembled into a geometry afterwards.
+#[derive(Default)]
+struct FlatCoordinates<const D: usize> {
+    /// All positions
+    coords: Vec<[f64; D]>,
+    /// End offsets of the arrays at level `i + 1` into the arrays of the next level
+    /// (or into `coords` if the next level consists of positions)
+    ends: [Vec<u32>; MAX_LEVEL],
+    /// Nesting level of the positions, if any position has been read
+    position_level: Option<usize>,
+}
+
+impl<const D: usize> FlatCoordinates<D> {
+    /// Returns the number of arrays (or positions) read at the given level so far.
+    fn count(&self, level: usize) -> usize {
+        match self.position_level == Some(level) {
+            true => self.coords.len(),
+            false => self.ends.get(level - 1).map_or(0, Vec::len),
+        }
+    }
+}
+
+/// Reads an array at the given nesting level into [`FlatCoordinates`].
+struct ArraySeed<'a, const D: usize> {
+    out: &'a mut FlatCoordinates<D>,
+    level: usize,
+}
+
+impl<'de, const D: usize> DeserializeSeed<'de> for ArraySeed<'_, D> {
+    type Value = ();
+
+    fn deserialize<De: Deserializer<'de>>(self, deserializer: De) -> Result<(), De::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const D: usize> Visitor<'de> for ArraySeed<'_, D> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of coordinates")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let level = self.level;
+        let (mut coord, mut len, mut is_list) = ([0.; D], 0, false);
+        loop {
+            let seed = ElementSeed {
+                out: &mut *self.out,
+                level: level + 1,
+            };
+            match seq.next_element_seed(seed)? {
+                None => break,
+                Some(Element::Number(v)) if !is_list => {
+                    // extra values beyond `D` are ignored
+                    if let Some(dst) = coord.get_mut(len) {
+                        *dst = v;
+                    }
+                    len += 1;
+                }
+                Some(Element::Array) if len == 0 => is_list = true,
+                Some(_) => return Err(de::Error::custom("expected either a position or an array")),
+            }
+        }
+
+        let out = self.out;
+        if len > 0 {
+            // a position
+            if len < 2 {
+                return Err(de::Error::custom(
+                    "position must have at least two elements",
+                ));
+            }
+            match out.position_level {
+                None if level == 0 || out.ends[level - 1].is_empty() => {
+                    out.position_level = Some(level)
+                }
+                Some(l) if l == level => {}
+                _ => return Err(de::Error::custom("inconsistent nesting of coordinates")),
+            }
+            out.coords.push(coord);
+        } else if level > 0 {
+            // an array of arrays (or an empty array)
+            if out.position_level == Some(level) {
+                return Err(de::Error::custom(
+                    "position must have at least two elements",
+                ));
+            }
+            let end = out.count(level + 1) as u32;
+            out.ends[level - 1].push(end);
+        }
+        Ok(())
+    }
+}
+
+/// Element of an array in the `coordinates` member
+enum Element {
+    Number(f64),
+    /// An array, which has already been written into [`FlatCoordinates`]
+    Array,
+}
+
+/// Reads an element of an array at the given nesting level.
+struct ElementSeed<'a, const D: usize> {
+    out: &'a mut FlatCoordinates<D>,
+    level: usize,
+}
+
+impl<'de, const D: usize> DeserializeSeed<'de> for ElementSeed<'_, D> {
+    type Value = Element;
+
+    fn deserialize<De: Deserializer<'de>>(self, deserializer: De) -> Result<Element, De::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, const D: usize> Visitor<'de> for ElementSeed<'_, D> {
+    type Value = Element;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or an array")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Element, E> {
+        Ok(Element::Number(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Element, E> {
+        Ok(Element::Number(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Element, E> {
+        Ok(Element::Number(v as f64))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Element, A::Error> {
+        if self.level > MAX_LEVEL {
+            return Err(de::Error::custom("coordinates are nested too deeply"));
+        }
+        ArraySeed {
+            out: self.out,
+            level: self.level,
+        }
+        .visit_seq(seq)?;
+        Ok(Element::Array)
+    }
+}
+
+/// Pushes the start offsets (relative to `base`) of the non-empty arrays except the first one,
+/// given the end offsets of the arrays starting at `base`.
+fn push_starts(ends: &[u32], base: u32, out: &mut Vec<u32>) {
+    let mut start = base;
+    for &end in ends {
+        if base < start && start < end {
+            out.push(start - base);
+        }
+        start = end;
+    }
+}
+
+/// Removes the closing point of each ring in place, in the same way as [`Polygon::add_ring`],
+/// and updates the end offsets of the rings.
+fn remove_closing_points<const D: usize>(coords: &mut Vec<[f64; D]>, ring_ends: &mut [u32]) {
+    let (mut start, mut len) = (0, 0);
+    for end in ring_ends.iter_mut() {
+        let (head, tail) = (start, *end as usize);
+        start = tail;
+        let mut n = tail - head;
+        if n > 2 && coords[head] == coords[tail - 1] {
+            n -= 1;
+        }
+        coords.copy_within(head..head + n, len);
+        len += n;
+        *end = len as u32;
+    }
+    coords.truncate(len);
+}
+
+fn into_geometry<const D: usize, E: de::Error>(
+    kind: &str,
+    coordinates: Option<FlatCoordinates<D>>,
+) -> Result<Geometry<'static, [f64; D]>, E> {
+    let position_level = match kind {
+        "Point" => 0,
+        "MultiPoint" | "LineString" => 1,
+        "MultiLineString" | "Polygon" => 2,
+        "MultiPolygon" => 3,
+        _ => {
+            return Err(E::unknown_variant(
+                kind,
+                &[
+                    "Point",
+                    "MultiPoint",
+                    "LineString",
+                    "MultiLineString",
+                    "Polygon",
+                    "MultiPolygon",
+                    "GeometryCollection",
+                ],
+            ))
+        }
+    };
+    let FlatCoordinates {
+        mut coords,
+        mut ends,
+        position_level: level,
+    } = coordinates.ok_or_else(|| E::missing_field("coordinates"))?;
+    // arrays at (or below) the level of positions are empty positions
+    if level.unwrap_or(position_level) != position_level
+        || ends[position_level.saturating_sub(1)..]
+            .iter()
+            .any(|ends| !ends.is_empty())
+    {
+        return Err(E::custom(format_args!(
+            "coordinates do not match the geometry type {}",
+            kind
+        )));
+    }
+
+    // the arrays at level 1 are linestrings or rings, or polygons of a MultiPolygon
+    let [level1_ends, level2_ends, _] = &mut ends;
+    Ok(match kind {
+        "Point" => Geometry::Point(Point::new(coords.first().copied().unwrap_or([f64::NAN; D]))),
+        "MultiPoint" => Geometry::MultiPoint(MultiPoint::from_raw(coords.into())),
+        "LineString" => Geometry::LineString(LineString::from_raw(coords.into())),
+        "MultiLineString" => {
+            let mut coords_spans = Vec::new();
+            push_starts(level1_ends, 0, &mut coords_spans);
+            Geometry::MultiLineString(MultiLineString::from_raw(
+                coords.into(),
+                coords_spans.into(),
+            ))
+        }
+        "Polygon" => {
+            remove_closing_points(&mut coords, level1_ends);
+            let mut hole_indices = Vec::new();
+            push_starts(level1_ends, 0, &mut hole_indices);
+            Geometry::Polygon(Polygon::from_raw(coords.into(), hole_indices.into()))
+        }
+        _ => {
+            let (poly_ends, ring_ends) = (level1_ends, level2_ends);
+            remove_closing_points(&mut coords, ring_ends);
+            let mut coords_spans = Vec::with_capacity(poly_ends.len());
+            let mut all_hole_indices = Vec::new();
+            let mut holes_spans = Vec::with_capacity(poly_ends.len());
+            let mut ring_start = 0;
+            for &ring_end in poly_ends.iter() {
+                let rings = &ring_ends[ring_start..ring_end as usize];
+                let coords_start = match ring_start {
+                    0 => 0,
+                    i => ring_ends[i - 1],
+                };
+                ring_start = ring_end as usize;
+                // skip empty polygons
+                if rings.last().is_none_or(|&end| end == coords_start) {
+                    continue;
+                }
+                if coords_start > 0 {
+                    coords_spans.push(coords_start);
+                    holes_spans.push(all_hole_indices.len() as u32);
+                }
+                push_starts(rings, coords_start, &mut all_hole_indices);
+            }
+            Geometry::MultiPolygon(MultiPolygon::from_raw(
+                coords.into(),
+                coords_spans.into(),
+                all_hole_indices.into(),
+                holes_spans.into(),
+            ))
+        }
+    })
+}
+
+impl<const D: usize> FromGeoJson for Geometry<'static, [f64; D]> {
+    fn deserialize_geojson<'de, De: Deserializer<'de>>(
+        deserializer: De,
+    ) -> Result<Self, De::Error> {
+        struct GeometryVisitor<const D: usize>;
+
+        impl<'de, const D: usize> Visitor<'de> for GeometryVisitor<D> {
+            type Value = Geometry<'static, [f64; D]>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a GeoJSON geometry object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut kind: Option<String> = None;
+                let mut coordinates = None;
+                let mut geometries: Option<Vec<GeoJson<Geometry<'static, [f64; D]>>>> = None;
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "type" => kind = Some(map.next_value()?),
+                        "coordinates" => {
+                            if coordinates.is_some() {
+                                return Err(de::Error::duplicate_field("coordinates"));
+                            }
+                            let mut flat = FlatCoordinates::default();
+                            map.next_value_seed(ArraySeed {
+                                out: &mut flat,
+                                level: 0,
+                            })?;
+                            coordinates = Some(flat);
+                        }
+                        "geometries" => geometries = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let kind = kind.ok_or_else(|| de::Error::missing_field("type"))?;
+                if kind == "GeometryCollection" {
+                    let geometries =
+                        geometries.ok_or_else(|| de::Error::missing_field("geometries"))?;
+                    return Ok(Geometry::GeometryCollection(
+                        geometries.into_iter().map(|GeoJson(geom)| geom).collect(),
+                    ));
+                }
+                into_geometry(&kind, coordinates)
+            }
+        }
+
+        deserializer.deserialize_map(GeometryVisitor::<D>)
+    }
+}
+
+macro_rules! impl_from_geojson {
+    ($ty:ident, $name:literal) => {
+        impl<const D: usize> FromGeoJson for $ty<'static, [f64; D]> {
+            fn deserialize_geojson<'de, De: Deserializer<'de>>(
+                deserializer: De,
+            ) -> Result<Self, De::Error> {
+                match Geometry::deserialize_geojson(deserializer)? {
+                    Geometry::$ty(geom) => Ok(geom),
+                    _ => Err(de::Error::custom(concat!(
+                        "expected a ",
+                        $name,
+                        " geometry"
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_from_geojson!(MultiPoint, "MultiPoint");
+impl_from_geojson!(LineString, "LineString");
+impl_from_geojson!(MultiLineString, "MultiLineString");
+impl_from_geojson!(Polygon, "Polygon");
+impl_from_geojson!(MultiPolygon, "MultiPolygon");
+
+impl<const D: usize> FromGeoJson for Point<[f64; D]> {
+    fn deserialize_geojson<'de, De: Deserializer<'de>>(
+        deserializer: De,
+    ) -> Result<Self, De::Error> {
+        match Geometry::deserialize_geojson(deserializer)? {
+            Geometry::Point(geom) => Ok(geom),
+            _ => Err(de::Error::custom("expected a Point geometry")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, Geometry3, MultiLineString2, MultiPolygon2, Polygon2};
+
+    fn from_json<G: FromGeoJson>(s: &str) -> Result<G, serde_json::Error> {
+        serde_json::from_str::<GeoJson<G>>(s).map(|GeoJson(geom)| geom)
+    }
+
+    #[test]
+    fn test_deserialize_geometries() {
+        let Geometry3::Point(p) =
+            from_json(r#"{"coordinates": [1, 2], "type": "Point", "bbox": [1, 2, 1, 2]}"#).unwrap()
+        else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2., 0.]);
+
+        let poly: Polygon2 = from_json(
+            r#"{"type": "Polygon", "coordinates": [[[0, 0], [5, 0], [5, 5], [0, 0]], [[1, 1], [4, 1], [4, 4], [1, 1]]]}"#,
+        )
+        .unwrap();
+        assert_eq!(poly.raw_coords().len(), 6);
+        assert_eq!(poly.hole_indices(), [3]);
+
+        let mpoly: MultiPolygon2 = from_json(
+            r#"{"type": "MultiPolygon", "coordinates": [[[[0, 0], [5, 0], [5, 5], [0, 0]]], [[[6, 0], [7, 0], [7, 1]]]]}"#,
+        )
+        .unwrap();
+        assert_eq!(mpoly.len(), 2);
+        assert_eq!(mpoly.get(0).exterior().len(), 3);
+        assert_eq!(mpoly.get(1).exterior().len(), 3);
+
+        // `coordinates` before `type`, with holes and an empty polygon
+        let mpoly: MultiPolygon2 = from_json(
+            r#"{"coordinates": [[[[0, 0], [5, 0], [5, 5], [0, 0]], [[1, 1], [4, 1], [4, 4], [1, 1]]], [], [[[6, 0], [7, 0], [7, 1], [6, 0]]]], "type": "MultiPolygon"}"#,
+        )
+        .unwrap();
+        assert_eq!(mpoly.len(), 2);
+        assert_eq!(mpoly.raw_coords().len(), 9);
+        assert_eq!(mpoly.coords_spans(), [6]);
+        assert_eq!(mpoly.all_hole_indices(), [3]);
+        assert_eq!(mpoly.holes_spans(), [1]);
+        assert_eq!(mpoly.get(1).exterior().raw_coords()[0], [6., 0.]);
+
+        let Geometry2::GeometryCollection(geoms) = from_json(
+            r#"{"type": "GeometryCollection", "geometries": [
+                {"type": "LineString", "coordinates": [[0, 0], [1, 1.5, 9]]},
+                {"type": "MultiLineString", "coordinates": [[[0, 0], [1, 1]]]},
+                {"type": "MultiPoint", "coordinates": []}
+            ]}"#,
+        )
+        .unwrap() else {
+            panic!("GeometryCollection is expected");
+        };
+        assert_eq!(geoms.len(), 3);
+        let Geometry2::LineString(ls) = &geoms[0] else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[0., 0.], [1., 1.5]]);
+        let Geometry2::MultiLineString(mls) = &geoms[1] else {
+            panic!("MultiLineString is expected");
+        };
+        assert!(mls.coords_spans().is_empty());
+        let Geometry2::MultiPoint(mp) = &geoms[2] else {
+            panic!("MultiPoint is expected");
+        };
+        assert!(mp.is_empty());
+
+        // closing points are kept in linestrings
+        let mls: MultiLineString2 = from_json(
+            r#"{"coordinates": [[[0, 0], [1, 1], [0, 0]], [[2, 2], [3, 3]]], "type": "MultiLineString"}"#,
+        )
+        .unwrap();
+        assert_eq!(mls.raw_coords().len(), 5);
+        assert_eq!(mls.coords_spans(), [3]);
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        assert!(from_json::<Polygon2>(r#"{"type": "Point", "coordinates": [1, 2]}"#).is_err());
+        assert!(from_json::<Geometry2>(r#"{"type": "Circle", "coordinates": [1, 2]}"#).is_err());
+        assert!(from_json::<Geometry2>(r#"{"type": "Point"}"#).is_err());
+        assert!(from_json::<Geometry2>(r#"{"coordinates": [1, 2]}"#).is_err());
+        assert!(from_json::<Geometry2>(r#"{"type": "Point", "coordinates": [1]}"#).is_err());
+        assert!(
+            from_json::<Geometry2>(r#"{"type": "LineString", "coordinates": [1, 2]}"#).is_err()
+        );
+        assert!(
+            from_json::<Geometry2>(r#"{"type": "LineString", "coordinates": [[1, 2], []]}"#)
+                .is_err()
+        );
+        assert!(from_json::<Geometry2>(
+            r#"{"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1]], [0, 0]]}"#
+        )
+        .is_err());
+        assert!(from_json::<Geometry2>(
+            r#"{"type": "MultiPolygon", "coordinates": [[[[[0, 0], [1, 0], [1, 1]]]]]}"#
+        )
+        .is_err());
+        assert!(from_json::<Geometry2>(
+            r#"{"type": "Point", "coordinates": [1, 2], "coordinates": [3, 4]}"#
+        )
+        .is_err());
+    }
+}
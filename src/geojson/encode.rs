@@ -0,0 +1,279 @@
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+use super::GeoJson;
+use crate::{
+    Coord, CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    MultiTriangle, Point, Polygon, Triangle,
+};
+
+/// Geometries that can be serialized as GeoJSON geometry objects.
+pub trait ToGeoJson {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl<G: ToGeoJson + ?Sized> ToGeoJson for &G {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize_geojson(serializer)
+    }
+}
+
+fn write_geometry<S: Serializer>(
+    serializer: S,
+    name: &str,
+    coordinates: &impl Serialize,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry("type", name)?;
+    map.serialize_entry("coordinates", coordinates)?;
+    map.end()
+}
+
+/// Position
+struct Pos<const D: usize, N>([N; D]);
+
+impl<const D: usize, N: Serialize> Serialize for Pos<D, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(&self.0)
+    }
+}
+
+/// Array of positions
+struct Positions<F>(F);
+
+impl<const D: usize, N: Serialize, I: Iterator<Item = [N; D]>, F: Fn() -> I> Serialize
+    for Positions<F>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((self.0)().map(Pos))
+    }
+}
+
+/// Array of closed rings
+struct Rings<'a, 'b, T: Coord>(&'a Polygon<'b, T>);
+
+impl<const D: usize, N: CoordNum + Serialize> Serialize for Rings<'_, '_, [N; D]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.raw_coords().is_empty() {
+            return serializer.collect_seq([(); 0]);
+        }
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for ring in self.0.rings() {
+            seq.serialize_element(&Positions(|| ring.iter_closed()))?;
+        }
+        seq.end()
+    }
+}
+
+/// Triangle as an array of Polygon rings
+struct TriangleRings<'a, T: Coord>(&'a Triangle<T>);
+
+impl<const D: usize, N: CoordNum + Serialize> Serialize for TriangleRings<'_, [N; D]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq([Positions(|| self.0.iter_closed())])
+    }
+}
+
+/// Array of geometry objects
+struct Geometries<'a, 'b, T: Coord>(&'a [Geometry<'b, T>]);
+
+impl<const D: usize, N: CoordNum + Serialize> Serialize for Geometries<'_, '_, [N; D]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(GeoJson))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for Point<[N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let coord = self.coord();
+        if coord.iter().all(|v| v.to_f64().is_none_or(f64::is_nan)) {
+            // POINT EMPTY
+            return write_geometry(serializer, "Point", &[(); 0]);
+        }
+        write_geometry(serializer, "Point", &Pos(coord.clone()))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for MultiPoint<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        write_geometry(serializer, "MultiPoint", &Positions(|| self.iter()))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for Line<[N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        write_geometry(serializer, "LineString", &Positions(|| self.iter()))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for LineString<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        write_geometry(serializer, "LineString", &Positions(|| self.iter()))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for MultiLineString<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Coords<'a, 'b, T: Coord>(&'a MultiLineString<'b, T>);
+
+        impl<const D: usize, N: CoordNum + Serialize> Serialize for Coords<'_, '_, [N; D]> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for ls in self.0 {
+                    seq.serialize_element(&Positions(|| ls.iter()))?;
+                }
+                seq.end()
+            }
+        }
+
+        write_geometry(serializer, "MultiLineString", &Coords(self))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for Polygon<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        write_geometry(serializer, "Polygon", &Rings(self))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for MultiPolygon<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Coords<'a, 'b, T: Coord>(&'a MultiPolygon<'b, T>);
+
+        impl<const D: usize, N: CoordNum + Serialize> Serialize for Coords<'_, '_, [N; D]> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for poly in self.0 {
+                    seq.serialize_element(&Rings(&poly))?;
+                }
+                seq.end()
+            }
+        }
+
+        write_geometry(serializer, "MultiPolygon", &Coords(self))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for Triangle<[N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        write_geometry(serializer, "Polygon", &TriangleRings(self))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for MultiTriangle<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Coords<'a, 'b, T: Coord>(&'a MultiTriangle<'b, T>);
+
+        impl<const D: usize, N: CoordNum + Serialize> Serialize for Coords<'_, '_, [N; D]> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for tri in self.0 {
+                    seq.serialize_element(&TriangleRings(&tri))?;
+                }
+                seq.end()
+            }
+        }
+
+        write_geometry(serializer, "MultiPolygon", &Coords(self))
+    }
+}
+
+impl<const D: usize, N: CoordNum + Serialize> ToGeoJson for Geometry<'_, [N; D]> {
+    fn serialize_geojson<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Geometry::Point(geom) => geom.serialize_geojson(serializer),
+            Geometry::MultiPoint(geom) => geom.serialize_geojson(serializer),
+            Geometry::Line(geom) => geom.serialize_geojson(serializer),
+            Geometry::LineString(geom) => geom.serialize_geojson(serializer),
+            Geometry::MultiLineString(geom) => geom.serialize_geojson(serializer),
+            Geometry::Polygon(geom) => geom.serialize_geojson(serializer),
+            Geometry::MultiPolygon(geom) => geom.serialize_geojson(serializer),
+            Geometry::Triangle(geom) => geom.serialize_geojson(serializer),
+            Geometry::MultiTriangle(geom) => geom.serialize_geojson(serializer),
+            Geometry::GeometryCollection(geoms) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("type", "GeometryCollection")?;
+                map.serialize_entry("geometries", &Geometries(geoms))?;
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiLineString2, MultiPolygon2, MultiTriangle2, Polygon2, Polygon3};
+    use alloc::vec;
+    use serde_json::json;
+
+    fn to_json<G: ToGeoJson>(geom: G) -> serde_json::Value {
+        serde_json::to_value(GeoJson(geom)).unwrap()
+    }
+
+    #[test]
+    fn test_serialize_geometries() {
+        assert_eq!(
+            to_json(Point::new([1., 2.])),
+            json!({"type": "Point", "coordinates": [1., 2.]})
+        );
+        assert_eq!(
+            to_json(Point::new([f64::NAN; 2])),
+            json!({"type": "Point", "coordinates": []})
+        );
+        assert_eq!(
+            to_json(Line::new([0u32, 0], [1, 1])),
+            json!({"type": "LineString", "coordinates": [[0, 0], [1, 1]]})
+        );
+
+        let mut poly = Polygon3::new();
+        poly.add_ring([[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]]);
+        assert_eq!(
+            to_json(&poly),
+            json!({"type": "Polygon", "coordinates": [[[0., 0., 1.], [1., 0., 1.], [0., 1., 1.], [0., 0., 1.]]]})
+        );
+        assert_eq!(
+            to_json(Polygon2::<f64>::new()),
+            json!({"type": "Polygon", "coordinates": []})
+        );
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 1.]]);
+        mls.add_linestring([[2., 2.], [3., 3.]]);
+        assert_eq!(
+            to_json(&mls),
+            json!({"type": "MultiLineString", "coordinates": [[[0., 0.], [1., 1.]], [[2., 2.], [3., 3.]]]})
+        );
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.]]);
+        mpoly.add_interior([[1., 1.], [4., 1.], [4., 4.]]);
+        mpoly.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        assert_eq!(
+            to_json(&mpoly),
+            json!({"type": "MultiPolygon", "coordinates": [
+                [[[0., 0.], [5., 0.], [5., 5.], [0., 0.]], [[1., 1.], [4., 1.], [4., 4.], [1., 1.]]],
+                [[[6., 0.], [7., 0.], [7., 1.], [6., 0.]]],
+            ]})
+        );
+
+        let tri = Triangle::new([0., 0.], [1., 0.], [0., 1.]);
+        let mut mtri = MultiTriangle2::new();
+        mtri.push(&tri);
+        assert_eq!(
+            to_json(mtri),
+            json!({"type": "MultiPolygon", "coordinates": [[[[0., 0.], [1., 0.], [0., 1.], [0., 0.]]]]})
+        );
+
+        let geom = Geometry::GeometryCollection(vec![
+            Geometry::Triangle(tri),
+            Geometry::MultiPoint(MultiPoint::from_raw(vec![[1., 2.]].into())),
+        ]);
+        assert_eq!(
+            to_json(&geom),
+            json!({"type": "GeometryCollection", "geometries": [
+                {"type": "Polygon", "coordinates": [[[0., 0.], [1., 0.], [0., 1.], [0., 0.]]]},
+                {"type": "MultiPoint", "coordinates": [[1., 2.]]},
+            ]})
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Serde adapter for RFC 7946 GeoJSON geometry objects.
+//!
+//! The serde derives on the geometry types produce their internal flat layout.
+//! This module instead reads and writes the standard `{"type": ..., "coordinates": ...}` shape,
+//! either through the [`GeoJson`] wrapper or as a `#[serde(with = "flatgeom::geojson")]` module:
+//!
+//! ```
+//! use flatgeom::{geojson::GeoJson, Polygon2};
+//!
+//! let mut poly = Polygon2::new();
+//! poly.add_ring([[0., 0.], [1., 0.], [0., 1.]]);
+//! let json = serde_json::to_string(&GeoJson(&poly)).unwrap();
+//! assert_eq!(json, r#"{"type":"Polygon","coordinates":[[[0.0,0.0],[1.0,0.0],[0.0,1.0],[0.0,0.0]]]}"#);
+//!
+//! let GeoJson(poly2): GeoJson<Polygon2> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(poly, poly2);
+//! ```
+//!
+//! Rings are closed when written, and the closing points are removed when read, in the same way
+//! as [`Polygon::add_ring`](crate::Polygon::add_ring). Lines are written as LineStrings,
+//! Triangles as Polygons, and MultiTriangles as MultiPolygons.
+
+mod decode;
+mod encode;
+
+pub use decode::FromGeoJson;
+pub use encode::ToGeoJson;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Wrapper to serialize and deserialize a geometry as a GeoJSON geometry object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoJson<G>(pub G);
+
+impl<G: ToGeoJson> Serialize for GeoJson<G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_geojson(serializer)
+    }
+}
+
+impl<'de, G: FromGeoJson> Deserialize<'de> for GeoJson<G> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        G::deserialize_geojson(deserializer).map(GeoJson)
+    }
+}
+
+/// Serializes a geometry as GeoJSON, for use with `#[serde(with = "flatgeom::geojson")]`.
+pub fn serialize<G: ToGeoJson, S: Serializer>(geom: &G, serializer: S) -> Result<S::Ok, S::Error> {
+    geom.serialize_geojson(serializer)
+}
+
+/// Deserializes a geometry from GeoJSON, for use with `#[serde(with = "flatgeom::geojson")]`.
+pub fn deserialize<'de, G: FromGeoJson, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<G, D::Error> {
+    G::deserialize_geojson(deserializer)
+}
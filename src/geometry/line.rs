@@ -0,0 +1,122 @@
+use crate::Coord;
+
+/// Computer-friendly Line (a segment between two vertices)
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Line<T: Coord> {
+    /// Coordinates of the start and end points
+    coords: [T; 2],
+}
+
+pub type Line2<C = f64> = Line<[C; 2]>;
+pub type Line3<C = f64> = Line<[C; 3]>;
+
+impl<T: Coord> Line<T> {
+    /// Creates a Line from the start and end coordinates.
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            coords: [start, end],
+        }
+    }
+
+    /// Returns the start point of the Line.
+    pub fn start(&self) -> &T {
+        &self.coords[0]
+    }
+
+    /// Returns the end point of the Line.
+    pub fn end(&self) -> &T {
+        &self.coords[1]
+    }
+
+    pub fn raw_coords(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Returns iterator over the start and end points.
+    pub fn iter(&self) -> core::iter::Cloned<core::slice::Iter<'_, T>> {
+        self.coords.iter().cloned()
+    }
+
+    /// Create a new Line by applying the given transformation to both coordinates.
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> Line<T2> {
+        Line {
+            coords: [f(&self.coords[0]), f(&self.coords[1])],
+        }
+    }
+
+    /// Applies the given transformation to both coordinates of the Line.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.coords.iter_mut().for_each(|c| {
+            *c = f(c);
+        });
+    }
+
+    /// Swaps the start and end points of the Line.
+    pub fn reverse_inplace(&mut self) {
+        self.coords.swap(0, 1);
+    }
+}
+
+impl<T: Coord> AsRef<[T]> for Line<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.coords
+    }
+}
+
+impl<'a, T: Coord> IntoIterator for &'a Line<T> {
+    type Item = T;
+    type IntoIter = core::iter::Cloned<core::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedLine<T> {
+    pub fn start(&self) -> &T::Archived {
+        &self.coords[0]
+    }
+
+    pub fn end(&self) -> &T::Archived {
+        &self.coords[1]
+    }
+
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_line_basic() {
+        let mut line = Line2::new([0., 1.], [2., 3.]);
+        assert_eq!(line.start(), &[0., 1.]);
+        assert_eq!(line.end(), &[2., 3.]);
+        assert_eq!(line.raw_coords(), [[0., 1.], [2., 3.]]);
+        assert_eq!(line.iter().collect::<Vec<_>>(), [[0., 1.], [2., 3.]]);
+
+        line.reverse_inplace();
+        assert_eq!(line.start(), &[2., 3.]);
+        assert_eq!(line.end(), &[0., 1.]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut line = Line2::new([0., 0.], [5., 0.]);
+        let new_line = line.transform(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(new_line.raw_coords(), [[2., 1.], [7., 1.]]);
+
+        line.transform_inplace(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(line.raw_coords(), [[2., 1.], [7., 1.]]);
+    }
+}
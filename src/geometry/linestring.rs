@@ -3,11 +3,16 @@ use alloc::borrow::Cow;
 
 /// Computer-friendly LineString
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct LineString<'a, T: Coord> {
     /// Coordinates of all points
     ///
     /// e.g. `[x0, y0, z0, x1, y1, z1, ...]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     coords: Cow<'a, [T]>,
 }
 
@@ -31,7 +36,7 @@ impl<'a, T: Coord> LineString<'a, T> {
     }
 
     /// Returns iterator over the all points in the LineString.
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             slice: &self.coords,
             pos: 0,
@@ -40,7 +45,7 @@ impl<'a, T: Coord> LineString<'a, T> {
     }
 
     /// Returns iterator over the all points with the start point repeated.
-    pub fn iter_closed(&self) -> Iter<T> {
+    pub fn iter_closed(&self) -> Iter<'_, T> {
         Iter {
             slice: &self.coords,
             pos: 0,
@@ -74,7 +79,7 @@ impl<'a, T: Coord> LineString<'a, T> {
     }
 
     /// Create a new LineString by applying the given transformation to all coordinates.
-    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> LineString<T2> {
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> LineString<'_, T2> {
         LineString {
             coords: self.coords.iter().map(f).collect(),
         }
@@ -103,7 +108,7 @@ impl<'a, T: Coord> LineString<'a, T> {
         let len = self.coords.len();
         if len > 1 {
             let data = self.coords.to_mut();
-            for i in 1..(data.len() + 1) / 2 {
+            for i in 1..data.len().div_ceil(2) {
                 data.swap(i, len - i);
             }
         }
@@ -182,6 +187,26 @@ impl<'a, T: Coord> Iterator for Iter<'a, T> {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedLineString<'_, T> {
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+
+    /// Returns an iterator over the archived coordinates.
+    pub fn iter(&self) -> core::slice::Iter<'_, T::Archived> {
+        self.coords.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,16 +1,43 @@
 use alloc::vec::Vec;
 
+mod line;
 mod linestring;
 mod multi_linestring;
 mod multi_point;
 mod multi_polygon;
+mod multi_polygon_batch;
+mod multi_triangle;
+mod point;
 mod polygon;
+mod rect;
+mod triangle;
 
+pub use line::{Line, Line2, Line3};
 pub use linestring::{LineString, LineString2, LineString3};
 pub use multi_linestring::{MultiLineString, MultiLineString2, MultiLineString3};
 pub use multi_point::{MultiPoint, MultiPoint2, MultiPoint3};
 pub use multi_polygon::{MultiPolygon, MultiPolygon2, MultiPolygon3};
-pub use polygon::{Polygon, Polygon2, Polygon3};
+pub use multi_polygon_batch::{MultiPolygonBatch, MultiPolygonBatch2, MultiPolygonBatch3};
+pub use multi_triangle::{MultiTriangle, MultiTriangle2, MultiTriangle3};
+pub use point::{Point, Point2, Point3};
+pub use polygon::{Orientation, Polygon, Polygon2, Polygon3};
+pub use rect::{Rect, Rect2, Rect3};
+pub use triangle::{Triangle, Triangle2, Triangle3};
+
+#[cfg(feature = "rkyv")]
+pub use {
+    line::ArchivedLine,
+    linestring::ArchivedLineString,
+    multi_linestring::ArchivedMultiLineString,
+    multi_point::ArchivedMultiPoint,
+    multi_polygon::{ArchivedMultiPolygon, ArchivedMultiPolygonRef},
+    multi_polygon_batch::ArchivedMultiPolygonBatch,
+    multi_triangle::ArchivedMultiTriangle,
+    point::ArchivedPoint,
+    polygon::{ArchivedPolygon, ArchivedPolygonRef},
+    rect::ArchivedRect,
+    triangle::ArchivedTriangle,
+};
 
 use num_traits::ToPrimitive;
 
@@ -40,29 +67,82 @@ impl<N: CoordNum> Coord2d for [N; 2] {
     }
 }
 
+pub trait Coord3d: Coord {
+    fn xyz(&self) -> (f64, f64, f64);
+}
+
+impl<N: CoordNum> Coord3d for [N; 3] {
+    fn xyz(&self) -> (f64, f64, f64) {
+        (
+            self[0].to_f64().unwrap(),
+            self[1].to_f64().unwrap(),
+            self[2].to_f64().unwrap(),
+        )
+    }
+}
+
 /// Computer-friendly Geometry
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(tag = "type")
 )]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(serialize_bounds(
+        __S: rkyv::ser::Writer + rkyv::ser::Allocator,
+        __S::Error: rkyv::rancor::Source,
+    )),
+    rkyv(deserialize_bounds(__D::Error: rkyv::rancor::Source)),
+    rkyv(bytecheck(bounds(
+        __C: rkyv::validation::ArchiveContext,
+        __C::Error: rkyv::rancor::Source,
+    )))
+)]
 #[derive(Debug, Clone)]
 pub enum Geometry<'a, T: Coord> {
-    // Point?
+    Point(Point<T>),
     MultiPoint(MultiPoint<'a, T>),
-    // Line?
+    Line(Line<T>),
     LineString(LineString<'a, T>),
     MultiLineString(MultiLineString<'a, T>),
     Polygon(Polygon<'a, T>),
     MultiPolygon(MultiPolygon<'a, T>),
-    // Triangle?
-    // TIN?
-    GeometryCollection(Vec<Geometry<'a, T>>),
+    Triangle(Triangle<T>),
+    MultiTriangle(MultiTriangle<'a, T>),
+    GeometryCollection(#[cfg_attr(feature = "rkyv", rkyv(omit_bounds))] Vec<Geometry<'a, T>>),
 }
 
 pub type Geometry2<'a, C = f64> = Geometry<'a, [C; 2]>;
 pub type Geometry3<'a, C = f64> = Geometry<'a, [C; 3]>;
 
+impl<T: Coord> Geometry<'_, T> {
+    /// Applies the given transformation to all coordinates in the Geometry.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.transform_inplace_dyn(&mut f);
+    }
+
+    fn transform_inplace_dyn(&mut self, f: &mut dyn FnMut(&T) -> T) {
+        match self {
+            Geometry::Point(geom) => geom.transform_inplace(f),
+            Geometry::MultiPoint(geom) => geom.transform_inplace(f),
+            Geometry::Line(geom) => geom.transform_inplace(f),
+            Geometry::LineString(geom) => geom.transform_inplace(f),
+            Geometry::MultiLineString(geom) => geom.transform_inplace(f),
+            Geometry::Polygon(geom) => geom.transform_inplace(f),
+            Geometry::MultiPolygon(geom) => geom.transform_inplace(f),
+            Geometry::Triangle(geom) => geom.transform_inplace(f),
+            Geometry::MultiTriangle(geom) => geom.transform_inplace(f),
+            Geometry::GeometryCollection(geoms) => {
+                for geom in geoms {
+                    geom.transform_inplace_dyn(f);
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,4 +167,70 @@ mod tests {
             LineString::from_raw(vec![[0.0, 0.0], [1.0, 1.0], [-2.0, 3.0]].into());
         assert_eq!(a.ring_area(), 2.5);
     }
+
+    #[test]
+    fn test_geometry_transform_inplace() {
+        let mut geom = Geometry2::GeometryCollection(vec![
+            Geometry::Point(Point::new([1., 2.])),
+            Geometry::LineString(LineString::from_raw(vec![[0., 0.], [1., 1.]].into())),
+        ]);
+        geom.transform_inplace(|[x, y]| [x + 10., y * 2.]);
+        let Geometry::GeometryCollection(geoms) = geom else {
+            unreachable!();
+        };
+        let Geometry::Point(p) = &geoms[0] else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[11., 4.]);
+        let Geometry::LineString(ls) = &geoms[1] else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[10., 0.], [11., 2.]]);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_geometry_rkyv() {
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 1.]]);
+        mls.add_linestring([[2., 2.], [3., 3.], [4., 4.]]);
+        let geom = Geometry2::GeometryCollection(vec![
+            Geometry::Point(Point::new([1., 2.])),
+            Geometry::MultiLineString(mls),
+            Geometry::GeometryCollection(vec![Geometry::Triangle(Triangle::new(
+                [0., 0.],
+                [1., 0.],
+                [0., 1.],
+            ))]),
+        ]);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&geom).unwrap();
+        let archived =
+            rkyv::access::<ArchivedGeometry<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        let ArchivedGeometry::GeometryCollection(geoms) = archived else {
+            panic!("GeometryCollection is expected");
+        };
+        let ArchivedGeometry::Point(p) = &geoms[0] else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2.]);
+        let ArchivedGeometry::MultiLineString(mls) = &geoms[1] else {
+            panic!("MultiLineString is expected");
+        };
+        assert_eq!(mls.len(), 2);
+        assert_eq!(mls.iter().nth(1).unwrap(), [[2., 2.], [3., 3.], [4., 4.]]);
+
+        let deserialized: Geometry2 =
+            rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+        let Geometry::GeometryCollection(geoms) = deserialized else {
+            panic!("GeometryCollection is expected");
+        };
+        let Geometry::GeometryCollection(inner) = &geoms[2] else {
+            panic!("GeometryCollection is expected");
+        };
+        let Geometry::Triangle(tri) = &inner[0] else {
+            panic!("Triangle is expected");
+        };
+        assert_eq!(tri.raw_coords(), [[0., 0.], [1., 0.], [0., 1.]]);
+    }
 }
@@ -1,21 +1,30 @@
 use alloc::borrow::Cow;
 use core::ops::Range;
 
+use crate::error::{check_spans, Result, SpanKind};
+
 use super::{linestring::LineString, Coord};
 
 /// Computer-friendly MultiString
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct MultiLineString<'a, T: Coord> {
     /// All coordinates of all LineStrings
     ///
     /// e.g. `[x0, y0, z0, x1, y1, z1, ...]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     all_coords: Cow<'a, [T]>,
 
     /// A sequence of indices of all_coords from which each linestring starts
     /// (the first linestring always starts from 0 so it is omitted)
     ///
     /// e.g. `[5, 12, 23]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     coords_spans: Cow<'a, [u32]>,
 }
 
@@ -31,18 +40,21 @@ impl<'a, T: Coord> MultiLineString<'a, T> {
         }
     }
 
+    /// Creates a new MultiLineString from the given raw data, panicking if `coords_spans` is invalid.
+    ///
+    /// See [`MultiLineString::try_from_raw`] for a non-panicking version.
     pub fn from_raw(all_coords: Cow<'a, [T]>, coords_spans: Cow<'a, [u32]>) -> Self {
+        Self::try_from_raw(all_coords, coords_spans).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new MultiLineString from the given raw data, checking for validity.
+    pub fn try_from_raw(all_coords: Cow<'a, [T]>, coords_spans: Cow<'a, [u32]>) -> Result<Self> {
         // Check if all span values are within range and monotonically increasing
-        if let Some(&last) = coords_spans.last() {
-            let len = (all_coords.len()) as u32;
-            if last >= len || coords_spans.windows(2).any(|a| a[0] >= a[1]) {
-                panic!("invalid coords_spans")
-            }
-        }
-        Self {
+        check_spans(SpanKind::CoordsSpans, &coords_spans, 0, all_coords.len())?;
+        Ok(Self {
             all_coords,
             coords_spans,
-        }
+        })
     }
 
     pub fn from_raw_unchecked(all_coords: Cow<'a, [T]>, coords_spans: Cow<'a, [u32]>) -> Self {
@@ -52,8 +64,18 @@ impl<'a, T: Coord> MultiLineString<'a, T> {
         }
     }
 
+    pub fn raw_coords(&self) -> &[T] {
+        self.all_coords.as_ref()
+    }
+
+    /// A sequence of indices of the coordinates from which each linestring starts
+    /// (the first linestring always starts from 0 so it is omitted)
+    pub fn coords_spans(&self) -> &[u32] {
+        self.coords_spans.as_ref()
+    }
+
     /// Returns iterator over the linestrings.
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             ls: self,
             pos: 0,
@@ -62,7 +84,7 @@ impl<'a, T: Coord> MultiLineString<'a, T> {
     }
 
     /// Returns iterator over the linestrings in the given range.
-    pub fn iter_range(&self, range: Range<usize>) -> Iter<T> {
+    pub fn iter_range(&self, range: Range<usize>) -> Iter<'_, T> {
         Iter {
             ls: self,
             pos: range.start,
@@ -101,7 +123,7 @@ impl<'a, T: Coord> MultiLineString<'a, T> {
     }
 
     /// Create a new MultiLineString by applying the given transformation to all coordinates.
-    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiLineString<T2> {
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiLineString<'_, T2> {
         MultiLineString {
             all_coords: self.all_coords.iter().map(f).collect(),
             coords_spans: self.coords_spans.clone(),
@@ -157,6 +179,99 @@ impl<'a, T: Coord> Iterator for Iter<'a, T> {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl<'a, T: Coord + rkyv::Archive> ArchivedMultiLineString<'a, T> {
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.all_coords
+    }
+
+    /// A sequence of indices of all_coords from which each linestring (except the first) starts
+    pub fn coords_spans(&self) -> &[rkyv::Archived<u32>] {
+        &self.coords_spans
+    }
+
+    /// Returns an iterator over the coordinates of the linestrings.
+    pub fn iter(&self) -> ArchivedIter<'_, 'a, T> {
+        ArchivedIter {
+            mls: self,
+            pos: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns the number of linestrings.
+    pub fn len(&self) -> usize {
+        if self.all_coords.is_empty() {
+            0
+        } else {
+            self.coords_spans.len() + 1
+        }
+    }
+
+    /// Returns `true` if the MultiLineString is empty.
+    pub fn is_empty(&self) -> bool {
+        self.all_coords.is_empty()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+// SAFETY: `verify` returns `Ok` only if the spans are valid for the archived buffers.
+unsafe impl<C, T> rkyv::bytecheck::Verify<C> for ArchivedMultiLineString<'_, T>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+    T: Coord + rkyv::Archive,
+{
+    fn verify(&self, _context: &mut C) -> core::result::Result<(), C::Error> {
+        crate::error::verify::<C>(check_spans(
+            SpanKind::CoordsSpans,
+            &self.coords_spans,
+            0,
+            self.all_coords.len(),
+        ))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, 'b, T: Coord + rkyv::Archive> IntoIterator for &'a ArchivedMultiLineString<'b, T> {
+    type Item = &'a [T::Archived];
+    type IntoIter = ArchivedIter<'a, 'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub struct ArchivedIter<'a, 'b, T: Coord + rkyv::Archive> {
+    mls: &'a ArchivedMultiLineString<'b, T>,
+    pos: usize,
+    end: usize,
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, T: Coord + rkyv::Archive> Iterator for ArchivedIter<'a, '_, T> {
+    type Item = &'a [T::Archived];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.end {
+            let spans = &self.mls.coords_spans;
+            let start = match self.pos {
+                0 => 0,
+                pos => spans[pos - 1].to_native() as usize,
+            };
+            let end = match spans.get(self.pos) {
+                Some(i) => i.to_native() as usize,
+                None => self.mls.all_coords.len(),
+            };
+            self.pos += 1;
+            Some(&self.mls.all_coords[start..end])
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,6 +384,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mline_try_from_raw() {
+        use crate::Error;
+
+        let all_coords: Vec<[f64; 2]> = (0..=4).map(|i| [i as f64, i as f64]).collect();
+        let mline = MultiLineString2::try_from_raw(all_coords[..].into(), [2][..].into()).unwrap();
+        assert_eq!(mline.len(), 2);
+
+        assert_eq!(
+            MultiLineString2::try_from_raw(all_coords[..].into(), [2, 5][..].into()),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::CoordsSpans,
+                index: 1,
+                value: 5,
+                len: 5,
+            })
+        );
+        assert_eq!(
+            MultiLineString2::try_from_raw(all_coords[..].into(), [3, 3][..].into()),
+            Err(Error::NonMonotonicSpans {
+                kind: SpanKind::CoordsSpans,
+                index: 1,
+            })
+        );
+    }
+
     #[test]
     #[should_panic(expected = "invalid coords_spans")]
     fn test_mline_invalid_coords_spans_1() {
@@ -286,4 +427,27 @@ mod tests {
         let _polygon: MultiLineString2<f64> =
             MultiLineString2::from_raw(all_coords.into(), coords_spans.into());
     }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let mline = MultiLineString2::from_raw(
+            vec![[0., 0.], [1., 1.], [2., 2.], [3., 3.]].into(),
+            vec![2].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&mline).unwrap();
+        let archived =
+            rkyv::access::<ArchivedMultiLineString<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.iter().count(), 2);
+
+        // invalid spans are rejected when accessed
+        let invalid = MultiLineString2::<f64>::from_raw_unchecked(
+            vec![[0., 0.], [1., 1.]].into(),
+            vec![3].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&invalid).unwrap();
+        assert!(
+            rkyv::access::<ArchivedMultiLineString<[f64; 2]>, rkyv::rancor::Error>(&bytes).is_err()
+        );
+    }
 }
@@ -4,11 +4,16 @@ use core::ops::Range;
 
 /// Computer-friendly MultiPoint
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct MultiPoint<'a, T: Coord> {
     /// Coordinates of all points
     ///
     /// e.g. `[x0, y0, z0, x1, y1, z1, ...]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     coords: Cow<'a, [T]>,
 }
 
@@ -32,7 +37,7 @@ impl<'a, T: Coord> MultiPoint<'a, T> {
     }
 
     /// Returns iterator over the all points.
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             slice: &self.coords,
             pos: 0,
@@ -41,7 +46,7 @@ impl<'a, T: Coord> MultiPoint<'a, T> {
     }
 
     /// Returns iterator over the points in the given range.
-    pub fn iter_range(&self, range: Range<usize>) -> Iter<T> {
+    pub fn iter_range(&self, range: Range<usize>) -> Iter<'_, T> {
         Iter {
             slice: &self.coords,
             pos: range.start,
@@ -80,7 +85,7 @@ impl<'a, T: Coord> MultiPoint<'a, T> {
     }
 
     /// Create a new MultiPoint by applying the given transformation to all coordinates.
-    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiPoint<T2> {
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiPoint<'_, T2> {
         MultiPoint {
             coords: self.coords.iter().map(f).collect(),
         }
@@ -129,6 +134,26 @@ impl<'a, T: Coord> Iterator for Iter<'a, T> {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedMultiPoint<'_, T> {
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+
+    /// Returns an iterator over the archived coordinates.
+    pub fn iter(&self) -> core::slice::Iter<'_, T::Archived> {
+        self.coords.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,26 +1,43 @@
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use core::ops::Range;
 
-use super::{polygon::Polygon, Coord};
+#[cfg(feature = "rkyv")]
+use super::polygon::ArchivedPolygonRef;
+use super::{
+    polygon::{misoriented_rings, orient_rings, Orientation, Polygon},
+    Coord,
+};
+use crate::error::{check_spans, Error, Result, SpanKind};
+use crate::Coord2d;
 
 /// Computer-friendly MultiPolygon
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct MultiPolygon<'a, T: Coord> {
     /// All coordinates of all polygons
     ///
     /// e.g. `[x0, y0, z0, x1, y1, z1, ...]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     all_coords: Cow<'a, [T]>,
 
     /// A sequence of indices of all_coords from which each polygon starts
     /// (the first polygon always starts from 0 so it is omitted)
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     coords_spans: Cow<'a, [u32]>,
 
     /// All hole_indices (See `Polygon`) of all polygons
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     all_hole_indices: Cow<'a, [u32]>,
 
     /// A sequence of indices of all_hole_indices from which each polygon starts
     /// (the first polygon always starts from 0 so it is omitted)
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     holes_spans: Cow<'a, [u32]>,
 }
 
@@ -38,52 +55,38 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
         }
     }
 
-    /// Create a new polygon from the given raw data, checking for validity.
+    /// Create a new polygon from the given raw data, panicking if the spans are invalid.
+    ///
+    /// See [`MultiPolygon::try_from_raw`] for a non-panicking version.
     pub fn from_raw(
         all_coords: Cow<'a, [T]>,
         coords_spans: Cow<'a, [u32]>,
         all_hole_indices: Cow<'a, [u32]>,
         holes_spans: Cow<'a, [u32]>,
     ) -> Self {
-        if coords_spans.len() != holes_spans.len() {
-            panic!("coords_spans and holes_spans must have the same length");
-        }
-
-        // check multipolygon
-        let mut cs_start = 0;
-        let mut hs_start = 0;
-        coords_spans
-            .iter()
-            .zip(holes_spans.iter())
-            .chain(Some((
-                &((all_coords.len()) as u32),
-                &((all_hole_indices.len()) as u32),
-            )))
-            .for_each(|(&cs_end, &hs_end)| {
-                if cs_start > cs_end {
-                    panic!("invalid coords_spans");
-                }
-                if hs_start > hs_end {
-                    panic!("invalid holes_spans");
-                }
-                // check polygon
-                let coords = &all_coords[cs_start as usize..cs_end as usize];
-                let hole_indices = &all_hole_indices[hs_start as usize..hs_end as usize];
-                if let Some(&last) = hole_indices.last() {
-                    let len = (coords.len()) as u32;
-                    if last >= len || hole_indices.windows(2).any(|a| a[0] >= a[1]) {
-                        panic!("invalid hole_indices")
-                    }
-                }
-                (cs_start, hs_start) = (cs_end, hs_end);
-            });
+        Self::try_from_raw(all_coords, coords_spans, all_hole_indices, holes_spans)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        Self {
+    /// Create a new polygon from the given raw data, checking for validity.
+    pub fn try_from_raw(
+        all_coords: Cow<'a, [T]>,
+        coords_spans: Cow<'a, [u32]>,
+        all_hole_indices: Cow<'a, [u32]>,
+        holes_spans: Cow<'a, [u32]>,
+    ) -> Result<Self> {
+        check_raw(
+            all_coords.len(),
+            &coords_spans,
+            &all_hole_indices,
+            &holes_spans,
+        )?;
+        Ok(Self {
             all_coords,
             coords_spans,
             holes_spans,
             all_hole_indices,
-        }
+        })
     }
 
     /// Creates a new multipolygon from the given raw data, without validity check.
@@ -101,6 +104,27 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
         }
     }
 
+    pub fn raw_coords(&self) -> &[T] {
+        self.all_coords.as_ref()
+    }
+
+    /// A sequence of indices of the coordinates from which each polygon starts
+    /// (the first polygon always starts from 0 so it is omitted)
+    pub fn coords_spans(&self) -> &[u32] {
+        self.coords_spans.as_ref()
+    }
+
+    /// All hole indices of all polygons, relative to the start of each polygon
+    pub fn all_hole_indices(&self) -> &[u32] {
+        self.all_hole_indices.as_ref()
+    }
+
+    /// A sequence of indices of `all_hole_indices` from which each polygon starts
+    /// (the first polygon always starts from 0 so it is omitted)
+    pub fn holes_spans(&self) -> &[u32] {
+        self.holes_spans.as_ref()
+    }
+
     /// Returns the number of polygons in the multipolygon.
     pub fn len(&self) -> usize {
         match self.coords_spans.len() {
@@ -118,7 +142,7 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
     }
 
     /// Returns an iterator over the polygons
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             mpoly: self,
             pos: 0,
@@ -127,7 +151,7 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
     }
 
     /// Returns an iterator over the polygons in the given range.
-    pub fn iter_range(&self, range: Range<usize>) -> Iter<T> {
+    pub fn iter_range(&self, range: Range<usize>) -> Iter<'_, T> {
         Iter {
             mpoly: self,
             pos: range.start,
@@ -228,7 +252,7 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
     }
 
     /// Create a new MultiPolygon by applying the given transformation to all coordinates.
-    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiPolygon<T2> {
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiPolygon<'_, T2> {
         MultiPolygon {
             all_coords: self.all_coords.iter().map(f).collect(),
             coords_spans: self.coords_spans.clone(),
@@ -245,6 +269,62 @@ impl<'a, T: Coord> MultiPolygon<'a, T> {
     }
 }
 
+// 2-dimensional only
+impl<T: Coord2d> MultiPolygon<'_, T> {
+    /// Returns the coordinate and hole index ranges of each polygon.
+    fn polygon_ranges(&self) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+        let len = self.len();
+        (0..len).map(move |i| {
+            let start = |spans: &[u32], i: usize| if i == 0 { 0 } else { spans[i - 1] as usize };
+            let end =
+                |spans: &[u32], total: usize, i: usize| spans.get(i).map_or(total, |&s| s as usize);
+            (
+                start(&self.coords_spans, i)..end(&self.coords_spans, self.all_coords.len(), i),
+                start(&self.holes_spans, i)..end(&self.holes_spans, self.all_hole_indices.len(), i),
+            )
+        })
+    }
+
+    /// Reverses the rings that violate the orientation, in place.
+    ///
+    /// The first coordinate of each ring is preserved.
+    pub fn orient(&mut self, orientation: Orientation) {
+        if self.is_oriented(orientation) {
+            return;
+        }
+        let ranges: Vec<_> = self.polygon_ranges().collect();
+        let all_coords = self.all_coords.to_mut();
+        for (coords, holes) in ranges {
+            orient_rings(
+                &mut all_coords[coords],
+                &self.all_hole_indices[holes],
+                orientation,
+            );
+        }
+    }
+
+    /// Returns `true` if all rings follow the orientation.
+    pub fn is_oriented(&self, orientation: Orientation) -> bool {
+        self.misoriented_rings(orientation).is_empty()
+    }
+
+    /// Returns the `(polygon, ring)` indices of the rings that violate the orientation,
+    /// where ring 0 is the exterior.
+    pub fn misoriented_rings(&self, orientation: Orientation) -> Vec<(usize, usize)> {
+        self.polygon_ranges()
+            .enumerate()
+            .flat_map(|(i, (coords, holes))| {
+                misoriented_rings(
+                    &self.all_coords[coords],
+                    &self.all_hole_indices[holes],
+                    orientation,
+                )
+                .map(move |ring| (i, ring))
+            })
+            .collect()
+    }
+}
+
 impl<'a, T: Coord> IntoIterator for &'a MultiPolygon<'_, T> {
     type Item = Polygon<'a, T>;
     type IntoIter = Iter<'a, T>;
@@ -275,6 +355,286 @@ impl<'a, T: Coord> Iterator for Iter<'a, T> {
     }
 }
 
+/// Checks the raw buffers of a MultiPolygon with `coords_len` coordinates.
+pub(crate) fn check_raw<S: Copy + Into<u32>>(
+    coords_len: usize,
+    coords_spans: &[S],
+    all_hole_indices: &[S],
+    holes_spans: &[S],
+) -> Result<()> {
+    if coords_spans.len() != holes_spans.len() {
+        return Err(Error::SpanLengthMismatch {
+            coords_spans: coords_spans.len(),
+            holes_spans: holes_spans.len(),
+        });
+    }
+
+    // check multipolygon
+    let n = coords_spans.len();
+    let (mut cs_start, mut hs_start) = (0, 0);
+    for i in 0..=n {
+        let (cs_end, hs_end) = if i < n {
+            let (cs_end, hs_end) = (
+                coords_spans[i].into() as usize,
+                holes_spans[i].into() as usize,
+            );
+            for (kind, start, end, len) in [
+                (SpanKind::CoordsSpans, cs_start, cs_end, coords_len),
+                (
+                    SpanKind::HolesSpans,
+                    hs_start,
+                    hs_end,
+                    all_hole_indices.len(),
+                ),
+            ] {
+                if end > len {
+                    return Err(Error::SpanOutOfRange {
+                        kind,
+                        index: i,
+                        value: end as u32,
+                        len,
+                    });
+                }
+                if start > end {
+                    return Err(Error::NonMonotonicSpans { kind, index: i });
+                }
+            }
+            (cs_end, hs_end)
+        } else {
+            (coords_len, all_hole_indices.len())
+        };
+        // check polygon
+        check_spans(
+            SpanKind::HoleIndices,
+            &all_hole_indices[hs_start..hs_end],
+            hs_start,
+            cs_end - cs_start,
+        )?;
+        (cs_start, hs_start) = (cs_end, hs_end);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedMultiPolygon<'_, T> {
+    fn as_multi_polygon_ref(&self) -> ArchivedMultiPolygonRef<'_, T::Archived> {
+        ArchivedMultiPolygonRef::new(
+            &self.all_coords,
+            &self.coords_spans,
+            &self.all_hole_indices,
+            &self.holes_spans,
+        )
+    }
+
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.all_coords
+    }
+
+    /// A sequence of indices of all_coords from which each polygon (except the first) starts
+    pub fn coords_spans(&self) -> &[rkyv::Archived<u32>] {
+        &self.coords_spans
+    }
+
+    /// All hole_indices of all polygons, relative to the start of each polygon
+    pub fn all_hole_indices(&self) -> &[rkyv::Archived<u32>] {
+        &self.all_hole_indices
+    }
+
+    /// A sequence of indices of all_hole_indices from which each polygon (except the first) starts
+    pub fn holes_spans(&self) -> &[rkyv::Archived<u32>] {
+        &self.holes_spans
+    }
+
+    /// Returns the number of polygons in the multipolygon.
+    pub fn len(&self) -> usize {
+        self.as_multi_polygon_ref().len()
+    }
+
+    /// Returns `true` if the multipolygon contains no polygons.
+    pub fn is_empty(&self) -> bool {
+        self.all_coords.is_empty()
+    }
+
+    /// Returns an iterator over the polygons
+    pub fn iter(&self) -> ArchivedIter<'_, T::Archived> {
+        self.as_multi_polygon_ref().iter()
+    }
+
+    /// Returns the polygon at the given index.
+    pub fn get(&self, index: usize) -> ArchivedPolygonRef<'_, T::Archived> {
+        self.as_multi_polygon_ref().get(index)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+// SAFETY: `verify` returns `Ok` only if the spans are valid for the archived buffers.
+unsafe impl<C, T> rkyv::bytecheck::Verify<C> for ArchivedMultiPolygon<'_, T>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+    T: Coord + rkyv::Archive,
+{
+    fn verify(&self, _context: &mut C) -> core::result::Result<(), C::Error> {
+        crate::error::verify::<C>(check_raw(
+            self.all_coords.len(),
+            &self.coords_spans,
+            &self.all_hole_indices,
+            &self.holes_spans,
+        ))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, T: Coord + rkyv::Archive> IntoIterator for &'a ArchivedMultiPolygon<'_, T> {
+    type Item = ArchivedPolygonRef<'a, T::Archived>;
+    type IntoIter = ArchivedIter<'a, T::Archived>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrowed view of a multipolygon stored in an archived buffer
+///
+/// Yielded by [`ArchivedMultiPolygonBatch::iter`](crate::ArchivedMultiPolygonBatch::iter).
+#[cfg(feature = "rkyv")]
+#[derive(Debug)]
+pub struct ArchivedMultiPolygonRef<'a, A> {
+    all_coords: &'a [A],
+    coords_spans: &'a [rkyv::Archived<u32>],
+    all_hole_indices: &'a [rkyv::Archived<u32>],
+    holes_spans: &'a [rkyv::Archived<u32>],
+}
+
+#[cfg(feature = "rkyv")]
+impl<A> Clone for ArchivedMultiPolygonRef<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<A> Copy for ArchivedMultiPolygonRef<'_, A> {}
+
+#[cfg(feature = "rkyv")]
+impl<'a, A> ArchivedMultiPolygonRef<'a, A> {
+    pub(crate) fn new(
+        all_coords: &'a [A],
+        coords_spans: &'a [rkyv::Archived<u32>],
+        all_hole_indices: &'a [rkyv::Archived<u32>],
+        holes_spans: &'a [rkyv::Archived<u32>],
+    ) -> Self {
+        Self {
+            all_coords,
+            coords_spans,
+            all_hole_indices,
+            holes_spans,
+        }
+    }
+
+    pub fn raw_coords(&self) -> &'a [A] {
+        self.all_coords
+    }
+
+    /// A sequence of indices of all_coords from which each polygon (except the first) starts
+    pub fn coords_spans(&self) -> &'a [rkyv::Archived<u32>] {
+        self.coords_spans
+    }
+
+    /// All hole_indices of all polygons, relative to the start of each polygon
+    pub fn all_hole_indices(&self) -> &'a [rkyv::Archived<u32>] {
+        self.all_hole_indices
+    }
+
+    /// A sequence of indices of all_hole_indices from which each polygon (except the first) starts
+    pub fn holes_spans(&self) -> &'a [rkyv::Archived<u32>] {
+        self.holes_spans
+    }
+
+    /// Returns the number of polygons in the multipolygon.
+    pub fn len(&self) -> usize {
+        match self.coords_spans.len() {
+            0 => match self.all_coords.len() {
+                0 => 0,
+                _ => 1,
+            },
+            len => len + 1,
+        }
+    }
+
+    /// Returns `true` if the multipolygon contains no polygons.
+    pub fn is_empty(&self) -> bool {
+        self.all_coords.is_empty()
+    }
+
+    /// Returns an iterator over the polygons
+    pub fn iter(&self) -> ArchivedIter<'a, A> {
+        ArchivedIter {
+            mpoly: *self,
+            pos: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns the polygon at the given index.
+    pub fn get(&self, index: usize) -> ArchivedPolygonRef<'a, A> {
+        let len = self.len();
+        if index >= len {
+            panic!(
+                "index out of bounds: {} polygon(s) but index is {}",
+                len, index
+            );
+        }
+        let span = |spans: &[rkyv::Archived<u32>], total: usize| {
+            let start = match index {
+                0 => 0,
+                _ => spans[index - 1].to_native() as usize,
+            };
+            let end = match index == len - 1 {
+                true => total,
+                false => spans[index].to_native() as usize,
+            };
+            start..end
+        };
+        ArchivedPolygonRef::new(
+            &self.all_coords[span(self.coords_spans, self.all_coords.len())],
+            &self.all_hole_indices[span(self.holes_spans, self.all_hole_indices.len())],
+        )
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, A> IntoIterator for ArchivedMultiPolygonRef<'a, A> {
+    type Item = ArchivedPolygonRef<'a, A>;
+    type IntoIter = ArchivedIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub struct ArchivedIter<'a, A> {
+    mpoly: ArchivedMultiPolygonRef<'a, A>,
+    pos: usize,
+    end: usize,
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, A> Iterator for ArchivedIter<'a, A> {
+    type Item = ArchivedPolygonRef<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.end {
+            let poly = self.mpoly.get(self.pos);
+            self.pos += 1;
+            Some(poly)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{super::polygon::Polygon2, *};
@@ -539,6 +899,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_from_raw_invalid() {
+        let coords = [[0.0, 0.0], [5.0, 0.0], [5.0, 5.0], [0.0, 1.0]];
+        assert_eq!(
+            MultiPolygon2::try_from_raw(
+                coords[..].into(),
+                [2][..].into(),
+                [1, 99][..].into(),
+                [1][..].into(),
+            ),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::HoleIndices,
+                index: 1,
+                value: 99,
+                len: 2,
+            })
+        );
+        assert_eq!(
+            MultiPolygon2::try_from_raw(
+                coords[..].into(),
+                [99][..].into(),
+                [1, 1][..].into(),
+                [1][..].into(),
+            ),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::CoordsSpans,
+                index: 0,
+                value: 99,
+                len: 4,
+            })
+        );
+        assert_eq!(
+            MultiPolygon2::try_from_raw(
+                coords[..].into(),
+                [1, 2][..].into(),
+                [][..].into(),
+                [0][..].into(),
+            ),
+            Err(Error::SpanLengthMismatch {
+                coords_spans: 2,
+                holes_spans: 1,
+            })
+        );
+        assert_eq!(
+            MultiPolygon2::try_from_raw(
+                coords[..].into(),
+                [2, 1][..].into(),
+                [][..].into(),
+                [0, 0][..].into(),
+            ),
+            Err(Error::NonMonotonicSpans {
+                kind: SpanKind::CoordsSpans,
+                index: 1,
+            })
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_from_raw_invalid_1() {
@@ -607,4 +1024,89 @@ mod tests {
             [1, 0][..].into(), // not increasing
         );
     }
+
+    #[test]
+    fn test_orient() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        mpoly.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        mpoly.add_exterior([[10., 0.], [10., 5.], [15., 5.], [15., 0.]]);
+        mpoly.add_interior([[11., 1.], [11., 2.], [12., 2.], [12., 1.]]);
+        assert_eq!(
+            mpoly.misoriented_rings(Orientation::CounterClockwise),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            mpoly.misoriented_rings(Orientation::Clockwise),
+            [(0, 0), (1, 1)]
+        );
+
+        let (coords_spans, holes_spans) = (mpoly.coords_spans.clone(), mpoly.holes_spans.clone());
+        mpoly.orient(Orientation::CounterClockwise);
+        assert!(mpoly.is_oriented(Orientation::CounterClockwise));
+        for poly in &mpoly {
+            assert!(poly.exterior().is_ccw());
+            assert!(poly.interiors().all(|ring| ring.is_cw()));
+        }
+        assert_eq!(mpoly.coords_spans, coords_spans);
+        assert_eq!(mpoly.holes_spans, holes_spans);
+        assert_eq!(mpoly.get(1).exterior().raw_coords()[0], [10., 0.]);
+
+        mpoly.orient(Orientation::Clockwise);
+        assert!(mpoly.misoriented_rings(Orientation::CounterClockwise).len() == 4);
+
+        let mut empty = MultiPolygon2::<f64>::new();
+        empty.orient(Orientation::Clockwise);
+        assert!(empty.is_oriented(Orientation::Clockwise));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        mpoly.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        mpoly.add_exterior([[4., 0.], [7., 0.], [7., 3.], [4., 3.]]);
+        mpoly.add_exterior([[8., 0.], [9., 0.], [9., 1.]]);
+        mpoly.add_interior([[8.1, 0.1], [8.9, 0.1], [8.9, 0.8]]);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&mpoly).unwrap();
+        let archived =
+            rkyv::access::<ArchivedMultiPolygon<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        assert!(!archived.is_empty());
+        assert_eq!(archived.iter().count(), 3);
+        for (poly, archived_poly) in mpoly.iter().zip(archived) {
+            assert_eq!(poly.hole_indices(), archived_poly.hole_indices());
+            assert_eq!(poly.exterior().raw_coords(), archived_poly.exterior());
+            for (ring, archived_ring) in poly.rings().zip(archived_poly.rings()) {
+                assert_eq!(ring.raw_coords(), archived_ring);
+            }
+        }
+        assert_eq!(archived.get(1).len(), 1);
+        assert_eq!(archived.get(2).interiors().count(), 1);
+
+        let deserialized: MultiPolygon2 =
+            rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(deserialized, mpoly);
+
+        // empty
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&MultiPolygon2::<f64>::new()).unwrap();
+        let archived =
+            rkyv::access::<ArchivedMultiPolygon<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert!(archived.is_empty());
+        assert_eq!(archived.iter().count(), 0);
+
+        // invalid spans are rejected when accessed
+        let invalid = MultiPolygon2::<f64>::from_raw_unchecked(
+            [[0., 0.], [1., 0.], [1., 1.]][..].into(),
+            [5][..].into(),
+            [0][..].into(),
+            [][..].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&invalid).unwrap();
+        assert!(
+            rkyv::access::<ArchivedMultiPolygon<[f64; 2]>, rkyv::rancor::Error>(&bytes).is_err()
+        );
+    }
 }
@@ -0,0 +1,614 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "rkyv")]
+use super::multi_polygon::ArchivedMultiPolygonRef;
+use super::{
+    multi_polygon::{check_raw as check_multi_polygon, MultiPolygon},
+    Coord,
+};
+use crate::error::{Error, Result, SpanKind};
+
+/// Columnar collection of MultiPolygons
+///
+/// The buffers of all multipolygons are concatenated into the same four buffers as
+/// [`MultiPolygon`], so a whole feature collection is stored in a constant number of
+/// allocations, and each multipolygon can be borrowed from it without copying.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawMultiPolygonBatch<'a, T>",
+        bound(deserialize = "T: serde::Deserialize<'de>")
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultiPolygonBatch<'a, T: Coord> {
+    /// All coordinates of all multipolygons
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    all_coords: Cow<'a, [T]>,
+
+    /// coords_spans (See `MultiPolygon`) of all multipolygons
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    all_coords_spans: Cow<'a, [u32]>,
+
+    /// all_hole_indices (See `MultiPolygon`) of all multipolygons
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    all_hole_indices: Cow<'a, [u32]>,
+
+    /// holes_spans (See `MultiPolygon`) of all multipolygons
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    all_holes_spans: Cow<'a, [u32]>,
+
+    /// The end positions of each multipolygon in the four buffers above
+    /// (each multipolygon starts where the previous one ends)
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    ends: Cow<'a, [[u32; 4]]>,
+}
+
+pub type MultiPolygonBatch2<'a, C = f64> = MultiPolygonBatch<'a, [C; 2]>;
+pub type MultiPolygonBatch3<'a, C = f64> = MultiPolygonBatch<'a, [C; 3]>;
+
+impl<'a, T: Coord> MultiPolygonBatch<'a, T> {
+    /// Creates an empty MultiPolygonBatch.
+    pub fn new() -> Self {
+        Self {
+            all_coords: Cow::Borrowed(&[]),
+            all_coords_spans: Cow::Borrowed(&[]),
+            all_hole_indices: Cow::Borrowed(&[]),
+            all_holes_spans: Cow::Borrowed(&[]),
+            ends: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Create a new batch from the given raw data, panicking if the spans are invalid.
+    ///
+    /// See [`MultiPolygonBatch::try_from_raw`] for a non-panicking version.
+    pub fn from_raw(
+        all_coords: Cow<'a, [T]>,
+        all_coords_spans: Cow<'a, [u32]>,
+        all_hole_indices: Cow<'a, [u32]>,
+        all_holes_spans: Cow<'a, [u32]>,
+        ends: Cow<'a, [[u32; 4]]>,
+    ) -> Self {
+        Self::try_from_raw(
+            all_coords,
+            all_coords_spans,
+            all_hole_indices,
+            all_holes_spans,
+            ends,
+        )
+        .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Create a new batch from the given raw data, checking for validity.
+    pub fn try_from_raw(
+        all_coords: Cow<'a, [T]>,
+        all_coords_spans: Cow<'a, [u32]>,
+        all_hole_indices: Cow<'a, [u32]>,
+        all_holes_spans: Cow<'a, [u32]>,
+        ends: Cow<'a, [[u32; 4]]>,
+    ) -> Result<Self> {
+        check_raw(
+            all_coords.len(),
+            &all_coords_spans,
+            &all_hole_indices,
+            &all_holes_spans,
+            &ends,
+        )?;
+        Ok(Self {
+            all_coords,
+            all_coords_spans,
+            all_hole_indices,
+            all_holes_spans,
+            ends,
+        })
+    }
+
+    /// Creates a new batch from the given raw data, without validity check.
+    pub fn from_raw_unchecked(
+        all_coords: Cow<'a, [T]>,
+        all_coords_spans: Cow<'a, [u32]>,
+        all_hole_indices: Cow<'a, [u32]>,
+        all_holes_spans: Cow<'a, [u32]>,
+        ends: Cow<'a, [[u32; 4]]>,
+    ) -> Self {
+        Self {
+            all_coords,
+            all_coords_spans,
+            all_hole_indices,
+            all_holes_spans,
+            ends,
+        }
+    }
+
+    /// All coordinates of all multipolygons
+    pub fn raw_coords(&self) -> &[T] {
+        self.all_coords.as_ref()
+    }
+
+    /// Returns the number of multipolygons in the batch.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the batch contains no multipolygons.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the multipolygon at the given index, borrowing from the batch.
+    pub fn get(&self, index: usize) -> MultiPolygon<'_, T> {
+        let len = self.len();
+        if index >= len {
+            panic!(
+                "index out of bounds: {} multipolygon(s) but index is {}",
+                len, index
+            );
+        }
+        let [c_start, cs_start, h_start, hs_start] = match index {
+            0 => [0; 4],
+            _ => self.ends[index - 1].map(|i| i as usize),
+        };
+        let [c_end, cs_end, h_end, hs_end] = self.ends[index].map(|i| i as usize);
+        MultiPolygon::from_raw_unchecked(
+            (&self.all_coords[c_start..c_end]).into(),
+            (&self.all_coords_spans[cs_start..cs_end]).into(),
+            (&self.all_holes_spans[hs_start..hs_end]).into(),
+            (&self.all_hole_indices[h_start..h_end]).into(),
+        )
+    }
+
+    /// Returns an iterator over the multipolygons
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            batch: self,
+            pos: 0,
+        }
+    }
+
+    /// Removes all multipolygons from the batch.
+    pub fn clear(&mut self) {
+        self.all_coords.to_mut().clear();
+        self.all_coords_spans.to_mut().clear();
+        self.all_hole_indices.to_mut().clear();
+        self.all_holes_spans.to_mut().clear();
+        self.ends.to_mut().clear();
+    }
+
+    /// Appends a copy of the multipolygon to the batch.
+    pub fn push(&mut self, mpoly: &MultiPolygon<T>) {
+        self.all_coords
+            .to_mut()
+            .extend_from_slice(mpoly.raw_coords());
+        self.all_coords_spans
+            .to_mut()
+            .extend_from_slice(mpoly.coords_spans());
+        self.all_hole_indices
+            .to_mut()
+            .extend_from_slice(mpoly.all_hole_indices());
+        self.all_holes_spans
+            .to_mut()
+            .extend_from_slice(mpoly.holes_spans());
+        let end = [
+            self.all_coords.len() as u32,
+            self.all_coords_spans.len() as u32,
+            self.all_hole_indices.len() as u32,
+            self.all_holes_spans.len() as u32,
+        ];
+        self.ends.to_mut().push(end);
+    }
+
+    /// Applies the given transformation to all coordinates in the batch.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.all_coords.to_mut().iter_mut().for_each(|c| {
+            *c = f(c);
+        });
+    }
+}
+
+impl<'b, T: Coord> Extend<MultiPolygon<'b, T>> for MultiPolygonBatch<'_, T> {
+    fn extend<I: IntoIterator<Item = MultiPolygon<'b, T>>>(&mut self, iter: I) {
+        for mpoly in iter {
+            self.push(&mpoly);
+        }
+    }
+}
+
+impl<'b, T: Coord> FromIterator<MultiPolygon<'b, T>> for MultiPolygonBatch<'_, T> {
+    fn from_iter<I: IntoIterator<Item = MultiPolygon<'b, T>>>(iter: I) -> Self {
+        let mut batch = Self::new();
+        batch.extend(iter);
+        batch
+    }
+}
+
+impl<'a, T: Coord> IntoIterator for &'a MultiPolygonBatch<'_, T> {
+    type Item = MultiPolygon<'a, T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, T: Coord> {
+    batch: &'a MultiPolygonBatch<'a, T>,
+    pos: usize,
+}
+
+impl<'a, T: Coord> Iterator for Iter<'a, T> {
+    type Item = MultiPolygon<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.batch.len() {
+            let mpoly = self.batch.get(self.pos);
+            self.pos += 1;
+            Some(mpoly)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks the raw buffers of a MultiPolygonBatch with `coords_len` coordinates.
+fn check_raw<S: Copy + Into<u32>>(
+    coords_len: usize,
+    all_coords_spans: &[S],
+    all_hole_indices: &[S],
+    all_holes_spans: &[S],
+    ends: &[[S; 4]],
+) -> Result<()> {
+    let lens = [
+        coords_len,
+        all_coords_spans.len(),
+        all_hole_indices.len(),
+        all_holes_spans.len(),
+    ];
+    let mut start = [0; 4];
+    for (i, end) in ends.iter().enumerate() {
+        let end = end.map(|e| e.into() as usize);
+        for k in 0..4 {
+            if end[k] > lens[k] {
+                return Err(Error::SpanOutOfRange {
+                    kind: SpanKind::Ends,
+                    index: i,
+                    value: end[k] as u32,
+                    len: lens[k],
+                });
+            }
+            if start[k] > end[k] {
+                return Err(Error::NonMonotonicSpans {
+                    kind: SpanKind::Ends,
+                    index: i,
+                });
+            }
+        }
+        let [c_start, cs_start, h_start, hs_start] = start;
+        let [c_end, cs_end, h_end, hs_end] = end;
+        check_multi_polygon(
+            c_end - c_start,
+            &all_coords_spans[cs_start..cs_end],
+            &all_hole_indices[h_start..h_end],
+            &all_holes_spans[hs_start..hs_end],
+        )?;
+        start = end;
+    }
+    if start != lens {
+        return Err(Error::BatchLengthMismatch { ends: start, lens });
+    }
+    Ok(())
+}
+
+/// Unchecked counterpart of [`MultiPolygonBatch`] for deserialization
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawMultiPolygonBatch<'a, T: Coord> {
+    all_coords: Cow<'a, [T]>,
+    all_coords_spans: Cow<'a, [u32]>,
+    all_hole_indices: Cow<'a, [u32]>,
+    all_holes_spans: Cow<'a, [u32]>,
+    ends: Cow<'a, [[u32; 4]]>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Coord> TryFrom<RawMultiPolygonBatch<'a, T>> for MultiPolygonBatch<'a, T> {
+    type Error = Error;
+
+    fn try_from(raw: RawMultiPolygonBatch<'a, T>) -> Result<Self> {
+        Self::try_from_raw(
+            raw.all_coords,
+            raw.all_coords_spans,
+            raw.all_hole_indices,
+            raw.all_holes_spans,
+            raw.ends,
+        )
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, T: Coord + rkyv::Archive> ArchivedMultiPolygonBatch<'a, T> {
+    /// All coordinates of all multipolygons
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.all_coords
+    }
+
+    /// Returns the number of multipolygons in the batch.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the batch contains no multipolygons.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the multipolygon at the given index, borrowing from the batch.
+    pub fn get(&self, index: usize) -> ArchivedMultiPolygonRef<'_, T::Archived> {
+        let len = self.len();
+        if index >= len {
+            panic!(
+                "index out of bounds: {} multipolygon(s) but index is {}",
+                len, index
+            );
+        }
+        let [c_start, cs_start, h_start, hs_start] = match index {
+            0 => [0; 4],
+            _ => self.ends[index - 1].map(|i| i.to_native() as usize),
+        };
+        let [c_end, cs_end, h_end, hs_end] = self.ends[index].map(|i| i.to_native() as usize);
+        ArchivedMultiPolygonRef::new(
+            &self.all_coords[c_start..c_end],
+            &self.all_coords_spans[cs_start..cs_end],
+            &self.all_hole_indices[h_start..h_end],
+            &self.all_holes_spans[hs_start..hs_end],
+        )
+    }
+
+    /// Returns an iterator over the multipolygons
+    pub fn iter(&self) -> ArchivedIter<'_, 'a, T> {
+        ArchivedIter {
+            batch: self,
+            pos: 0,
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+// SAFETY: `verify` returns `Ok` only if the ends and spans are valid for the archived buffers.
+unsafe impl<C, T> rkyv::bytecheck::Verify<C> for ArchivedMultiPolygonBatch<'_, T>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+    T: Coord + rkyv::Archive,
+{
+    fn verify(&self, _context: &mut C) -> core::result::Result<(), C::Error> {
+        crate::error::verify::<C>(check_raw(
+            self.all_coords.len(),
+            &self.all_coords_spans,
+            &self.all_hole_indices,
+            &self.all_holes_spans,
+            &self.ends,
+        ))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, 'b, T: Coord + rkyv::Archive> IntoIterator for &'a ArchivedMultiPolygonBatch<'b, T> {
+    type Item = ArchivedMultiPolygonRef<'a, T::Archived>;
+    type IntoIter = ArchivedIter<'a, 'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub struct ArchivedIter<'a, 'b, T: Coord + rkyv::Archive> {
+    batch: &'a ArchivedMultiPolygonBatch<'b, T>,
+    pos: usize,
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, T: Coord + rkyv::Archive> Iterator for ArchivedIter<'a, '_, T> {
+    type Item = ArchivedMultiPolygonRef<'a, T::Archived>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.batch.len() {
+            let mpoly = self.batch.get(self.pos);
+            self.pos += 1;
+            Some(mpoly)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MultiPolygon2;
+    use alloc::vec::Vec;
+
+    fn mpolys() -> Vec<MultiPolygon2<'static>> {
+        let mut a = MultiPolygon2::new();
+        a.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        a.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        a.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        let mut b = MultiPolygon2::new();
+        b.add_exterior([[10., 10.], [11., 10.], [11., 11.]]);
+        b.add_exterior([[12., 10.], [13., 10.], [13., 11.]]);
+        b.add_interior([[12.1, 10.1], [12.9, 10.1], [12.9, 10.9]]);
+        b.add_interior([[12.2, 10.2], [12.8, 10.2], [12.8, 10.8]]);
+        alloc::vec![a, MultiPolygon2::new(), b]
+    }
+
+    #[test]
+    fn test_batch_push_and_get() {
+        let mpolys = mpolys();
+        let mut batch = MultiPolygonBatch2::new();
+        assert!(batch.is_empty());
+        for mpoly in &mpolys {
+            batch.push(mpoly);
+        }
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.raw_coords().len(), 23);
+        for (i, mpoly) in mpolys.iter().enumerate() {
+            assert_eq!(&batch.get(i), mpoly);
+        }
+        assert!(batch.get(1).is_empty());
+        assert_eq!(batch.get(2).get(1).interiors().count(), 2);
+
+        // the multipolygons are borrowed from the batch
+        let mpoly = batch.get(2);
+        assert_eq!(
+            mpoly.raw_coords().as_ptr(),
+            batch.raw_coords()[11..].as_ptr()
+        );
+
+        batch.clear();
+        assert!(batch.is_empty());
+        assert!(batch.raw_coords().is_empty());
+    }
+
+    #[test]
+    fn test_batch_iter() {
+        let mpolys = mpolys();
+        let batch: MultiPolygonBatch2 = mpolys.iter().cloned().collect();
+        assert_eq!(batch.iter().collect::<Vec<_>>(), mpolys);
+
+        let mut batch2 = batch.clone();
+        batch2.extend(batch.iter());
+        assert_eq!(batch2.len(), 6);
+        assert_eq!((&batch2).into_iter().nth(3).unwrap(), mpolys[0]);
+    }
+
+    #[test]
+    fn test_batch_transform_inplace() {
+        let mut batch: MultiPolygonBatch2 = mpolys().into_iter().collect();
+        batch.transform_inplace(|[x, y]| [x + 1., y * 2.]);
+        assert_eq!(batch.get(2).get(0).exterior().raw_coords()[0], [11., 20.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_batch_get_out_of_bounds() {
+        let batch: MultiPolygonBatch2 = mpolys().into_iter().collect();
+        batch.get(3);
+    }
+
+    #[test]
+    fn test_batch_try_from_raw() {
+        let batch: MultiPolygonBatch2 = mpolys().into_iter().collect();
+        let raw = |ends: &[[u32; 4]]| {
+            MultiPolygonBatch2::try_from_raw(
+                batch.all_coords.clone(),
+                batch.all_coords_spans.clone(),
+                batch.all_hole_indices.clone(),
+                batch.all_holes_spans.clone(),
+                ends.to_vec().into(),
+            )
+        };
+        assert_eq!(raw(&batch.ends).unwrap(), batch);
+        assert_eq!(
+            raw(&[[11, 1, 1, 1], [5, 1, 1, 1], [23, 2, 3, 2]]),
+            Err(Error::NonMonotonicSpans {
+                kind: SpanKind::Ends,
+                index: 1
+            })
+        );
+        assert_eq!(
+            raw(&[[11, 1, 1, 1], [24, 2, 3, 2]]),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::Ends,
+                index: 1,
+                value: 24,
+                len: 23
+            })
+        );
+        assert_eq!(
+            raw(&[[11, 1, 1, 1]]),
+            Err(Error::BatchLengthMismatch {
+                ends: [11, 1, 1, 1],
+                lens: [23, 2, 3, 2]
+            })
+        );
+        // the hole of the first multipolygon is moved to the second one
+        assert!(matches!(
+            raw(&[[11, 1, 0, 1], [23, 2, 3, 2]]),
+            Err(Error::SpanOutOfRange { .. })
+        ));
+
+        assert_eq!(
+            MultiPolygonBatch2::try_from_raw(
+                [[0., 0.]][..].into(),
+                [][..].into(),
+                [][..].into(),
+                [][..].into(),
+                [][..].into(),
+            ),
+            Err(Error::BatchLengthMismatch {
+                ends: [0; 4],
+                lens: [1, 0, 0, 0]
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid ends")]
+    fn test_batch_from_raw_panics() {
+        MultiPolygonBatch2::from_raw(
+            [[0., 0.]][..].into(),
+            [][..].into(),
+            [][..].into(),
+            [][..].into(),
+            [[2, 0, 0, 0]][..].into(),
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let mpolys = mpolys();
+        let batch: MultiPolygonBatch2 = mpolys.iter().cloned().collect();
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&batch).unwrap();
+        let archived =
+            rkyv::access::<ArchivedMultiPolygonBatch<[f64; 2]>, rkyv::rancor::Error>(&bytes)
+                .unwrap();
+        assert_eq!(archived.len(), 3);
+        assert!(!archived.is_empty());
+        assert_eq!(archived.raw_coords().len(), 23);
+        assert!(archived.get(1).is_empty());
+        for (mpoly, archived_mpoly) in mpolys.iter().zip(archived) {
+            assert_eq!(mpoly.len(), archived_mpoly.len());
+            assert_eq!(mpoly.raw_coords(), archived_mpoly.raw_coords());
+            for (poly, archived_poly) in mpoly.iter().zip(archived_mpoly) {
+                assert_eq!(poly.exterior().raw_coords(), archived_poly.exterior());
+                assert_eq!(poly.hole_indices(), archived_poly.hole_indices());
+            }
+        }
+        assert_eq!(archived.get(2).get(1).interiors().count(), 2);
+
+        let deserialized: MultiPolygonBatch2 =
+            rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(deserialized, batch);
+
+        // invalid ends are rejected when accessed
+        let invalid = MultiPolygonBatch2::<f64>::from_raw_unchecked(
+            batch.all_coords.clone(),
+            batch.all_coords_spans.clone(),
+            batch.all_hole_indices.clone(),
+            batch.all_holes_spans.clone(),
+            [[11, 1, 1, 1], [30, 2, 3, 2]][..].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&invalid).unwrap();
+        assert!(
+            rkyv::access::<ArchivedMultiPolygonBatch<[f64; 2]>, rkyv::rancor::Error>(&bytes)
+                .is_err()
+        );
+    }
+}
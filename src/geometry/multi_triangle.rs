@@ -0,0 +1,321 @@
+use alloc::borrow::Cow;
+use core::ops::Range;
+
+use super::{triangle::Triangle, Coord};
+use crate::error::{Error, Result};
+
+/// Computer-friendly MultiTriangle (a.k.a. TIN, Triangulated Irregular Network)
+///
+/// Vertices are stored once in a shared buffer and each triangle refers to them by index.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MultiTriangle<'a, T: Coord> {
+    /// Coordinates of all vertices
+    ///
+    /// e.g. `[x0, y0, z0, x1, y1, z1, ...]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    coords: Cow<'a, [T]>,
+
+    /// Vertex indices of all triangles, three per triangle
+    ///
+    /// e.g. `[0, 1, 2, 2, 1, 3]`
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+    indices: Cow<'a, [u32]>,
+}
+
+pub type MultiTriangle2<'a, C = f64> = MultiTriangle<'a, [C; 2]>;
+pub type MultiTriangle3<'a, C = f64> = MultiTriangle<'a, [C; 3]>;
+
+impl<'a, T: Coord> MultiTriangle<'a, T> {
+    /// Creates an empty MultiTriangle.
+    pub fn new() -> Self {
+        Self {
+            coords: Cow::Borrowed(&[]),
+            indices: Cow::Borrowed(&[]),
+        }
+    }
+
+    /// Creates a new MultiTriangle from the given raw data, panicking if `indices` is invalid.
+    ///
+    /// See [`MultiTriangle::try_from_raw`] for a non-panicking version.
+    pub fn from_raw(coords: Cow<'a, [T]>, indices: Cow<'a, [u32]>) -> Self {
+        Self::try_from_raw(coords, indices).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new MultiTriangle from the given raw data, checking for validity.
+    pub fn try_from_raw(coords: Cow<'a, [T]>, indices: Cow<'a, [u32]>) -> Result<Self> {
+        check_indices(&indices, coords.len())?;
+        Ok(Self { coords, indices })
+    }
+
+    /// Creates a new MultiTriangle from the given raw data, without validity check.
+    pub fn from_raw_unchecked(coords: Cow<'a, [T]>, indices: Cow<'a, [u32]>) -> Self {
+        Self { coords, indices }
+    }
+
+    pub fn raw_coords(&self) -> &[T] {
+        self.coords.as_ref()
+    }
+
+    /// Vertex indices of all triangles, three per triangle
+    pub fn raw_indices(&self) -> &[u32] {
+        self.indices.as_ref()
+    }
+
+    /// Returns the number of triangles.
+    pub fn len(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Returns `true` if the MultiTriangle contains no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the triangle at the given index.
+    pub fn get(&self, index: usize) -> Option<Triangle<T>> {
+        let idx = self.indices.get(index * 3..index * 3 + 3)?;
+        Some(Triangle::new(
+            self.coords[idx[0] as usize].clone(),
+            self.coords[idx[1] as usize].clone(),
+            self.coords[idx[2] as usize].clone(),
+        ))
+    }
+
+    /// Returns an iterator over the triangles.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            mtri: self,
+            pos: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns an iterator over the triangles in the given range.
+    pub fn iter_range(&self, range: Range<usize>) -> Iter<'_, T> {
+        Iter {
+            mtri: self,
+            pos: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Removes all triangles and vertices.
+    pub fn clear(&mut self) {
+        self.coords.to_mut().clear();
+        self.indices.to_mut().clear();
+    }
+
+    /// Appends a vertex and returns its index.
+    pub fn push_vertex(&mut self, coord: T) -> u32 {
+        self.coords.to_mut().push(coord);
+        (self.coords.len() - 1) as u32
+    }
+
+    /// Appends a triangle referring to existing vertices.
+    ///
+    /// The indices are not checked against the number of vertices.
+    pub fn push_indices(&mut self, indices: [u32; 3]) {
+        self.indices.to_mut().extend(indices);
+    }
+
+    /// Appends a triangle with its own three vertices.
+    pub fn push(&mut self, triangle: &Triangle<T>) {
+        let head = self.coords.len() as u32;
+        self.coords.to_mut().extend(triangle.iter());
+        self.push_indices([head, head + 1, head + 2]);
+    }
+
+    /// Create a new MultiTriangle by applying the given transformation to all coordinates.
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> MultiTriangle<'_, T2> {
+        MultiTriangle {
+            coords: self.coords.iter().map(f).collect(),
+            indices: self.indices.clone(),
+        }
+    }
+
+    /// Applies the given transformation to all coordinates in the MultiTriangle.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.coords.to_mut().iter_mut().for_each(|c| {
+            *c = f(c);
+        });
+    }
+}
+
+impl<'a, T: Coord> IntoIterator for &'a MultiTriangle<'_, T> {
+    type Item = Triangle<T>;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, T: Coord> {
+    mtri: &'a MultiTriangle<'a, T>,
+    pos: usize,
+    end: usize,
+}
+
+impl<T: Coord> Iterator for Iter<'_, T> {
+    type Item = Triangle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.end {
+            let tri = self.mtri.get(self.pos);
+            self.pos += 1;
+            tri
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedMultiTriangle<'_, T> {
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+
+    pub fn raw_indices(&self) -> &[rkyv::Archived<u32>] {
+        &self.indices
+    }
+
+    /// Returns the number of triangles.
+    pub fn len(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Returns `true` if the MultiTriangle contains no triangles.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+/// Checks the triangle indices into `len` coordinates.
+fn check_indices<S: Copy + Into<u32>>(indices: &[S], len: usize) -> Result<()> {
+    if !indices.len().is_multiple_of(3) {
+        return Err(Error::IncompleteTriangle { len: indices.len() });
+    }
+    for (index, value) in indices.iter().enumerate() {
+        let value: u32 = (*value).into();
+        if value as usize >= len {
+            return Err(Error::TriangleIndexOutOfRange { index, value, len });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rkyv")]
+// SAFETY: `verify` returns `Ok` only if the spans are valid for the archived buffers.
+unsafe impl<C, T> rkyv::bytecheck::Verify<C> for ArchivedMultiTriangle<'_, T>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+    T: Coord + rkyv::Archive,
+{
+    fn verify(&self, _context: &mut C) -> core::result::Result<(), C::Error> {
+        crate::error::verify::<C>(check_indices(&self.indices, self.coords.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Triangle2;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_mtri_basic() {
+        let mut mtri = MultiTriangle2::new();
+        assert!(mtri.is_empty());
+        assert_eq!(mtri.len(), 0);
+        assert_eq!(mtri.iter().count(), 0);
+
+        let a = mtri.push_vertex([0., 0.]);
+        let b = mtri.push_vertex([1., 0.]);
+        let c = mtri.push_vertex([0., 1.]);
+        let d = mtri.push_vertex([1., 1.]);
+        mtri.push_indices([a, b, c]);
+        mtri.push_indices([c, b, d]);
+        assert_eq!(mtri.len(), 2);
+        assert_eq!(mtri.raw_coords().len(), 4);
+
+        mtri.push(&Triangle2::new([5., 5.], [6., 5.], [5., 6.]));
+        assert_eq!(mtri.len(), 3);
+        assert_eq!(mtri.raw_indices(), [0, 1, 2, 2, 1, 3, 4, 5, 6]);
+
+        assert_eq!(
+            mtri.get(1),
+            Some(Triangle2::new([0., 1.], [1., 0.], [1., 1.]))
+        );
+        assert_eq!(mtri.get(3), None);
+        assert_eq!(
+            mtri.iter_range(2..3).collect::<Vec<_>>(),
+            [Triangle2::new([5., 5.], [6., 5.], [5., 6.])]
+        );
+
+        mtri.clear();
+        assert!(mtri.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_raw() {
+        let coords = [[0., 0.], [1., 0.], [0., 1.], [1., 1.]];
+        let mtri =
+            MultiTriangle2::try_from_raw(coords[..].into(), [0, 1, 2, 2, 1, 3][..].into()).unwrap();
+        assert_eq!(mtri.len(), 2);
+
+        assert_eq!(
+            MultiTriangle2::try_from_raw(coords[..].into(), [0, 1, 2, 2][..].into()),
+            Err(Error::IncompleteTriangle { len: 4 })
+        );
+        assert_eq!(
+            MultiTriangle2::try_from_raw(coords[..].into(), [0, 1, 4][..].into()),
+            Err(Error::TriangleIndexOutOfRange {
+                index: 2,
+                value: 4,
+                len: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut mtri = MultiTriangle2::new();
+        mtri.push(&Triangle2::new([0., 0.], [1., 0.], [0., 1.]));
+        let new_mtri = mtri.transform(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(new_mtri.raw_coords(), [[2., 1.], [3., 1.], [2., 2.]]);
+
+        mtri.transform_inplace(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(mtri.raw_coords(), [[2., 1.], [3., 1.], [2., 2.]]);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let mtri = MultiTriangle2::from_raw(
+            alloc::vec![[0., 0.], [1., 0.], [0., 1.]].into(),
+            alloc::vec![0, 1, 2].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&mtri).unwrap();
+        let archived =
+            rkyv::access::<ArchivedMultiTriangle<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.raw_indices(), [0, 1, 2]);
+
+        // invalid indices are rejected when accessed
+        let invalid = MultiTriangle2::<f64>::from_raw_unchecked(
+            alloc::vec![[0., 0.], [1., 0.], [0., 1.]].into(),
+            alloc::vec![0, 1, 3].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&invalid).unwrap();
+        assert!(
+            rkyv::access::<ArchivedMultiTriangle<[f64; 2]>, rkyv::rancor::Error>(&bytes).is_err()
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Coord;
+
+/// Computer-friendly Point
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point<T: Coord> {
+    /// Coordinate of the point
+    ///
+    /// e.g. `[x, y, z]`
+    coord: T,
+}
+
+pub type Point2<C = f64> = Point<[C; 2]>;
+pub type Point3<C = f64> = Point<[C; 3]>;
+
+impl<T: Coord> Point<T> {
+    /// Creates a Point from the given coordinate.
+    pub fn new(coord: T) -> Self {
+        Self { coord }
+    }
+
+    /// Returns the coordinate of the Point.
+    pub fn coord(&self) -> &T {
+        &self.coord
+    }
+
+    /// Consumes the Point and returns its coordinate.
+    pub fn into_coord(self) -> T {
+        self.coord
+    }
+
+    /// Create a new Point by applying the given transformation to the coordinate.
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> Point<T2> {
+        Point {
+            coord: f(&self.coord),
+        }
+    }
+
+    /// Applies the given transformation to the coordinate of the Point.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.coord = f(&self.coord);
+    }
+}
+
+impl<T: Coord> From<T> for Point<T> {
+    fn from(coord: T) -> Self {
+        Self::new(coord)
+    }
+}
+
+impl<T: Coord> AsRef<T> for Point<T> {
+    fn as_ref(&self) -> &T {
+        &self.coord
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedPoint<T> {
+    pub fn coord(&self) -> &T::Archived {
+        &self.coord
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_basic() {
+        let point = Point2::new([1.0, 2.0]);
+        assert_eq!(point.coord(), &[1.0, 2.0]);
+        assert_eq!(point.as_ref(), &[1.0, 2.0]);
+        assert_eq!(point.into_coord(), [1.0, 2.0]);
+
+        let point: Point3<u32> = [1, 2, 3].into();
+        assert_eq!(point.coord(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut point = Point2::new([1.0, 2.0]);
+        let new_point = point.transform(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(new_point.coord(), &[3., 3.]);
+
+        point.transform_inplace(|[x, y]| [x * 2., y * 2.]);
+        assert_eq!(point.coord(), &[2., 4.]);
+    }
+}
@@ -1,22 +1,100 @@
+use crate::error::{check_spans, Result, SpanKind};
 use crate::Coord2d;
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::ops::Range;
 
 use super::{linestring::LineString, Coord};
 
 /// Computer-friendly Polygon
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Polygon<'a, T: Coord> {
     /// Coordinates
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     coords: Cow<'a, [T]>,
 
     /// A sequence of indices from which each hole starts
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
     hole_indices: Cow<'a, [u32]>,
 }
 
 pub type Polygon3<'a, C = f64> = Polygon<'a, [C; 3]>;
 pub type Polygon2<'a, C = f64> = Polygon<'a, [C; 2]>;
 
+/// Winding order convention of polygon rings
+///
+/// The variant names the winding order of exterior rings, and interior rings are wound
+/// the opposite way. Windings are evaluated with the y axis pointing up, so in y-down
+/// spaces such as MVT tile coordinates the visual winding is inverted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Exterior rings counter-clockwise, interior rings clockwise (e.g. GeoJSON)
+    CounterClockwise,
+    /// Exterior rings clockwise, interior rings counter-clockwise (e.g. Shapefile)
+    Clockwise,
+}
+
+impl Orientation {
+    /// Returns `true` if a ring with the given signed area follows this convention.
+    ///
+    /// Rings without area have no winding order and are always considered oriented.
+    fn matches(self, signed_area: f64, exterior: bool) -> bool {
+        let ccw = match (self, exterior) {
+            (Orientation::CounterClockwise, true) | (Orientation::Clockwise, false) => true,
+            (Orientation::Clockwise, true) | (Orientation::CounterClockwise, false) => false,
+        };
+        signed_area == 0.0 || (signed_area > 0.0) == ccw
+    }
+}
+
+/// Returns the coordinate ranges of each ring described by `hole_indices`.
+pub(crate) fn ring_ranges(
+    len: usize,
+    hole_indices: &[u32],
+) -> impl Iterator<Item = Range<usize>> + '_ {
+    let starts = core::iter::once(0).chain(hole_indices.iter().map(|&i| i as usize));
+    let ends = hole_indices
+        .iter()
+        .map(|&i| i as usize)
+        .chain(core::iter::once(len));
+    starts.zip(ends).map(|(start, end)| start..end)
+}
+
+/// Returns the indices of the rings that violate the orientation.
+pub(crate) fn misoriented_rings<'a, T: Coord2d>(
+    coords: &'a [T],
+    hole_indices: &'a [u32],
+    orientation: Orientation,
+) -> impl Iterator<Item = usize> + 'a {
+    ring_ranges(coords.len(), hole_indices)
+        .enumerate()
+        .filter(move |(i, range)| {
+            let area = LineString::from_raw(coords[range.clone()].into()).signed_ring_area();
+            !orientation.matches(area, *i == 0)
+        })
+        .map(|(i, _)| i)
+}
+
+/// Reverses the rings that violate the orientation, preserving their first coordinates.
+pub(crate) fn orient_rings<T: Coord2d>(
+    coords: &mut [T],
+    hole_indices: &[u32],
+    orientation: Orientation,
+) {
+    for (i, range) in ring_ranges(coords.len(), hole_indices).enumerate() {
+        let area = LineString::from_raw(coords[range.clone()].into()).signed_ring_area();
+        if !orientation.matches(area, i == 0) {
+            coords[range.start + 1..range.end].reverse();
+        }
+    }
+}
+
 impl<'a, T: Coord> Polygon<'a, T> {
     /// Creates an empty Polygon.
     pub fn new() -> Self {
@@ -26,18 +104,21 @@ impl<'a, T: Coord> Polygon<'a, T> {
         }
     }
 
+    /// Creates a new Polygon from the given raw data, panicking if `hole_indices` is invalid.
+    ///
+    /// See [`Polygon::try_from_raw`] for a non-panicking version.
     pub fn from_raw(coords: Cow<'a, [T]>, hole_indices: Cow<'a, [u32]>) -> Self {
+        Self::try_from_raw(coords, hole_indices).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new Polygon from the given raw data, checking for validity.
+    pub fn try_from_raw(coords: Cow<'a, [T]>, hole_indices: Cow<'a, [u32]>) -> Result<Self> {
         // Check if all span values are within range and monotonically increasing
-        if let Some(&last) = hole_indices.last() {
-            let len = (coords.len()) as u32;
-            if last >= len || hole_indices.windows(2).any(|a| a[0] >= a[1]) {
-                panic!("invalid hole_indices")
-            }
-        }
-        Self {
+        check_spans(SpanKind::HoleIndices, &hole_indices, 0, coords.len())?;
+        Ok(Self {
             coords,
             hole_indices,
-        }
+        })
     }
 
     pub fn from_raw_unchecked(coords: Cow<'a, [T]>, hole_indices: Cow<'a, [u32]>) -> Self {
@@ -66,7 +147,7 @@ impl<'a, T: Coord> Polygon<'a, T> {
     }
 
     /// Returns the exterior ring of the polygon.
-    pub fn exterior(&self) -> LineString<T> {
+    pub fn exterior(&self) -> LineString<'_, T> {
         LineString::from_raw(if self.hole_indices.is_empty() {
             self.coords[..].into()
         } else {
@@ -75,12 +156,12 @@ impl<'a, T: Coord> Polygon<'a, T> {
     }
 
     /// Returns an iterator over the interior rings of the polygon.
-    pub fn interiors(&self) -> Iter<T> {
+    pub fn interiors(&self) -> Iter<'_, T> {
         Iter { poly: self, pos: 1 }
     }
 
     /// Returns an iterator over the exterior and interior rings of the polygon.
-    pub fn rings(&self) -> Iter<T> {
+    pub fn rings(&self) -> Iter<'_, T> {
         Iter { poly: self, pos: 0 }
     }
 
@@ -106,7 +187,7 @@ impl<'a, T: Coord> Polygon<'a, T> {
     }
 
     /// Create a new Polygon by applying the given transformation to all coordinates.
-    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> Polygon<T2> {
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> Polygon<'_, T2> {
         Polygon {
             coords: self.coords.iter().map(f).collect(),
             hole_indices: self.hole_indices.clone(),
@@ -131,6 +212,29 @@ impl<'a, T: Coord2d> Polygon<'a, T> {
         }
         area
     }
+
+    /// Reverses the rings that violate the orientation, in place.
+    ///
+    /// The first coordinate of each ring is preserved.
+    pub fn orient(&mut self, orientation: Orientation) {
+        if self.coords.is_empty() || self.is_oriented(orientation) {
+            return;
+        }
+        orient_rings(self.coords.to_mut(), &self.hole_indices, orientation);
+    }
+
+    /// Returns `true` if all rings follow the orientation.
+    pub fn is_oriented(&self, orientation: Orientation) -> bool {
+        self.misoriented_rings(orientation).is_empty()
+    }
+
+    /// Returns the indices of the rings (0 for the exterior) that violate the orientation.
+    pub fn misoriented_rings(&self, orientation: Orientation) -> Vec<usize> {
+        if self.coords.is_empty() {
+            return Vec::new();
+        }
+        misoriented_rings(&self.coords, &self.hole_indices, orientation).collect()
+    }
 }
 
 pub struct Iter<'a, T: Coord> {
@@ -164,6 +268,165 @@ impl<'a, T: Coord> Iterator for Iter<'a, T> {
     }
 }
 
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedPolygon<'_, T> {
+    fn as_polygon_ref(&self) -> ArchivedPolygonRef<'_, T::Archived> {
+        ArchivedPolygonRef::new(&self.coords, &self.hole_indices)
+    }
+
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+
+    /// A sequence of indices from which each hole starts
+    pub fn hole_indices(&self) -> &[rkyv::Archived<u32>] {
+        &self.hole_indices
+    }
+
+    /// Returns the number of rings (including the exterior ring)
+    pub fn len(&self) -> usize {
+        self.hole_indices.len() + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the coordinates of the exterior ring of the polygon.
+    pub fn exterior(&self) -> &[T::Archived] {
+        self.as_polygon_ref().exterior()
+    }
+
+    /// Returns an iterator over the coordinates of the interior rings of the polygon.
+    pub fn interiors(&self) -> ArchivedIter<'_, T::Archived> {
+        self.as_polygon_ref().interiors()
+    }
+
+    /// Returns an iterator over the coordinates of the exterior and interior rings of the polygon.
+    pub fn rings(&self) -> ArchivedIter<'_, T::Archived> {
+        self.as_polygon_ref().rings()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+// SAFETY: `verify` returns `Ok` only if the spans are valid for the archived buffers.
+unsafe impl<C, T> rkyv::bytecheck::Verify<C> for ArchivedPolygon<'_, T>
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+    T: Coord + rkyv::Archive,
+{
+    fn verify(&self, _context: &mut C) -> core::result::Result<(), C::Error> {
+        crate::error::verify::<C>(check_spans(
+            SpanKind::HoleIndices,
+            &self.hole_indices,
+            0,
+            self.coords.len(),
+        ))
+    }
+}
+
+/// Borrowed view of a polygon stored in an archived buffer
+///
+/// Yielded by [`ArchivedMultiPolygon::iter`](crate::ArchivedMultiPolygon::iter).
+#[cfg(feature = "rkyv")]
+#[derive(Debug)]
+pub struct ArchivedPolygonRef<'a, A> {
+    coords: &'a [A],
+    hole_indices: &'a [rkyv::Archived<u32>],
+}
+
+#[cfg(feature = "rkyv")]
+impl<A> Clone for ArchivedPolygonRef<'_, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<A> Copy for ArchivedPolygonRef<'_, A> {}
+
+#[cfg(feature = "rkyv")]
+impl<'a, A> ArchivedPolygonRef<'a, A> {
+    pub(crate) fn new(coords: &'a [A], hole_indices: &'a [rkyv::Archived<u32>]) -> Self {
+        Self {
+            coords,
+            hole_indices,
+        }
+    }
+
+    pub fn raw_coords(&self) -> &'a [A] {
+        self.coords
+    }
+
+    /// A sequence of indices from which each hole starts
+    pub fn hole_indices(&self) -> &'a [rkyv::Archived<u32>] {
+        self.hole_indices
+    }
+
+    /// Returns the number of rings (including the exterior ring)
+    pub fn len(&self) -> usize {
+        self.hole_indices.len() + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the coordinates of the exterior ring of the polygon.
+    pub fn exterior(&self) -> &'a [A] {
+        match self.hole_indices.first() {
+            Some(i) => &self.coords[..i.to_native() as usize],
+            None => self.coords,
+        }
+    }
+
+    /// Returns an iterator over the coordinates of the interior rings of the polygon.
+    pub fn interiors(&self) -> ArchivedIter<'a, A> {
+        ArchivedIter {
+            poly: *self,
+            pos: 1,
+        }
+    }
+
+    /// Returns an iterator over the coordinates of the exterior and interior rings of the polygon.
+    pub fn rings(&self) -> ArchivedIter<'a, A> {
+        ArchivedIter {
+            poly: *self,
+            pos: 0,
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub struct ArchivedIter<'a, A> {
+    poly: ArchivedPolygonRef<'a, A>,
+    pos: usize,
+}
+
+#[cfg(feature = "rkyv")]
+impl<'a, A> Iterator for ArchivedIter<'a, A> {
+    type Item = &'a [A];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let hole_indices = self.poly.hole_indices;
+        if self.pos < hole_indices.len() + 1 {
+            let start = match self.pos {
+                0 => 0,
+                pos => hole_indices[pos - 1].to_native() as usize,
+            };
+            let end = match hole_indices.get(self.pos) {
+                Some(i) => i.to_native() as usize,
+                None => self.poly.coords.len(),
+            };
+            self.pos += 1;
+            Some(&self.poly.coords[start..end])
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,7 +572,33 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn test_polygon_try_from_raw() {
+        use crate::Error;
+
+        let coords: Vec<_> = (0..=2).map(|i| [i as f64, i as f64]).collect();
+        let polygon = Polygon2::try_from_raw(coords[..].into(), [1][..].into()).unwrap();
+        assert_eq!(polygon.interiors().count(), 1);
+
+        assert_eq!(
+            Polygon2::try_from_raw(coords[..].into(), [1, 3][..].into()),
+            Err(Error::SpanOutOfRange {
+                kind: SpanKind::HoleIndices,
+                index: 1,
+                value: 3,
+                len: 3,
+            })
+        );
+        assert_eq!(
+            Polygon2::try_from_raw(coords[..].into(), [2, 1][..].into()),
+            Err(Error::NonMonotonicSpans {
+                kind: SpanKind::HoleIndices,
+                index: 1,
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hole_indices")]
     fn test_polygon_invalid_hole_indices_1() {
         let coords: Vec<_> = (0..=2).map(|i| [i as f64, i as f64]).collect();
         let hole_indices: Vec<u32> = vec![3]; // out of `all_coords` range
@@ -317,7 +606,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "invalid hole_indices")]
     fn test_polygon_invalid_hole_indices_2() {
         let coords: Vec<_> = (0..15).map(|i| [i as f64, i as f64]).collect();
         let hole_indices: Vec<u32> = vec![6, 3]; // not monotonically increasing
@@ -340,4 +629,72 @@ mod tests {
         polygon.add_ring([[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]);
         assert_eq!(polygon.area(), 8.0);
     }
+
+    #[test]
+    fn test_orient() {
+        let mut polygon = Polygon2::new();
+        polygon.add_ring([[0.0, 0.0], [0.0, 3.0], [3.0, 3.0], [3.0, 0.0]]);
+        polygon.add_ring([[1.0, 1.0], [1.0, 2.0], [2.0, 2.0], [2.0, 1.0]]);
+        polygon.add_ring([[1.5, 1.5], [1.6, 1.5], [1.6, 1.6]]);
+        assert_eq!(
+            polygon.misoriented_rings(Orientation::CounterClockwise),
+            [0, 2]
+        );
+        assert_eq!(polygon.misoriented_rings(Orientation::Clockwise), [1]);
+
+        polygon.orient(Orientation::CounterClockwise);
+        assert!(polygon.is_oriented(Orientation::CounterClockwise));
+        assert!(polygon.exterior().is_ccw());
+        assert!(polygon.interiors().all(|ring| ring.is_cw()));
+        // first coordinates are preserved
+        assert_eq!(
+            polygon.exterior().raw_coords(),
+            [[0.0, 0.0], [3.0, 0.0], [3.0, 3.0], [0.0, 3.0]]
+        );
+        assert_eq!(polygon.hole_indices(), [4, 8]);
+        assert_eq!(polygon.area(), 9.0 - 1.0 - 0.005);
+
+        polygon.orient(Orientation::Clockwise);
+        assert!(polygon.exterior().is_cw());
+        assert!(polygon.interiors().all(|ring| ring.is_ccw()));
+
+        // empty and degenerate
+        let mut polygon = Polygon2::<f64>::new();
+        polygon.orient(Orientation::Clockwise);
+        assert!(polygon.is_oriented(Orientation::Clockwise));
+        polygon.add_ring([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+        assert!(polygon.is_oriented(Orientation::Clockwise));
+        assert!(polygon.is_oriented(Orientation::CounterClockwise));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let mut polygon = Polygon2::new();
+        polygon.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        polygon.add_ring([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        polygon.add_ring([[3., 3.], [4., 3.], [4., 4.], [3., 4.]]);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&polygon).unwrap();
+        let archived =
+            rkyv::access::<ArchivedPolygon<[f64; 2]>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), 3);
+        assert_eq!(archived.hole_indices(), [4, 8]);
+        assert_eq!(archived.exterior().len(), 4);
+        assert_eq!(archived.interiors().count(), 2);
+        for (ring, archived_ring) in polygon.rings().zip(archived.rings()) {
+            assert_eq!(ring.raw_coords(), archived_ring);
+        }
+
+        let deserialized: Polygon2 = rkyv::deserialize::<_, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(deserialized, polygon);
+
+        // invalid hole indices are rejected when accessed
+        let invalid = Polygon2::<f64>::from_raw_unchecked(
+            vec![[0., 0.], [1., 0.], [1., 1.]].into(),
+            vec![2, 1].into(),
+        );
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&invalid).unwrap();
+        assert!(rkyv::access::<ArchivedPolygon<[f64; 2]>, rkyv::rancor::Error>(&bytes).is_err());
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{Coord, CoordNum};
+
+/// Axis-aligned bounding box
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Rect<T: Coord> {
+    /// Minimum values of each axis
+    min: T,
+    /// Maximum values of each axis
+    max: T,
+}
+
+pub type Rect2<C = f64> = Rect<[C; 2]>;
+pub type Rect3<C = f64> = Rect<[C; 3]>;
+
+impl<const D: usize, N: CoordNum + PartialOrd + Copy> Rect<[N; D]> {
+    /// Creates a Rect from two corners, ordering the values of each axis.
+    pub fn new(a: [N; D], b: [N; D]) -> Self {
+        let mut min = a;
+        let mut max = a;
+        for i in 0..D {
+            if b[i] < min[i] {
+                min[i] = b[i];
+            } else {
+                max[i] = b[i];
+            }
+        }
+        Self { min, max }
+    }
+
+    /// Computes the bounding box of the given coordinates, or `None` if there is none.
+    pub fn from_coords<I: IntoIterator<Item = [N; D]>>(iter: I) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        let mut rect = Self {
+            min: first,
+            max: first,
+        };
+        for coord in iter {
+            rect.include(&coord);
+        }
+        Some(rect)
+    }
+
+    /// Returns the minimum corner.
+    pub fn min(&self) -> &[N; D] {
+        &self.min
+    }
+
+    /// Returns the maximum corner.
+    pub fn max(&self) -> &[N; D] {
+        &self.max
+    }
+
+    /// Grows the Rect to include the given coordinate.
+    pub fn include(&mut self, coord: &[N; D]) {
+        for ((&v, min), max) in coord.iter().zip(&mut self.min).zip(&mut self.max) {
+            if v < *min {
+                *min = v;
+            }
+            if v > *max {
+                *max = v;
+            }
+        }
+    }
+
+    /// Returns the smallest Rect containing both Rects.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut rect = *self;
+        rect.include(&other.min);
+        rect.include(&other.max);
+        rect
+    }
+
+    /// Returns the overlapping part of two Rects, or `None` if they do not intersect.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let mut rect = *self;
+        for i in 0..D {
+            if other.min[i] > rect.min[i] {
+                rect.min[i] = other.min[i];
+            }
+            if other.max[i] < rect.max[i] {
+                rect.max[i] = other.max[i];
+            }
+        }
+        Some(rect)
+    }
+
+    /// Returns `true` if the Rects share at least one point (including their boundaries).
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..D).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Returns `true` if the other Rect lies entirely within this Rect (including its boundary).
+    pub fn contains(&self, other: &Self) -> bool {
+        (0..D).all(|i| self.min[i] <= other.min[i] && other.max[i] <= self.max[i])
+    }
+
+    /// Returns `true` if the coordinate lies within this Rect (including its boundary).
+    pub fn contains_coord(&self, coord: &[N; D]) -> bool {
+        (0..D).all(|i| self.min[i] <= coord[i] && coord[i] <= self.max[i])
+    }
+}
+
+// 2-dimensional only
+impl<N: CoordNum + PartialOrd + Copy> Rect<[N; 2]> {
+    /// Returns the extent along the x axis.
+    pub fn width(&self) -> f64 {
+        self.max[0].to_f64().unwrap() - self.min[0].to_f64().unwrap()
+    }
+
+    /// Returns the extent along the y axis.
+    pub fn height(&self) -> f64 {
+        self.max[1].to_f64().unwrap() - self.min[1].to_f64().unwrap()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedRect<T> {
+    pub fn min(&self) -> &T::Archived {
+        &self.min
+    }
+
+    pub fn max(&self) -> &T::Archived {
+        &self.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_basic() {
+        let rect = Rect2::new([5., 1.], [1., 3.]);
+        assert_eq!(rect.min(), &[1., 1.]);
+        assert_eq!(rect.max(), &[5., 3.]);
+        assert_eq!(rect.width(), 4.);
+        assert_eq!(rect.height(), 2.);
+
+        let rect = Rect3::<i32>::from_coords([[1, 2, 3], [-1, 5, 0], [0, 0, 9]]).unwrap();
+        assert_eq!(rect.min(), &[-1, 0, 0]);
+        assert_eq!(rect.max(), &[1, 5, 9]);
+
+        assert_eq!(Rect2::<f64>::from_coords([]), None);
+    }
+
+    #[test]
+    fn test_rect_predicates() {
+        let a = Rect2::new([0., 0.], [4., 4.]);
+        let b = Rect2::new([2., 2.], [6., 6.]);
+        let c = Rect2::new([1., 1.], [2., 2.]);
+        let d = Rect2::new([5., 5.], [6., 6.]);
+
+        assert_eq!(a.union(&b), Rect2::new([0., 0.], [6., 6.]));
+        assert_eq!(a.intersection(&b), Some(Rect2::new([2., 2.], [4., 4.])));
+        assert_eq!(a.intersection(&d), None);
+
+        assert!(a.intersects(&b));
+        assert!(a.intersects(&c));
+        assert!(!a.intersects(&d));
+        // touching boundaries
+        assert!(a.intersects(&Rect2::new([4., 0.], [5., 1.])));
+
+        assert!(a.contains(&c));
+        assert!(!a.contains(&b));
+        assert!(a.contains(&a));
+
+        assert!(a.contains_coord(&[4., 0.]));
+        assert!(!a.contains_coord(&[4.1, 0.]));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::Coord;
+
+/// Computer-friendly Triangle
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Triangle<T: Coord> {
+    /// Coordinates of the three vertices
+    coords: [T; 3],
+}
+
+pub type Triangle2<C = f64> = Triangle<[C; 2]>;
+pub type Triangle3<C = f64> = Triangle<[C; 3]>;
+
+impl<T: Coord> Triangle<T> {
+    /// Creates a Triangle from the three vertices.
+    pub fn new(a: T, b: T, c: T) -> Self {
+        Self { coords: [a, b, c] }
+    }
+
+    pub fn raw_coords(&self) -> &[T] {
+        self.as_ref()
+    }
+
+    /// Returns iterator over the three vertices.
+    pub fn iter(&self) -> core::iter::Cloned<core::slice::Iter<'_, T>> {
+        self.coords.iter().cloned()
+    }
+
+    /// Returns iterator over the three vertices with the first vertex repeated.
+    pub fn iter_closed(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter().chain(Some(self.coords[0].clone()))
+    }
+
+    /// Create a new Triangle by applying the given transformation to all vertices.
+    pub fn transform<T2: Coord>(&self, f: impl Fn(&T) -> T2) -> Triangle<T2> {
+        Triangle {
+            coords: [f(&self.coords[0]), f(&self.coords[1]), f(&self.coords[2])],
+        }
+    }
+
+    /// Applies the given transformation to all vertices of the Triangle.
+    pub fn transform_inplace(&mut self, mut f: impl FnMut(&T) -> T) {
+        self.coords.iter_mut().for_each(|c| {
+            *c = f(c);
+        });
+    }
+}
+
+impl<T: Coord> AsRef<[T]> for Triangle<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.coords
+    }
+}
+
+impl<'a, T: Coord> IntoIterator for &'a Triangle<T> {
+    type Item = T;
+    type IntoIter = core::iter::Cloned<core::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: Coord + rkyv::Archive> ArchivedTriangle<T> {
+    pub fn raw_coords(&self) -> &[T::Archived] {
+        &self.coords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_triangle_basic() {
+        let tri = Triangle2::new([0., 0.], [1., 0.], [0., 1.]);
+        assert_eq!(tri.raw_coords(), [[0., 0.], [1., 0.], [0., 1.]]);
+        assert_eq!(tri.iter().count(), 3);
+        assert_eq!(
+            tri.iter_closed().collect::<Vec<_>>(),
+            [[0., 0.], [1., 0.], [0., 1.], [0., 0.]]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut tri = Triangle2::new([0., 0.], [1., 0.], [0., 1.]);
+        let new_tri = tri.transform(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(new_tri.raw_coords(), [[2., 1.], [3., 1.], [2., 2.]]);
+
+        tri.transform_inplace(|[x, y]| [x + 2., y + 1.]);
+        assert_eq!(tri.raw_coords(), [[2., 1.], [3., 1.], [2., 2.]]);
+    }
+}
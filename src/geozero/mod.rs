@@ -6,11 +6,17 @@ use geozero::error::{GeozeroError, Result};
 use geozero::GeozeroGeometry;
 
 pub trait ToFlatgeom<const D: usize> {
-    fn to_flatgeom(&self) -> Result<crate::Geometry<[f64; D]>>;
+    fn to_flatgeom(&self) -> Result<crate::Geometry<'_, [f64; D]>>;
+}
+
+impl From<crate::Error> for GeozeroError {
+    fn from(err: crate::Error) -> Self {
+        GeozeroError::Geometry(err.to_string())
+    }
 }
 
 impl<const D: usize, T: GeozeroGeometry> ToFlatgeom<D> for T {
-    fn to_flatgeom(&self) -> Result<crate::Geometry<[f64; D]>> {
+    fn to_flatgeom(&self) -> Result<crate::Geometry<'_, [f64; D]>> {
         let mut writer = writer::FlatgeomWriter::<D>::new();
         self.process_geom(&mut writer)?;
         writer
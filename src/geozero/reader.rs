@@ -1,4 +1,7 @@
-use crate::geometry::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+use crate::geometry::{
+    Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point,
+    Polygon, Triangle,
+};
 
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -22,14 +25,46 @@ fn process_geometry<const D: usize, P: geozero::GeomProcessor>(
             processor.geometrycollection_end(idx)?;
             Ok(())
         }
+        Geometry::Point(geom) => process_point(geom, processor, idx),
         Geometry::MultiPoint(geom) => process_multipoint(geom, processor, idx),
+        Geometry::Line(geom) => process_line(geom, processor, idx),
         Geometry::LineString(geom) => process_linestring(geom, processor, idx),
         Geometry::MultiLineString(geom) => process_multilinestring(geom, processor, idx),
         Geometry::Polygon(geom) => process_polygon(geom, processor, idx),
         Geometry::MultiPolygon(geom) => process_multipolygon(geom, processor, idx),
+        Geometry::Triangle(geom) => process_triangle(geom, processor, true, idx),
+        Geometry::MultiTriangle(geom) => process_multitriangle(geom, processor, idx),
     }
 }
 
+impl<const D: usize> geozero::GeozeroGeometry for Point<[f64; D]> {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        process_point(self, processor, 0)
+    }
+}
+
+fn process_point<const D: usize, P: geozero::GeomProcessor>(
+    geom: &Point<[f64; D]>,
+    processor: &mut P,
+    idx: usize,
+) -> Result<(), geozero::error::GeozeroError> {
+    let coord = geom.coord();
+    if coord.iter().all(|v| v.is_nan()) {
+        return processor.empty_point(idx);
+    }
+    processor.point_begin(idx)?;
+    if processor.multi_dim() && D >= 3 {
+        processor.coordinate(coord[0], coord[1], Some(coord[2]), None, None, None, 0)?;
+    } else {
+        processor.xy(coord[0], coord[1], 0)?;
+    }
+    processor.point_end(idx)?;
+    Ok(())
+}
+
 impl<const D: usize> geozero::GeozeroGeometry for MultiPoint<'_, [f64; D]> {
     fn process_geom<P: geozero::GeomProcessor>(
         &self,
@@ -98,6 +133,41 @@ fn process_linestring<const D: usize, P: geozero::GeomProcessor>(
     Ok(())
 }
 
+impl<const D: usize> geozero::GeozeroGeometry for Line<[f64; D]> {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        process_line(self, processor, 0)
+    }
+}
+
+/// A Line is processed as a LineString with two vertices, since geozero has no segment type.
+fn process_line<const D: usize, P: geozero::GeomProcessor>(
+    geom: &Line<[f64; D]>,
+    processor: &mut P,
+    idx: usize,
+) -> Result<(), geozero::error::GeozeroError> {
+    processor.linestring_begin(true, 2, idx)?;
+    for (coord_idx, coord) in geom.iter().enumerate() {
+        if processor.multi_dim() && D >= 3 {
+            processor.coordinate(
+                coord[0],
+                coord[1],
+                Some(coord[2]),
+                None,
+                None,
+                None,
+                coord_idx,
+            )?;
+        } else {
+            processor.xy(coord[0], coord[1], coord_idx)?;
+        }
+    }
+    processor.linestring_end(true, idx)?;
+    Ok(())
+}
+
 impl<const D: usize> geozero::GeozeroGeometry for MultiLineString<'_, [f64; D]> {
     fn process_geom<P: geozero::GeomProcessor>(
         &self,
@@ -215,3 +285,62 @@ fn process_multipolygon<const D: usize, P: geozero::GeomProcessor>(
     processor.multipolygon_end(idx)?;
     Ok(())
 }
+
+impl<const D: usize> geozero::GeozeroGeometry for Triangle<[f64; D]> {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        process_triangle(self, processor, true, 0)
+    }
+}
+
+fn process_triangle<const D: usize, P: geozero::GeomProcessor>(
+    geom: &Triangle<[f64; D]>,
+    processor: &mut P,
+    tagged: bool,
+    idx: usize,
+) -> Result<(), geozero::error::GeozeroError> {
+    processor.triangle_begin(tagged, 1, idx)?;
+    processor.linestring_begin(false, 4, 0)?;
+    for (coord_idx, coord) in geom.iter_closed().enumerate() {
+        if processor.multi_dim() && D >= 3 {
+            processor.coordinate(
+                coord[0],
+                coord[1],
+                Some(coord[2]),
+                None,
+                None,
+                None,
+                coord_idx,
+            )?;
+        } else {
+            processor.xy(coord[0], coord[1], coord_idx)?;
+        }
+    }
+    processor.linestring_end(false, 0)?;
+    processor.triangle_end(tagged, idx)?;
+    Ok(())
+}
+
+impl<const D: usize> geozero::GeozeroGeometry for MultiTriangle<'_, [f64; D]> {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        process_multitriangle(self, processor, 0)
+    }
+}
+
+fn process_multitriangle<const D: usize, P: geozero::GeomProcessor>(
+    geom: &MultiTriangle<'_, [f64; D]>,
+    processor: &mut P,
+    idx: usize,
+) -> Result<(), geozero::error::GeozeroError> {
+    processor.tin_begin(geom.len(), idx)?;
+    for (tri_idx, tri) in geom.iter().enumerate() {
+        process_triangle(&tri, processor, false, tri_idx)?;
+    }
+    processor.tin_end(idx)?;
+    Ok(())
+}
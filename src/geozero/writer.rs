@@ -1,9 +1,13 @@
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use geozero::error::Result;
+use geozero::error::{GeozeroError, Result};
 use geozero::GeomProcessor;
 
-use crate::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+use crate::{
+    Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point, Polygon,
+    Triangle,
+};
 
 pub struct FlatgeomWriter<const D: usize> {
     geoms: Vec<Geometry<'static, [f64; D]>>,
@@ -52,10 +56,15 @@ impl<const D: usize> FlatgeomWriter<D> {
         }
     }
 
-    fn finish_coords(&mut self, idx: usize) {
+    fn finish_coords(&mut self, idx: usize) -> Result<()> {
         if let Some(geom) = self.current.as_mut() {
             let iter = self.coords.iter().copied();
             match geom {
+                Geometry::Point(point) => {
+                    // an empty point is represented with NaN coordinates
+                    let coord = self.coords.first().copied().unwrap_or([f64::NAN; D]);
+                    *point = Point::new(coord);
+                }
                 Geometry::MultiPoint(mp) => mp.extend(iter),
                 Geometry::LineString(ls) => ls.extend(iter),
                 Geometry::MultiLineString(mls) => mls.add_linestring(iter),
@@ -67,18 +76,37 @@ impl<const D: usize> FlatgeomWriter<D> {
                         mpoly.add_interior(iter);
                     }
                 }
+                Geometry::Triangle(tri) => *tri = triangle_from_coords(&self.coords)?,
+                Geometry::MultiTriangle(mtri) => mtri.push(&triangle_from_coords(&self.coords)?),
                 _ => {}
             }
         }
         self.coords.clear();
+        Ok(())
     }
 
-    fn finish_geometry(&mut self) {
+    fn finish_geometry(&mut self) -> Result<()> {
+        let Some(geom) = self.current.take() else {
+            return Err(GeozeroError::Geometry(
+                "geometry ended without being started".to_string(),
+            ));
+        };
         if let Some(last) = self.collections.last_mut() {
-            last.push(self.current.take().unwrap());
+            last.push(geom);
         } else {
-            self.geoms.push(self.current.take().unwrap());
+            self.geoms.push(geom);
         }
+        Ok(())
+    }
+}
+
+/// Builds a triangle from a (possibly closed) ring of coordinates.
+fn triangle_from_coords<const D: usize>(coords: &[[f64; D]]) -> Result<Triangle<[f64; D]>> {
+    match coords {
+        &[a, b, c, ..] => Ok(Triangle::new(a, b, c)),
+        _ => Err(GeozeroError::Geometry(
+            "triangle must have three vertices".to_string(),
+        )),
     }
 }
 
@@ -124,10 +152,21 @@ impl<const D: usize> GeomProcessor for FlatgeomWriter<D> {
 
     fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
         let Some(collection) = self.collections.pop() else {
-            panic!("collection stack underflow");
+            return Err(GeozeroError::Geometry(
+                "collection stack underflow".to_string(),
+            ));
         };
         self.current = Some(Geometry::GeometryCollection(collection));
-        self.finish_geometry();
+        self.finish_geometry()
+    }
+
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.point_begin(idx)?;
+        self.point_end(idx)
+    }
+
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.start_geometry(Geometry::Point(Point::new([0.; D])));
         Ok(())
     }
 
@@ -160,34 +199,59 @@ impl<const D: usize> GeomProcessor for FlatgeomWriter<D> {
         Ok(())
     }
 
-    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
-        self.finish_coords(idx);
-        self.finish_geometry();
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.finish_coords(idx)?;
+        self.finish_geometry()
+    }
+
+    fn triangle_begin(&mut self, tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        if tagged {
+            self.start_geometry(Geometry::Triangle(Triangle::new([0.; D], [0.; D], [0.; D])));
+        }
+        Ok(())
+    }
+
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.start_geometry(Geometry::MultiTriangle(MultiTriangle::new()));
         Ok(())
     }
 
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.finish_coords(idx)?;
+        self.finish_geometry()
+    }
+
     fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
-        self.finish_coords(idx);
+        self.finish_coords(idx)?;
         if tagged {
-            self.finish_geometry();
+            self.finish_geometry()?;
         }
         Ok(())
     }
 
     fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
-        self.finish_geometry();
-        Ok(())
+        self.finish_geometry()
     }
 
     fn polygon_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
         if tagged {
-            self.finish_geometry();
+            self.finish_geometry()?;
         }
         Ok(())
     }
 
     fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
-        self.finish_geometry();
+        self.finish_geometry()
+    }
+
+    fn triangle_end(&mut self, tagged: bool, _idx: usize) -> Result<()> {
+        if tagged {
+            self.finish_geometry()?;
+        }
         Ok(())
     }
+
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.finish_geometry()
+    }
 }
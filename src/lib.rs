@@ -2,9 +2,27 @@
 
 extern crate alloc;
 
+mod algorithm;
+pub mod binary;
+pub mod error;
 mod geometry;
+pub mod mvt;
+pub mod projection;
+pub mod tiling;
+pub mod wkb;
+pub mod wkt;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "geo-types")]
+pub mod geo_types;
+#[cfg(feature = "serde")]
+pub mod geojson;
 #[cfg(feature = "geozero")]
 pub mod geozero;
 
+pub use algorithm::{FillRule, PointLocation, Quantizer, ValidationError};
+pub use error::Error;
 pub use geometry::*;
@@ -0,0 +1,233 @@
+use alloc::vec::Vec;
+
+use super::{command, Error, GeomType, Result};
+use crate::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+/// Decodes MVT geometry commands into a geometry.
+///
+/// Points are decoded into a Point or a MultiPoint, linestrings into a LineString or
+/// a MultiLineString, and polygons into a Polygon or a MultiPolygon, depending on their number.
+/// Rings with a positive area start a new polygon, and rings with a negative area are
+/// added to it as interior rings. Rings with zero area are dropped.
+pub fn decode(geom_type: GeomType, commands: &[u32]) -> Result<Geometry<'static, [i32; 2]>> {
+    let mut dec = Decoder {
+        commands,
+        pos: 0,
+        cursor: [0, 0],
+    };
+    match geom_type {
+        GeomType::Point => {
+            let mut mpoint = MultiPoint::new();
+            while !dec.is_eof() {
+                let count = dec.command(command::MOVE_TO, |_| true)?;
+                for _ in 0..count {
+                    mpoint.push(dec.param()?);
+                }
+            }
+            match mpoint.len() {
+                1 => Ok(Geometry::Point(Point::new(mpoint.raw_coords()[0]))),
+                _ => Ok(Geometry::MultiPoint(mpoint)),
+            }
+        }
+        GeomType::LineString => {
+            let mut mls = MultiLineString::new();
+            let mut coords = Vec::new();
+            while !dec.is_eof() {
+                dec.command(command::MOVE_TO, |count| count == 1)?;
+                coords.push(dec.param()?);
+                let count = dec.command(command::LINE_TO, |count| count >= 1)?;
+                for _ in 0..count {
+                    coords.push(dec.param()?);
+                }
+                mls.add_linestring(coords.drain(..));
+            }
+            match mls.len() {
+                1 => Ok(Geometry::LineString(LineString::from_raw(
+                    mls.raw_coords().to_vec().into(),
+                ))),
+                _ => Ok(Geometry::MultiLineString(mls)),
+            }
+        }
+        GeomType::Polygon => {
+            let mut mpoly = MultiPolygon::new();
+            let mut ring = Vec::new();
+            while !dec.is_eof() {
+                let start = dec.pos;
+                dec.command(command::MOVE_TO, |count| count == 1)?;
+                ring.push(dec.param()?);
+                let count = dec.command(command::LINE_TO, |count| count >= 2)?;
+                for _ in 0..count {
+                    ring.push(dec.param()?);
+                }
+                dec.command(command::CLOSE_PATH, |count| count == 1)?;
+                match signed_area(&ring) {
+                    0 => {}
+                    area if area > 0 => mpoly.add_exterior(ring.iter().copied()),
+                    _ if mpoly.is_empty() => {
+                        return Err(Error::UnexpectedInteriorRing { index: start })
+                    }
+                    _ => mpoly.add_interior(ring.iter().copied()),
+                }
+                ring.clear();
+            }
+            match mpoly.len() {
+                1 => {
+                    let mut poly = Polygon::new();
+                    for ring in mpoly.get(0).rings() {
+                        poly.add_ring(ring.iter());
+                    }
+                    Ok(Geometry::Polygon(poly))
+                }
+                _ => Ok(Geometry::MultiPolygon(mpoly)),
+            }
+        }
+    }
+}
+
+struct Decoder<'a> {
+    commands: &'a [u32],
+    pos: usize,
+    cursor: [i32; 2],
+}
+
+impl Decoder<'_> {
+    fn is_eof(&self) -> bool {
+        self.pos >= self.commands.len()
+    }
+
+    fn next(&mut self) -> Result<u32> {
+        let v = *self.commands.get(self.pos).ok_or(Error::UnexpectedEof)?;
+        self.pos += 1;
+        Ok(v)
+    }
+
+    /// Reads a command integer and returns its count.
+    fn command(&mut self, expected: u32, valid_count: impl Fn(u32) -> bool) -> Result<u32> {
+        let index = self.pos;
+        let v = self.next()?;
+        let (id, count) = (v & 0x7, v >> 3);
+        if id != expected {
+            return Err(Error::InvalidCommand { index, id });
+        }
+        if !valid_count(count) {
+            return Err(Error::InvalidCount { index, count });
+        }
+        Ok(count)
+    }
+
+    fn param(&mut self) -> Result<[i32; 2]> {
+        let dx = command::unzigzag(self.next()?);
+        let dy = command::unzigzag(self.next()?);
+        self.cursor = [
+            self.cursor[0].wrapping_add(dx),
+            self.cursor[1].wrapping_add(dy),
+        ];
+        Ok(self.cursor)
+    }
+}
+
+/// Twice the signed area of a ring (surveyor's formula)
+fn signed_area(ring: &[[i32; 2]]) -> i128 {
+    let mut prev = ring[ring.len() - 1];
+    let mut area = 0;
+    for &[x1, y1] in ring {
+        let [x0, y0] = prev;
+        area += x0 as i128 * y1 as i128 - x1 as i128 * y0 as i128;
+        prev = [x1, y1];
+    }
+    area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mvt::ToMvt, MultiPolygon2};
+
+    #[test]
+    fn test_decode_points() {
+        let Ok(Geometry::Point(p)) = decode(GeomType::Point, &[9, 50, 34]) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[25, 17]);
+
+        let Ok(Geometry::MultiPoint(mp)) = decode(GeomType::Point, &[17, 10, 14, 3, 9]) else {
+            panic!("MultiPoint is expected");
+        };
+        assert_eq!(mp.raw_coords(), [[5, 7], [3, 2]]);
+    }
+
+    #[test]
+    fn test_decode_linestrings() {
+        let Ok(Geometry::LineString(ls)) =
+            decode(GeomType::LineString, &[9, 4, 4, 18, 0, 16, 16, 0])
+        else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[2, 2], [2, 10], [10, 10]]);
+
+        let Ok(Geometry::MultiLineString(mls)) = decode(
+            GeomType::LineString,
+            &[9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8],
+        ) else {
+            panic!("MultiLineString is expected");
+        };
+        assert_eq!(mls.len(), 2);
+        assert_eq!(mls.iter().nth(1).unwrap().raw_coords(), [[1, 1], [3, 5]]);
+    }
+
+    #[test]
+    fn test_decode_polygons() {
+        let Ok(Geometry::Polygon(poly)) =
+            decode(GeomType::Polygon, &[9, 6, 12, 18, 10, 12, 24, 44, 15])
+        else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(poly.raw_coords(), [[3, 6], [8, 12], [20, 34]]);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0i32, 0], [10, 0], [10, 10], [0, 10]]);
+        mpoly.add_exterior([[11, 11], [20, 11], [20, 20], [11, 20]]);
+        mpoly.add_interior([[13, 13], [13, 17], [17, 17], [17, 13]]);
+        let Ok(Geometry::MultiPolygon(decoded)) = decode(GeomType::Polygon, &mpoly.to_mvt()) else {
+            panic!("MultiPolygon is expected");
+        };
+        assert_eq!(decoded, mpoly);
+    }
+
+    #[test]
+    fn test_decode_extreme_coords() {
+        let (min, max) = (i32::MIN, i32::MAX);
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[min, min], [max, min], [max, max], [min, max]]);
+        mpoly.add_interior([[min, min], [min, max], [max, max], [max, min]]);
+        let Ok(Geometry::Polygon(poly)) = decode(GeomType::Polygon, &mpoly.to_mvt()) else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(poly.len(), 2);
+        assert_eq!(
+            poly.exterior().raw_coords(),
+            mpoly.get(0).exterior().raw_coords()
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode(GeomType::Point, &[9, 50]).unwrap_err(),
+            Error::UnexpectedEof
+        );
+        assert_eq!(
+            decode(GeomType::LineString, &[10, 4, 4]).unwrap_err(),
+            Error::InvalidCommand { index: 0, id: 2 }
+        );
+        assert_eq!(
+            decode(GeomType::Polygon, &[9, 6, 12, 10, 10, 12, 15]).unwrap_err(),
+            Error::InvalidCount { index: 3, count: 1 }
+        );
+        // counter-clockwise ring first
+        assert_eq!(
+            decode(GeomType::Polygon, &[9, 6, 12, 18, 34, 56, 23, 43, 15]).unwrap_err(),
+            Error::UnexpectedInteriorRing { index: 0 }
+        );
+    }
+}
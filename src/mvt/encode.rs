@@ -0,0 +1,263 @@
+use alloc::vec::Vec;
+
+use super::{command, GeomType};
+use crate::{CoordNum, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+
+/// Integer types that convert losslessly into the `i32` tile coordinates of MVT
+/// (`i8`, `u8`, `i16`, `u16` and `i32`).
+pub trait MvtNum: CoordNum + Copy + Into<i32> {}
+
+impl<N: CoordNum + Copy + Into<i32>> MvtNum for N {}
+
+/// Encodes geometries with integer tile coordinates into MVT geometry commands.
+///
+/// Only coordinate types that fit into `i32` are supported:
+///
+/// ```compile_fail
+/// use flatgeom::{mvt::ToMvt, MultiPoint2};
+///
+/// MultiPoint2::<i64>::new().to_mvt();
+/// ```
+pub trait ToMvt {
+    /// The MVT geometry type of the encoded commands.
+    const GEOM_TYPE: GeomType;
+
+    /// Appends the geometry commands to `out`.
+    fn write_mvt(&self, out: &mut Vec<u32>);
+
+    /// Returns the geometry commands.
+    fn to_mvt(&self) -> Vec<u32> {
+        let mut out = Vec::new();
+        self.write_mvt(&mut out);
+        out
+    }
+}
+
+/// Keeps track of the cursor position while writing commands.
+struct Encoder<'a> {
+    out: &'a mut Vec<u32>,
+    cursor: [i32; 2],
+}
+
+impl Encoder<'_> {
+    fn command(&mut self, id: u32, count: usize) {
+        self.out.push(command::encode(id, count as u32));
+    }
+
+    fn param<N: MvtNum>(&mut self, coord: &[N; 2]) {
+        let [x, y] = coord.map(Into::into);
+        self.out
+            .push(command::zigzag(x.wrapping_sub(self.cursor[0])));
+        self.out
+            .push(command::zigzag(y.wrapping_sub(self.cursor[1])));
+        self.cursor = [x, y];
+    }
+
+    fn linestring<N: MvtNum>(&mut self, coords: &[[N; 2]]) {
+        if coords.len() < 2 {
+            return;
+        }
+        self.command(command::MOVE_TO, 1);
+        self.param(&coords[0]);
+        self.command(command::LINE_TO, coords.len() - 1);
+        for coord in &coords[1..] {
+            self.param(coord);
+        }
+    }
+
+    /// Writes a ring with a positive (exterior) or negative (interior) area.
+    ///
+    /// Returns `false` if the ring is degenerate and was not written.
+    fn ring<N: MvtNum>(&mut self, ring: &[[N; 2]], exterior: bool) -> bool {
+        let ring = match ring {
+            [first, .., last] if ring.len() > 3 && first == last => &ring[..ring.len() - 1],
+            _ => ring,
+        };
+        if ring.len() < 3 {
+            return false;
+        }
+        let area = signed_area(ring);
+        if area == 0 {
+            return false;
+        }
+        self.command(command::MOVE_TO, 1);
+        self.param(&ring[0]);
+        self.command(command::LINE_TO, ring.len() - 1);
+        if (area > 0) == exterior {
+            ring[1..].iter().for_each(|coord| self.param(coord));
+        } else {
+            ring[1..].iter().rev().for_each(|coord| self.param(coord));
+        }
+        self.command(command::CLOSE_PATH, 1);
+        true
+    }
+
+    fn polygon<N: MvtNum>(&mut self, poly: &Polygon<'_, [N; 2]>) {
+        let mut rings = poly.rings();
+        let Some(exterior) = rings.next() else {
+            return;
+        };
+        if !self.ring(exterior.raw_coords(), true) {
+            // interior rings of a degenerate polygon are dropped too
+            return;
+        }
+        for interior in rings {
+            self.ring(interior.raw_coords(), false);
+        }
+    }
+}
+
+/// Twice the signed area of a ring (surveyor's formula)
+fn signed_area<N: MvtNum>(ring: &[[N; 2]]) -> i128 {
+    let mut prev = &ring[ring.len() - 1];
+    let mut area = 0;
+    for coord in ring {
+        let [x0, y0] = prev.map(|v| i128::from(v.into()));
+        let [x1, y1] = coord.map(|v| i128::from(v.into()));
+        area += x0 * y1 - x1 * y0;
+        prev = coord;
+    }
+    area
+}
+
+impl<N: MvtNum> ToMvt for MultiPoint<'_, [N; 2]> {
+    const GEOM_TYPE: GeomType = GeomType::Point;
+
+    fn write_mvt(&self, out: &mut Vec<u32>) {
+        if self.is_empty() {
+            return;
+        }
+        let mut enc = Encoder {
+            out,
+            cursor: [0, 0],
+        };
+        enc.command(command::MOVE_TO, self.len());
+        for coord in self.raw_coords() {
+            enc.param(coord);
+        }
+    }
+}
+
+impl<N: MvtNum> ToMvt for LineString<'_, [N; 2]> {
+    const GEOM_TYPE: GeomType = GeomType::LineString;
+
+    fn write_mvt(&self, out: &mut Vec<u32>) {
+        let mut enc = Encoder {
+            out,
+            cursor: [0, 0],
+        };
+        enc.linestring(self.raw_coords());
+    }
+}
+
+impl<N: MvtNum> ToMvt for MultiLineString<'_, [N; 2]> {
+    const GEOM_TYPE: GeomType = GeomType::LineString;
+
+    fn write_mvt(&self, out: &mut Vec<u32>) {
+        let mut enc = Encoder {
+            out,
+            cursor: [0, 0],
+        };
+        for ls in self {
+            enc.linestring(ls.raw_coords());
+        }
+    }
+}
+
+impl<N: MvtNum> ToMvt for Polygon<'_, [N; 2]> {
+    const GEOM_TYPE: GeomType = GeomType::Polygon;
+
+    fn write_mvt(&self, out: &mut Vec<u32>) {
+        let mut enc = Encoder {
+            out,
+            cursor: [0, 0],
+        };
+        enc.polygon(self);
+    }
+}
+
+impl<N: MvtNum> ToMvt for MultiPolygon<'_, [N; 2]> {
+    const GEOM_TYPE: GeomType = GeomType::Polygon;
+
+    fn write_mvt(&self, out: &mut Vec<u32>) {
+        let mut enc = Encoder {
+            out,
+            cursor: [0, 0],
+        };
+        for poly in self {
+            enc.polygon(&poly);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineString2, MultiLineString2, MultiPoint2, MultiPolygon2, Polygon2};
+    use alloc::vec;
+
+    #[test]
+    fn test_encode_points() {
+        // examples from the MVT specification
+        let mpoint = MultiPoint2::from_raw(vec![[25i32, 17]].into());
+        assert_eq!(mpoint.to_mvt(), [9, 50, 34]);
+
+        let mpoint = MultiPoint2::from_raw(vec![[5i32, 7], [3, 2]].into());
+        assert_eq!(mpoint.to_mvt(), [17, 10, 14, 3, 9]);
+
+        assert!(MultiPoint2::<i32>::new().to_mvt().is_empty());
+    }
+
+    #[test]
+    fn test_encode_linestrings() {
+        let ls = LineString2::from_raw(vec![[2i32, 2], [2, 10], [10, 10]].into());
+        assert_eq!(ls.to_mvt(), [9, 4, 4, 18, 0, 16, 16, 0]);
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[2i32, 2], [2, 10], [10, 10]]);
+        mls.add_linestring([[7, 7]]); // degenerate
+        mls.add_linestring([[1, 1], [3, 5]]);
+        assert_eq!(
+            mls.to_mvt(),
+            [9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8]
+        );
+    }
+
+    #[test]
+    fn test_encode_polygons() {
+        // clockwise in tile coordinates (positive area)
+        let mut poly = Polygon2::new();
+        poly.add_ring([[3i32, 6], [8, 12], [20, 34]]);
+        assert_eq!(poly.to_mvt(), [9, 6, 12, 18, 10, 12, 24, 44, 15]);
+
+        // exterior ring in the opposite direction is reversed
+        let mut poly = Polygon2::new();
+        poly.add_ring([[3i32, 6], [20, 34], [8, 12]]);
+        assert_eq!(poly.to_mvt(), [9, 6, 12, 18, 10, 12, 24, 44, 15]);
+
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0i32, 0], [10, 0], [10, 10], [0, 10]]);
+        mpoly.add_exterior([[11, 11], [20, 11], [20, 20], [11, 20]]);
+        mpoly.add_interior([[13, 13], [13, 17], [17, 17], [17, 13]]);
+        assert_eq!(
+            mpoly.to_mvt(),
+            [
+                9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4,
+                13, 26, 0, 8, 8, 0, 0, 7, 15
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_extreme_coords() {
+        let (min, max) = (i32::MIN, i32::MAX);
+        let mut poly = Polygon2::new();
+        poly.add_ring([[min, min], [max, min], [max, max], [min, max]]);
+        let commands = poly.to_mvt();
+        assert_eq!(commands.len(), 11);
+        assert_eq!(commands[1..3], [u32::MAX, u32::MAX]);
+
+        let mpoint = MultiPoint2::from_raw(vec![[u16::MAX, 0u16]].into());
+        assert_eq!(mpoint.to_mvt(), [9, 2 * u16::MAX as u32, 0]);
+    }
+}
@@ -0,0 +1,80 @@
+//! Mapbox Vector Tile geometry encoding and decoding.
+//!
+//! Geometries with integer tile coordinates are encoded into the `MoveTo`/`LineTo`/`ClosePath`
+//! command streams of the [MVT specification](https://github.com/mapbox/vector-tile-spec/tree/master/2.1#43-geometry-encoding),
+//! and command streams are decoded back into flat geometries.
+//!
+//! Exterior rings are written with a positive area in tile coordinates (clockwise, with the Y axis
+//! pointing down) and interior rings with a negative one, reversing rings where necessary.
+
+mod decode;
+mod encode;
+
+pub use decode::decode;
+pub use encode::{MvtNum, ToMvt};
+
+use core::fmt;
+
+/// Geometry type of an MVT feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum GeomType {
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+}
+
+/// Errors reported when decoding MVT geometry commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The command stream ended in the middle of a command's parameters.
+    UnexpectedEof,
+    /// The command at the given position is unknown or not allowed here.
+    InvalidCommand { index: usize, id: u32 },
+    /// The command at the given position has an invalid count.
+    InvalidCount { index: usize, count: u32 },
+    /// The polygon ring starting at the given position is an interior ring without a preceding exterior ring.
+    UnexpectedInteriorRing { index: usize },
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEof => write!(f, "unexpected end of MVT geometry commands"),
+            Error::InvalidCommand { index, id } => {
+                write!(f, "invalid command {} at index {}", id, index)
+            }
+            Error::InvalidCount { index, count } => {
+                write!(f, "invalid command count {} at index {}", count, index)
+            }
+            Error::UnexpectedInteriorRing { index } => {
+                write!(f, "interior ring at index {} has no exterior ring", index)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+pub(crate) mod command {
+    pub const MOVE_TO: u32 = 1;
+    pub const LINE_TO: u32 = 2;
+    pub const CLOSE_PATH: u32 = 7;
+
+    #[inline]
+    pub fn encode(id: u32, count: u32) -> u32 {
+        (id & 0x7) | (count << 3)
+    }
+
+    #[inline]
+    pub fn zigzag(v: i32) -> u32 {
+        ((v << 1) ^ (v >> 31)) as u32
+    }
+
+    #[inline]
+    pub fn unzigzag(v: u32) -> i32 {
+        ((v >> 1) as i32) ^ -((v & 1) as i32)
+    }
+}
@@ -0,0 +1,210 @@
+//! Coordinate conversions between lon/lat, Web Mercator, tile space and ECEF.
+//!
+//! The functions take and return coordinates by value, so they can be passed directly to
+//! `transform` and `transform_inplace` of any geometry, including [`Geometry`](crate::Geometry):
+//!
+//! ```
+//! use flatgeom::{projection, Point2};
+//!
+//! let mut point = Point2::new([139.7, 35.7]);
+//! point.transform_inplace(projection::lonlat_to_web_mercator);
+//! ```
+//!
+//! Values beyond X and Y (or beyond X, Y and Z for ECEF) are passed through unchanged.
+//! Longitudes and latitudes are in degrees.
+
+use core::f64::consts::PI;
+
+/// Equatorial radius of the WGS84 ellipsoid in meters
+pub const EARTH_RADIUS: f64 = 6378137.0;
+/// Flattening of the WGS84 ellipsoid
+pub const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+/// Half of the extent of the Web Mercator (EPSG:3857) plane in meters
+pub const WEB_MERCATOR_HALF_EXTENT: f64 = PI * EARTH_RADIUS;
+/// Maximum latitude representable in Web Mercator
+pub const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.0511287798066;
+
+/// Converts lon/lat (EPSG:4326) into Web Mercator (EPSG:3857) meters.
+///
+/// Latitudes are clamped to ±[`WEB_MERCATOR_MAX_LATITUDE`].
+pub fn lonlat_to_web_mercator<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    let mut c = *coord;
+    let lat = c[1].clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE);
+    c[0] = c[0].to_radians() * EARTH_RADIUS;
+    c[1] = libm::log(libm::tan(PI / 4.0 + lat.to_radians() / 2.0)) * EARTH_RADIUS;
+    c
+}
+
+/// Converts Web Mercator (EPSG:3857) meters into lon/lat (EPSG:4326).
+pub fn web_mercator_to_lonlat<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    let mut c = *coord;
+    c[0] = (c[0] / EARTH_RADIUS).to_degrees();
+    c[1] = (2.0 * libm::atan(libm::exp(c[1] / EARTH_RADIUS)) - PI / 2.0).to_degrees();
+    c
+}
+
+/// Converts lon/lat into normalized tile space, where the Web Mercator world is mapped
+/// onto `[0, 1]` with the Y axis pointing down (south).
+///
+/// Latitudes are clamped to ±[`WEB_MERCATOR_MAX_LATITUDE`].
+pub fn lonlat_to_tile_space<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    web_mercator_to_tile_space(&lonlat_to_web_mercator(coord))
+}
+
+/// Converts normalized tile space into lon/lat.
+pub fn tile_space_to_lonlat<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    let mut c = *coord;
+    c[0] = c[0] * 360.0 - 180.0;
+    c[1] = libm::atan(libm::sinh(PI * (1.0 - 2.0 * c[1]))).to_degrees();
+    c
+}
+
+/// Converts Web Mercator meters into normalized tile space.
+pub fn web_mercator_to_tile_space<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    let mut c = *coord;
+    c[0] = (c[0] / WEB_MERCATOR_HALF_EXTENT + 1.0) / 2.0;
+    c[1] = (1.0 - c[1] / WEB_MERCATOR_HALF_EXTENT) / 2.0;
+    c
+}
+
+/// Converts normalized tile space into Web Mercator meters.
+pub fn tile_space_to_web_mercator<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    let mut c = *coord;
+    c[0] = (c[0] * 2.0 - 1.0) * WEB_MERCATOR_HALF_EXTENT;
+    c[1] = (1.0 - c[1] * 2.0) * WEB_MERCATOR_HALF_EXTENT;
+    c
+}
+
+/// Converts lon/lat and ellipsoidal height in meters into geocentric (ECEF, EPSG:4978) coordinates on WGS84.
+pub fn lonlat_to_ecef<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    const { assert!(D >= 3, "ECEF requires 3-dimensional coordinates") };
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let mut c = *coord;
+    let (lon, lat, height) = (c[0].to_radians(), c[1].to_radians(), c[2]);
+    let (sin_lat, cos_lat) = libm::sincos(lat);
+    let (sin_lon, cos_lon) = libm::sincos(lon);
+    let n = EARTH_RADIUS / libm::sqrt(1.0 - e2 * sin_lat * sin_lat);
+    c[0] = (n + height) * cos_lat * cos_lon;
+    c[1] = (n + height) * cos_lat * sin_lon;
+    c[2] = (n * (1.0 - e2) + height) * sin_lat;
+    c
+}
+
+/// Converts geocentric (ECEF) coordinates into lon/lat and ellipsoidal height on WGS84.
+///
+/// Uses Bowring's method, which is accurate to well below a millimeter near the earth's surface.
+pub fn ecef_to_lonlat<const D: usize>(coord: &[f64; D]) -> [f64; D] {
+    const { assert!(D >= 3, "ECEF requires 3-dimensional coordinates") };
+    let a = EARTH_RADIUS;
+    let b = a * (1.0 - EARTH_FLATTENING);
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let ep2 = (a * a - b * b) / (b * b);
+
+    let mut c = *coord;
+    let (x, y, z) = (c[0], c[1], c[2]);
+    let p = libm::hypot(x, y);
+    let theta = libm::atan2(z * a, p * b);
+    let (sin_t, cos_t) = libm::sincos(theta);
+    let lat = libm::atan2(
+        z + ep2 * b * sin_t * sin_t * sin_t,
+        p - e2 * a * cos_t * cos_t * cos_t,
+    );
+    let (sin_lat, cos_lat) = libm::sincos(lat);
+    let n = a / libm::sqrt(1.0 - e2 * sin_lat * sin_lat);
+    let height = if cos_lat.abs() > 1e-10 {
+        p / cos_lat - n
+    } else {
+        z.abs() - b
+    };
+    c[0] = libm::atan2(y, x).to_degrees();
+    c[1] = lat.to_degrees();
+    c[2] = height;
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry3, LineString, Polygon2};
+    use alloc::vec;
+
+    fn assert_close<const D: usize>(a: [f64; D], b: [f64; D], eps: f64) {
+        for i in 0..D {
+            assert!((a[i] - b[i]).abs() < eps, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_web_mercator() {
+        assert_close(lonlat_to_web_mercator(&[0., 0.]), [0., 0.], 1e-9);
+        assert_close(
+            lonlat_to_web_mercator(&[180., WEB_MERCATOR_MAX_LATITUDE]),
+            [WEB_MERCATOR_HALF_EXTENT, WEB_MERCATOR_HALF_EXTENT],
+            1e-6,
+        );
+        let tokyo = [139.6917, 35.6895, 40.];
+        let merc = lonlat_to_web_mercator(&tokyo);
+        assert_close(merc, [15550408.91, 4257980.73, 40.], 0.01);
+        assert_close(web_mercator_to_lonlat(&merc), tokyo, 1e-9);
+
+        // clamped
+        assert!(lonlat_to_web_mercator(&[0., 90.])[1].is_finite());
+    }
+
+    #[test]
+    fn test_tile_space() {
+        assert_close(lonlat_to_tile_space(&[0., 0.]), [0.5, 0.5], 1e-12);
+        assert_close(
+            lonlat_to_tile_space(&[-180., WEB_MERCATOR_MAX_LATITUDE]),
+            [0., 0.],
+            1e-12,
+        );
+        let c = [139.6917, 35.6895];
+        assert_close(tile_space_to_lonlat(&lonlat_to_tile_space(&c)), c, 1e-9);
+        assert_close(
+            tile_space_to_web_mercator(&[1., 1.]),
+            [WEB_MERCATOR_HALF_EXTENT, -WEB_MERCATOR_HALF_EXTENT],
+            1e-9,
+        );
+        assert_close(
+            web_mercator_to_tile_space(&[0., WEB_MERCATOR_HALF_EXTENT]),
+            [0.5, 0.],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn test_ecef() {
+        assert_close(lonlat_to_ecef(&[0., 0., 0.]), [EARTH_RADIUS, 0., 0.], 1e-6);
+        let b = EARTH_RADIUS * (1.0 - EARTH_FLATTENING);
+        assert_close(lonlat_to_ecef(&[0., 90., 10.]), [0., 0., b + 10.], 1e-6);
+
+        // Mt. Fuji
+        let c = [138.7274, 35.3606, 3776., 1.];
+        let ecef = lonlat_to_ecef(&c);
+        assert_close(ecef, [-3916073.259, 3437037.684, 3672751.026, 1.], 1e-3);
+        assert_close(ecef_to_lonlat(&ecef), c, 1e-6);
+
+        assert_close(ecef_to_lonlat(&[0., 0., -b - 5.]), [0., -90., 5.], 1e-6);
+    }
+
+    #[test]
+    fn test_transform_geometries() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[-180., -85.], [180., -85.], [180., 85.], [-180., 85.]]);
+        poly.transform_inplace(lonlat_to_tile_space);
+        assert!(poly
+            .raw_coords()
+            .iter()
+            .all(|c| (0.0..=1.0).contains(&c[0])));
+
+        let mut geom = Geometry3::LineString(LineString::from_raw(
+            vec![[0., 0., 0.], [90., 0., 0.]].into(),
+        ));
+        geom.transform_inplace(lonlat_to_ecef);
+        let Geometry3::LineString(ls) = geom else {
+            panic!("LineString is expected");
+        };
+        assert_close(ls.raw_coords()[1], [0., EARTH_RADIUS, 0.], 1e-6);
+    }
+}
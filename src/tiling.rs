@@ -0,0 +1,293 @@
+//! Splitting geometries into a quadtree of map tiles.
+//!
+//! [`Tiler`] clips a geometry to every `z/x/y` tile it touches within a zoom range.
+//! Each tile is clipped from the already clipped geometry of its parent tile,
+//! so the cost of clipping shrinks as the zoom level increases.
+//!
+//! Geometries are given and returned in either Web Mercator meters or lon/lat degrees,
+//! and the tile bounds are computed in the same coordinate system.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::projection::{tile_space_to_lonlat, WEB_MERCATOR_HALF_EXTENT};
+use crate::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon, Rect2};
+
+/// Coordinate system of the geometries to tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crs {
+    /// Web Mercator (EPSG:3857) meters
+    WebMercator,
+    /// Longitude and latitude in degrees (EPSG:4326)
+    LonLat,
+}
+
+/// Returns the bounds of the tile `z/x/y` in the given coordinate system.
+pub fn tile_bounds(crs: Crs, z: u8, x: u32, y: u32) -> Rect2 {
+    let n = (1u64 << z) as f64;
+    match crs {
+        Crs::WebMercator => {
+            let size = 2.0 * WEB_MERCATOR_HALF_EXTENT / n;
+            Rect2::new(
+                [
+                    -WEB_MERCATOR_HALF_EXTENT + x as f64 * size,
+                    WEB_MERCATOR_HALF_EXTENT - (y + 1) as f64 * size,
+                ],
+                [
+                    -WEB_MERCATOR_HALF_EXTENT + (x + 1) as f64 * size,
+                    WEB_MERCATOR_HALF_EXTENT - y as f64 * size,
+                ],
+            )
+        }
+        Crs::LonLat => {
+            let lon = |x: u32| x as f64 / n * 360.0 - 180.0;
+            let lat = |y: u32| tile_space_to_lonlat(&[0.0, y as f64 / n])[1];
+            Rect2::new([lon(x), lat(y + 1)], [lon(x + 1), lat(y)])
+        }
+    }
+}
+
+/// Splits geometries into map tiles.
+#[derive(Debug, Clone)]
+pub struct Tiler {
+    crs: Crs,
+    min_zoom: u8,
+    max_zoom: u8,
+    buffer: f64,
+}
+
+impl Tiler {
+    /// Create a new Tiler for geometries in `crs`, producing tiles in the `zoom` range.
+    ///
+    /// Panics if the maximum zoom level is greater than 31.
+    pub fn new(crs: Crs, zoom: RangeInclusive<u8>) -> Self {
+        assert!(*zoom.end() <= 31, "zoom level must be 31 or less");
+        Self {
+            crs,
+            min_zoom: *zoom.start(),
+            max_zoom: *zoom.end(),
+            buffer: 0.0,
+        }
+    }
+
+    /// Sets the buffer around each tile, as a ratio of the tile size (e.g. `64.0 / 4096.0`).
+    pub fn buffer(mut self, buffer: f64) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// Returns the bounds of the tile `z/x/y`, including the buffer.
+    pub fn buffered_bounds(&self, z: u8, x: u32, y: u32) -> Rect2 {
+        let bounds = tile_bounds(self.crs, z, x, y);
+        let dx = bounds.width() * self.buffer;
+        let dy = bounds.height() * self.buffer;
+        let [min_x, min_y] = *bounds.min();
+        let [max_x, max_y] = *bounds.max();
+        Rect2::new([min_x - dx, min_y - dy], [max_x + dx, max_y + dy])
+    }
+
+    /// Returns an iterator over `(z, x, y, geometry)` for every tile the geometry touches.
+    ///
+    /// Lines are clipped as LineStrings, and Triangles and MultiTriangles as Polygons and MultiPolygons.
+    /// Values other than X and Y (such as Z) are interpolated along the cut edges.
+    pub fn tile<const D: usize>(&self, geom: &Geometry<'_, [f64; D]>) -> Tiles<'_, D> {
+        let mut stack = Vec::new();
+        if let Some(clipped) = clip(geom, &self.buffered_bounds(0, 0, 0)) {
+            stack.push((0, 0, 0, clipped));
+        }
+        Tiles { tiler: self, stack }
+    }
+}
+
+/// Iterator over the tiles of a geometry, created by [`Tiler::tile`].
+pub struct Tiles<'a, const D: usize> {
+    tiler: &'a Tiler,
+    stack: Vec<(u8, u32, u32, Geometry<'static, [f64; D]>)>,
+}
+
+impl<const D: usize> Iterator for Tiles<'_, D> {
+    type Item = (u8, u32, u32, Geometry<'static, [f64; D]>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (z, x, y, geom) = self.stack.pop()?;
+            if z < self.tiler.max_zoom {
+                // pushed in reverse order so that children are visited in row-major order
+                for (cx, cy) in [(1, 1), (0, 1), (1, 0), (0, 0)] {
+                    let (cz, cx, cy) = (z + 1, x * 2 + cx, y * 2 + cy);
+                    let bounds = self.tiler.buffered_bounds(cz, cx, cy);
+                    if let Some(clipped) = clip(&geom, &bounds) {
+                        self.stack.push((cz, cx, cy, clipped));
+                    }
+                }
+            }
+            if z >= self.tiler.min_zoom {
+                return Some((z, x, y, geom));
+            }
+        }
+    }
+}
+
+/// Clips a geometry to the rectangle, returning `None` if nothing remains.
+fn clip<const D: usize>(
+    geom: &Geometry<'_, [f64; D]>,
+    rect: &Rect2,
+) -> Option<Geometry<'static, [f64; D]>> {
+    let contains = |c: &[f64; D]| rect.contains_coord(&[c[0], c[1]]);
+    let linestrings = |mls: MultiLineString<'static, [f64; D]>| match mls.len() {
+        0 => None,
+        1 => Some(Geometry::LineString(LineString::from_raw(
+            mls.raw_coords().to_vec().into(),
+        ))),
+        _ => Some(Geometry::MultiLineString(mls)),
+    };
+    let polygon = |poly: Polygon<'static, [f64; D]>| {
+        (!poly.raw_coords().is_empty()).then_some(Geometry::Polygon(poly))
+    };
+    let multipolygon = |mpoly: MultiPolygon<'static, [f64; D]>| {
+        (!mpoly.is_empty()).then_some(Geometry::MultiPolygon(mpoly))
+    };
+
+    match geom {
+        Geometry::Point(p) => contains(p.coord()).then_some(Geometry::Point(*p)),
+        Geometry::MultiPoint(mp) => {
+            let coords: Vec<_> = mp.iter().filter(contains).collect();
+            (!coords.is_empty()).then(|| Geometry::MultiPoint(MultiPoint::from_raw(coords.into())))
+        }
+        Geometry::Line(line) => {
+            linestrings(LineString::from_raw(line.raw_coords().into()).clip(rect))
+        }
+        Geometry::LineString(ls) => linestrings(ls.clip(rect)),
+        Geometry::MultiLineString(mls) => {
+            let mls = mls.clip(rect);
+            (!mls.is_empty()).then_some(Geometry::MultiLineString(mls))
+        }
+        Geometry::Polygon(poly) => polygon(poly.clip(rect)),
+        Geometry::MultiPolygon(mpoly) => multipolygon(mpoly.clip(rect)),
+        Geometry::Triangle(tri) => {
+            polygon(Polygon::from_raw(tri.raw_coords().into(), [].as_slice().into()).clip(rect))
+        }
+        Geometry::MultiTriangle(mtri) => {
+            let mut mpoly = MultiPolygon::new();
+            for tri in mtri {
+                mpoly.add_exterior(tri.iter());
+            }
+            multipolygon(mpoly.clip(rect))
+        }
+        Geometry::GeometryCollection(geoms) => {
+            let geoms: Vec<_> = geoms.iter().filter_map(|g| clip(g, rect)).collect();
+            (!geoms.is_empty()).then_some(Geometry::GeometryCollection(geoms))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, Geometry3, MultiPolygon2, Polygon2};
+    use alloc::vec;
+
+    #[test]
+    fn test_tile_bounds() {
+        let bounds = tile_bounds(Crs::WebMercator, 0, 0, 0);
+        assert_eq!(
+            bounds.min(),
+            &[-WEB_MERCATOR_HALF_EXTENT, -WEB_MERCATOR_HALF_EXTENT]
+        );
+        assert_eq!(
+            bounds.max(),
+            &[WEB_MERCATOR_HALF_EXTENT, WEB_MERCATOR_HALF_EXTENT]
+        );
+
+        let bounds = tile_bounds(Crs::WebMercator, 1, 1, 0);
+        assert_eq!(bounds.min(), &[0., 0.]);
+        assert_eq!(
+            bounds.max(),
+            &[WEB_MERCATOR_HALF_EXTENT, WEB_MERCATOR_HALF_EXTENT]
+        );
+
+        let bounds = tile_bounds(Crs::LonLat, 0, 0, 0);
+        assert_eq!(bounds.min()[0], -180.);
+        assert!((bounds.max()[1] - 85.0511287798).abs() < 1e-9);
+
+        let bounds = tile_bounds(Crs::LonLat, 2, 3, 2);
+        assert_eq!(bounds.min(), &[90., -66.51326044311186]);
+        assert_eq!(bounds.max(), &[180., 0.]);
+    }
+
+    #[test]
+    fn test_tiler_polygon() {
+        // a square around lon/lat (0, 0)
+        let mut poly = Polygon2::new();
+        poly.add_ring([[-10., -10.], [10., -10.], [10., 10.], [-10., 10.]]);
+        let geom = Geometry2::Polygon(poly);
+
+        let tiler = Tiler::new(Crs::LonLat, 0..=2);
+        let tiles: Vec<_> = tiler.tile(&geom).collect();
+        let ids: Vec<_> = tiles.iter().map(|(z, x, y, _)| (*z, *x, *y)).collect();
+        assert_eq!(
+            ids,
+            [
+                (0, 0, 0),
+                (1, 0, 0),
+                (2, 1, 1),
+                (1, 1, 0),
+                (2, 2, 1),
+                (1, 0, 1),
+                (2, 1, 2),
+                (1, 1, 1),
+                (2, 2, 2)
+            ]
+        );
+        let Geometry::Polygon(clipped) = &tiles[2].3 else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(clipped.exterior().ring_area(), 100.);
+
+        // tiles above the minimum zoom level are skipped
+        let tiler = Tiler::new(Crs::LonLat, 2..=2);
+        assert_eq!(tiler.tile(&geom).count(), 4);
+
+        // the buffer makes tiles overlap
+        let tiler = Tiler::new(Crs::LonLat, 1..=1).buffer(0.1);
+        for (_, _, _, geom) in tiler.tile(&geom) {
+            let Geometry::Polygon(clipped) = geom else {
+                panic!("Polygon is expected");
+            };
+            assert!(clipped.exterior().ring_area() > 100.);
+        }
+    }
+
+    #[test]
+    fn test_tiler_mixed() {
+        let half = WEB_MERCATOR_HALF_EXTENT;
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[-half, 0.], [-half / 2., 0.], [-half / 2., half / 2.]]);
+        let geom = Geometry3::GeometryCollection(vec![
+            Geometry::Point([half / 2., half / 2., 1.].into()),
+            Geometry::LineString(LineString::from_raw(
+                vec![[-half / 2., -half / 2., 0.], [half / 2., -half / 2., 10.]].into(),
+            )),
+        ]);
+
+        let tiler = Tiler::new(Crs::WebMercator, 1..=1);
+        let tiles: Vec<_> = tiler.tile(&geom).collect();
+        assert_eq!(tiles.len(), 3);
+        let (z, x, y, Geometry::GeometryCollection(geoms)) = &tiles[0] else {
+            panic!("GeometryCollection is expected");
+        };
+        assert_eq!((*z, *x, *y), (1, 1, 0));
+        assert!(matches!(geoms[..], [Geometry::Point(_)]));
+        let (_, _, _, Geometry::GeometryCollection(geoms)) = &tiles[1] else {
+            panic!("GeometryCollection is expected");
+        };
+        let Geometry::LineString(ls) = &geoms[0] else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords()[1], [0., -half / 2., 5.]);
+
+        let tiles: Vec<_> = tiler.tile(&Geometry::MultiPolygon(mpoly)).collect();
+        assert_eq!(tiles.len(), 1);
+        assert_eq!((tiles[0].0, tiles[0].1, tiles[0].2), (1, 0, 0));
+    }
+}
@@ -0,0 +1,476 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use super::{code, Error, Result};
+use crate::{
+    Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point, Polygon,
+    Triangle,
+};
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Maximum nesting depth of GeometryCollections
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Decodes a WKB or EWKB geometry into flat buffers.
+///
+/// LineStrings and single-ring Polygons borrow their coordinates from `bytes` without copying
+/// when the byte order is native, the number of dimensions equals `D` and the buffer is suitably aligned.
+///
+/// GeometryCollections nested more than 64 levels deep are rejected with [`Error::NestingTooDeep`].
+pub fn read_wkb<const D: usize>(bytes: &[u8]) -> Result<Geometry<'_, [f64; D]>> {
+    const { assert!(D >= 2, "WKB coordinates must have at least 2 dimensions") };
+    Decoder { buf: bytes, pos: 0 }.geometry(0)
+}
+
+struct Header {
+    little_endian: bool,
+    kind: u32,
+    has_z: bool,
+    has_m: bool,
+}
+
+impl Header {
+    fn dims(&self) -> usize {
+        2 + self.has_z as usize + self.has_m as usize
+    }
+}
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or(Error::UnexpectedEof)?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.take()?;
+        Ok(match little_endian {
+            true => u32::from_le_bytes(bytes),
+            false => u32::from_be_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self, little_endian: bool) -> Result<f64> {
+        let bytes = self.take()?;
+        Ok(match little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Reads an element count, making sure the remaining input can hold that many elements
+    /// so that untrusted counts never cause huge allocations.
+    fn count(&mut self, little_endian: bool, min_size: usize) -> Result<usize> {
+        let n = self.u32(little_endian)? as usize;
+        if n.saturating_mul(min_size) > self.buf.len() - self.pos {
+            return Err(Error::UnexpectedEof);
+        }
+        Ok(n)
+    }
+
+    fn header(&mut self) -> Result<Header> {
+        let little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(Error::InvalidByteOrder(b)),
+        };
+        let raw = self.u32(little_endian)?;
+        if raw & EWKB_SRID != 0 {
+            self.u32(little_endian)?; // SRID is not used
+        }
+        let iso = raw & 0x0FFF_FFFF;
+        let (iso_z, iso_m) = match iso / 1000 {
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => (false, false),
+        };
+        Ok(Header {
+            little_endian,
+            kind: iso % 1000,
+            has_z: raw & EWKB_Z != 0 || iso_z,
+            has_m: raw & EWKB_M != 0 || iso_m,
+        })
+    }
+
+    fn member_header(&mut self, expected: u32) -> Result<Header> {
+        let header = self.header()?;
+        if header.kind != expected {
+            return Err(Error::UnexpectedGeometryType {
+                expected,
+                found: header.kind,
+            });
+        }
+        Ok(header)
+    }
+
+    fn coord<const D: usize>(&mut self, h: &Header) -> Result<[f64; D]> {
+        let mut coord = [0.; D];
+        coord[0] = self.f64(h.little_endian)?;
+        coord[1] = self.f64(h.little_endian)?;
+        if h.has_z {
+            let z = self.f64(h.little_endian)?;
+            if D >= 3 {
+                coord[2] = z;
+            }
+        }
+        if h.has_m {
+            let m = self.f64(h.little_endian)?;
+            if D >= 4 {
+                coord[3] = m;
+            }
+        }
+        Ok(coord)
+    }
+
+    fn coords<const D: usize>(
+        &mut self,
+        h: &Header,
+        n: usize,
+        out: &mut Vec<[f64; D]>,
+    ) -> Result<()> {
+        out.reserve(n);
+        for _ in 0..n {
+            out.push(self.coord(h)?);
+        }
+        Ok(())
+    }
+
+    /// Borrows `n` coordinates directly from the input if their memory layout matches `[f64; D]`.
+    fn borrow_coords<const D: usize>(&mut self, h: &Header, n: usize) -> Option<&'a [[f64; D]]> {
+        if h.dims() != D || h.little_endian != cfg!(target_endian = "little") {
+            return None;
+        }
+        let bytes = self.buf.get(self.pos..self.pos + n * D * 8)?;
+        if bytes
+            .as_ptr()
+            .align_offset(core::mem::align_of::<[f64; D]>())
+            != 0
+        {
+            return None;
+        }
+        self.pos += bytes.len();
+        // SAFETY: the pointer is aligned and in bounds, the byte order is native,
+        // and any bit pattern is a valid f64.
+        Some(unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const [f64; D], n) })
+    }
+
+    fn geometry<const D: usize>(&mut self, depth: usize) -> Result<Geometry<'a, [f64; D]>> {
+        let h = self.header()?;
+        let le = h.little_endian;
+        match h.kind {
+            code::POINT => Ok(Geometry::Point(Point::new(self.coord(&h)?))),
+            code::LINESTRING => {
+                let n = self.count(le, h.dims() * 8)?;
+                if let Some(coords) = self.borrow_coords(&h, n) {
+                    return Ok(Geometry::LineString(LineString::from_raw(coords.into())));
+                }
+                let mut coords = Vec::new();
+                self.coords(&h, n, &mut coords)?;
+                Ok(Geometry::LineString(LineString::from_raw(coords.into())))
+            }
+            code::POLYGON => {
+                let n_rings = self.count(le, 4)?;
+                if n_rings == 1 {
+                    let start = self.pos;
+                    let n = self.count(le, h.dims() * 8)?;
+                    if let Some(coords) = self.borrow_coords(&h, n) {
+                        let coords = match coords {
+                            [first, .., last] if coords.len() > 2 && first == last => {
+                                &coords[..n - 1]
+                            }
+                            _ => coords,
+                        };
+                        return Ok(Geometry::Polygon(Polygon::from_raw(
+                            coords.into(),
+                            Cow::Borrowed(&[]),
+                        )));
+                    }
+                    self.pos = start;
+                }
+                let mut coords = Vec::new();
+                let mut hole_indices = Vec::with_capacity(n_rings.saturating_sub(1));
+                self.rings(&h, n_rings, &mut coords, &mut hole_indices, 0)?;
+                Ok(Geometry::Polygon(Polygon::from_raw(
+                    coords.into(),
+                    hole_indices.into(),
+                )))
+            }
+            code::MULTIPOINT => {
+                let n = self.count(le, 5 + 16)?;
+                let mut coords = Vec::with_capacity(n);
+                for _ in 0..n {
+                    let h = self.member_header(code::POINT)?;
+                    coords.push(self.coord(&h)?);
+                }
+                Ok(Geometry::MultiPoint(MultiPoint::from_raw(coords.into())))
+            }
+            code::MULTILINESTRING => {
+                let n = self.count(le, 5 + 4)?;
+                let mut all_coords = Vec::new();
+                let mut coords_spans = Vec::with_capacity(n.saturating_sub(1));
+                for _ in 0..n {
+                    let h = self.member_header(code::LINESTRING)?;
+                    let n = self.count(h.little_endian, h.dims() * 8)?;
+                    // empty members are skipped
+                    if n > 0 && !all_coords.is_empty() {
+                        coords_spans.push(all_coords.len() as u32);
+                    }
+                    self.coords(&h, n, &mut all_coords)?;
+                }
+                Ok(Geometry::MultiLineString(MultiLineString::from_raw(
+                    all_coords.into(),
+                    coords_spans.into(),
+                )))
+            }
+            code::MULTIPOLYGON => {
+                let n = self.count(le, 5 + 4)?;
+                let mut all_coords = Vec::new();
+                let mut coords_spans = Vec::with_capacity(n.saturating_sub(1));
+                let mut all_hole_indices = Vec::new();
+                let mut holes_spans = Vec::with_capacity(n.saturating_sub(1));
+                for _ in 0..n {
+                    let h = self.member_header(code::POLYGON)?;
+                    let n_rings = self.count(h.little_endian, 4)?;
+                    all_hole_indices.reserve(n_rings.saturating_sub(1));
+                    let (base, holes_start) = (all_coords.len(), all_hole_indices.len());
+                    self.rings(&h, n_rings, &mut all_coords, &mut all_hole_indices, base)?;
+                    // empty members are skipped
+                    if base > 0 && all_coords.len() > base {
+                        coords_spans.push(base as u32);
+                        holes_spans.push(holes_start as u32);
+                    }
+                }
+                Ok(Geometry::MultiPolygon(MultiPolygon::from_raw(
+                    all_coords.into(),
+                    coords_spans.into(),
+                    all_hole_indices.into(),
+                    holes_spans.into(),
+                )))
+            }
+            code::GEOMETRYCOLLECTION => {
+                if depth >= MAX_NESTING_DEPTH {
+                    return Err(Error::NestingTooDeep);
+                }
+                let n = self.count(le, 5)?;
+                let mut geoms = Vec::with_capacity(n);
+                for _ in 0..n {
+                    geoms.push(self.geometry(depth + 1)?);
+                }
+                Ok(Geometry::GeometryCollection(geoms))
+            }
+            code::TRIANGLE => match self.triangle(&h)? {
+                Some(tri) => Ok(Geometry::Triangle(tri)),
+                None => Ok(Geometry::Triangle(Triangle::new(
+                    [f64::NAN; D],
+                    [f64::NAN; D],
+                    [f64::NAN; D],
+                ))),
+            },
+            code::TIN => {
+                let n = self.count(le, 5 + 4)?;
+                let mut coords = Vec::with_capacity(n * 3);
+                let mut indices = Vec::with_capacity(n * 3);
+                for _ in 0..n {
+                    let h = self.member_header(code::TRIANGLE)?;
+                    // empty members are skipped
+                    if let Some(tri) = self.triangle(&h)? {
+                        indices.extend((coords.len() as u32..).take(3));
+                        coords.extend(tri.iter());
+                    }
+                }
+                Ok(Geometry::MultiTriangle(MultiTriangle::from_raw(
+                    coords.into(),
+                    indices.into(),
+                )))
+            }
+            kind => Err(Error::UnsupportedGeometryType(kind)),
+        }
+    }
+
+    /// Reads polygon rings, removing the closing points the same way `Polygon::add_ring` does.
+    ///
+    /// Empty rings are skipped, so the first non-empty ring becomes the exterior.
+    fn rings<const D: usize>(
+        &mut self,
+        h: &Header,
+        n_rings: usize,
+        coords: &mut Vec<[f64; D]>,
+        hole_indices: &mut Vec<u32>,
+        base: usize,
+    ) -> Result<()> {
+        for _ in 0..n_rings {
+            let n = self.count(h.little_endian, h.dims() * 8)?;
+            let head = coords.len();
+            if n > 0 && head > base {
+                hole_indices.push((head - base) as u32);
+            }
+            self.coords(h, n, coords)?;
+
+            // remove closing point if exists
+            let tail = coords.len();
+            if tail > head + 2 && coords[head] == coords[tail - 1] {
+                coords.truncate(tail - 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a triangle, returning `None` if it is empty.
+    fn triangle<const D: usize>(&mut self, h: &Header) -> Result<Option<Triangle<[f64; D]>>> {
+        match self.count(h.little_endian, 4)? {
+            0 => Ok(None),
+            1 => {
+                let n = self.count(h.little_endian, h.dims() * 8)?;
+                if n != 4 {
+                    return Err(Error::InvalidTriangle { len: n });
+                }
+                let (a, b, c) = (self.coord(h)?, self.coord(h)?, self.coord(h)?);
+                self.coord::<D>(h)?; // closing point
+                Ok(Some(Triangle::new(a, b, c)))
+            }
+            n => Err(Error::InvalidTriangle { len: n }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, Geometry3};
+    use alloc::vec;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_read_point() {
+        // POINT(1 2)
+        let bytes = hex("0101000000000000000000F03F0000000000000040");
+        let Ok(Geometry2::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2.]);
+
+        // big endian
+        let bytes = hex("00000000013FF00000000000004000000000000000");
+        let Ok(Geometry2::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2.]);
+
+        // EWKB: SRID=4326;POINT(1 2)
+        let bytes = hex("0101000020E6100000000000000000F03F0000000000000040");
+        let Ok(Geometry3::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2., 0.]);
+
+        // ISO: POINT ZM (1 2 3 4)
+        let bytes =
+            hex("01B90B0000000000000000F03F000000000000004000000000000008400000000000001040");
+        let Ok(Geometry::<[f64; 4]>::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2., 3., 4.]);
+        let Ok(Geometry2::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2.]);
+
+        // EWKB: POINT M (1 2 4)
+        let bytes = hex("0101000040000000000000F03F00000000000000400000000000001040");
+        let Ok(Geometry::<[f64; 4]>::Point(point)) = read_wkb(&bytes) else {
+            panic!("Point is expected");
+        };
+        assert_eq!(point.coord(), &[1., 2., 0., 4.]);
+    }
+
+    #[test]
+    fn test_read_zero_copy() {
+        // align the coordinates of LINESTRING(1 2,3 4) to 8 bytes
+        let mut buf = vec![0u64; 6];
+        let bytes: &mut [u8] =
+            unsafe { core::slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut u8, buf.len() * 8) };
+        let wkb = hex(
+            "010200000002000000000000000000F03F000000000000004000000000000008400000000000001040",
+        );
+        // header (9 bytes) + padding so that coordinates start at offset 16
+        bytes[7..7 + wkb.len()].copy_from_slice(&wkb);
+        let Ok(Geometry2::LineString(ls)) = read_wkb(&bytes[7..7 + wkb.len()]) else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[1., 2.], [3., 4.]]);
+        if cfg!(target_endian = "little") {
+            assert_eq!(ls.raw_coords().as_ptr() as *const u8, bytes[16..].as_ptr());
+        }
+
+        // not aligned
+        let Ok(Geometry2::LineString(ls)) = read_wkb(&wkb) else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[1., 2.], [3., 4.]]);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(read_wkb::<2>(&[]).unwrap_err(), Error::UnexpectedEof);
+        assert_eq!(
+            read_wkb::<2>(&hex("0201000000")).unwrap_err(),
+            Error::InvalidByteOrder(2)
+        );
+        assert_eq!(
+            read_wkb::<2>(&hex("0108000000")).unwrap_err(),
+            Error::UnsupportedGeometryType(8)
+        );
+        // LINESTRING with a huge count
+        assert_eq!(
+            read_wkb::<2>(&hex("0102000000FFFFFFFF")).unwrap_err(),
+            Error::UnexpectedEof
+        );
+        // MULTIPOINT containing a LINESTRING
+        assert_eq!(
+            read_wkb::<2>(&hex(
+                "010400000001000000010200000000000000000000000000000000000000"
+            ))
+            .unwrap_err(),
+            Error::UnexpectedGeometryType {
+                expected: code::POINT,
+                found: code::LINESTRING
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_nesting_depth() {
+        let nested = |depth: usize| {
+            let mut bytes = hex(&"010700000001000000".repeat(depth));
+            bytes.extend(hex("010700000000000000"));
+            bytes
+        };
+        assert!(read_wkb::<2>(&nested(63)).is_ok());
+        assert_eq!(
+            read_wkb::<2>(&nested(64)).unwrap_err(),
+            Error::NestingTooDeep
+        );
+        assert_eq!(
+            read_wkb::<2>(&nested(100_000)).unwrap_err(),
+            Error::NestingTooDeep
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use alloc::vec::Vec;
+
+use super::code;
+use crate::{
+    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle,
+    Point, Polygon, Triangle,
+};
+
+/// Encodes geometries into little-endian ISO WKB.
+///
+/// 2-dimensional coordinates are written as XY, 3-dimensional ones as XYZ,
+/// and 4-dimensional ones as XYZM. Lines are written as LineStrings.
+pub trait ToWkb {
+    /// Appends the WKB representation to `out`.
+    fn write_wkb(&self, out: &mut Vec<u8>);
+
+    /// Returns the WKB representation.
+    fn to_wkb(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_wkb(&mut out);
+        out
+    }
+}
+
+fn write_header<const D: usize>(out: &mut Vec<u8>, kind: u32) {
+    const { assert!(D >= 2, "WKB coordinates must have at least 2 dimensions") };
+    let dims_offset = match D {
+        0..=2 => 0,
+        3 => 1000,
+        _ => 3000,
+    };
+    out.push(1);
+    out.extend((kind + dims_offset).to_le_bytes());
+}
+
+fn write_count(out: &mut Vec<u8>, n: usize) {
+    out.extend((n as u32).to_le_bytes());
+}
+
+fn write_coords<const D: usize, N: CoordNum>(
+    out: &mut Vec<u8>,
+    coords: impl IntoIterator<Item = [N; D]>,
+) {
+    for coord in coords {
+        for v in coord.iter().take(4) {
+            out.extend(v.to_f64().unwrap().to_le_bytes());
+        }
+    }
+}
+
+fn write_rings<const D: usize, N: CoordNum>(out: &mut Vec<u8>, poly: &Polygon<'_, [N; D]>) {
+    if poly.raw_coords().is_empty() {
+        write_count(out, 0);
+        return;
+    }
+    write_count(out, poly.len());
+    for ring in poly.rings() {
+        write_count(out, ring.iter_closed().count());
+        write_coords(out, ring.iter_closed());
+    }
+}
+
+fn write_triangle<const D: usize, N: CoordNum>(out: &mut Vec<u8>, tri: &Triangle<[N; D]>) {
+    write_header::<D>(out, code::TRIANGLE);
+    write_count(out, 1);
+    write_count(out, 4);
+    write_coords(out, tri.iter_closed());
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for Point<[N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::POINT);
+        write_coords(out, [self.coord().clone()]);
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for MultiPoint<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::MULTIPOINT);
+        write_count(out, self.len());
+        for coord in self {
+            write_header::<D>(out, code::POINT);
+            write_coords(out, [coord]);
+        }
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for Line<[N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::LINESTRING);
+        write_count(out, 2);
+        write_coords(out, self.iter());
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for LineString<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::LINESTRING);
+        write_count(out, self.len());
+        write_coords(out, self.iter());
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for MultiLineString<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::MULTILINESTRING);
+        write_count(out, self.len());
+        for ls in self {
+            ls.write_wkb(out);
+        }
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for Polygon<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::POLYGON);
+        write_rings(out, self);
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for MultiPolygon<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::MULTIPOLYGON);
+        write_count(out, self.len());
+        for poly in self {
+            poly.write_wkb(out);
+        }
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for Triangle<[N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_triangle(out, self);
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for MultiTriangle<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        write_header::<D>(out, code::TIN);
+        write_count(out, self.len());
+        for tri in self {
+            write_triangle(out, &tri);
+        }
+    }
+}
+
+impl<const D: usize, N: CoordNum> ToWkb for Geometry<'_, [N; D]> {
+    fn write_wkb(&self, out: &mut Vec<u8>) {
+        match self {
+            Geometry::Point(geom) => geom.write_wkb(out),
+            Geometry::MultiPoint(geom) => geom.write_wkb(out),
+            Geometry::Line(geom) => geom.write_wkb(out),
+            Geometry::LineString(geom) => geom.write_wkb(out),
+            Geometry::MultiLineString(geom) => geom.write_wkb(out),
+            Geometry::Polygon(geom) => geom.write_wkb(out),
+            Geometry::MultiPolygon(geom) => geom.write_wkb(out),
+            Geometry::Triangle(geom) => geom.write_wkb(out),
+            Geometry::MultiTriangle(geom) => geom.write_wkb(out),
+            Geometry::GeometryCollection(geoms) => {
+                write_header::<D>(out, code::GEOMETRYCOLLECTION);
+                write_count(out, geoms.len());
+                for geom in geoms {
+                    geom.write_wkb(out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        wkb::read_wkb, Geometry2, Geometry3, LineString2, MultiLineString3, MultiPoint2,
+        MultiPolygon2, MultiTriangle3, Polygon2,
+    };
+    use alloc::vec;
+
+    #[test]
+    fn test_write_point() {
+        assert_eq!(
+            Point::new([1., 2.]).to_wkb(),
+            [
+                0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40
+            ]
+        );
+        // integer coordinates are written as f64
+        assert_eq!(
+            Point::new([1u16, 2]).to_wkb(),
+            Point::new([1., 2.]).to_wkb()
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        poly.add_ring([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.push(&poly);
+        mpoly.add_exterior([[4., 0.], [7., 0.], [7., 3.], [4., 3.]]);
+        mpoly.add_interior([[5., 1.], [6., 1.], [6., 2.], [5., 2.]]);
+        let mut mls = MultiLineString3::new();
+        mls.add_linestring([[0., 0., 1.], [5., 0., 2.]]);
+        mls.add_linestring([[1., 0., 3.], [5., 0., 4.], [5., 5., 5.]]);
+
+        let geoms2 = [
+            Geometry2::Point([1., 2.].into()),
+            Geometry2::MultiPoint(MultiPoint2::from_raw(vec![[1., 2.], [3., 4.]].into())),
+            Geometry2::LineString(LineString2::from_raw(vec![[1., 2.], [3., 4.]].into())),
+            Geometry2::Polygon(poly.clone()),
+            Geometry2::MultiPolygon(mpoly),
+            Geometry2::Triangle(Triangle::new([0., 0.], [1., 0.], [0., 1.])),
+            Geometry2::GeometryCollection(vec![
+                Geometry2::Polygon(poly),
+                Geometry2::GeometryCollection(vec![]),
+            ]),
+        ];
+        for geom in geoms2 {
+            let wkb = geom.to_wkb();
+            let decoded = read_wkb::<2>(&wkb).unwrap();
+            assert_eq!(decoded.to_wkb(), wkb);
+        }
+
+        let mut mtri = MultiTriangle3::new();
+        mtri.push(&Triangle::new([0., 0., 0.], [1., 0., 0.], [0., 1., 1.]));
+        mtri.push(&Triangle::new([1., 1., 1.], [1., 0., 0.], [0., 1., 1.]));
+        let geoms3 = [
+            Geometry3::MultiLineString(mls),
+            Geometry3::MultiTriangle(mtri),
+        ];
+        for geom in geoms3 {
+            let wkb = geom.to_wkb();
+            let decoded = read_wkb::<3>(&wkb).unwrap();
+            assert_eq!(decoded.to_wkb(), wkb);
+        }
+    }
+
+    #[test]
+    fn test_empty_members() {
+        let square = |out: &mut Vec<u8>, o: f64| {
+            write_count(out, 5);
+            write_coords(
+                out,
+                [[o, o], [o + 1., o], [o + 1., o + 1.], [o, o + 1.], [o, o]],
+            );
+        };
+
+        // empty rings are skipped
+        let mut wkb = Vec::new();
+        write_header::<2>(&mut wkb, code::POLYGON);
+        write_count(&mut wkb, 4);
+        write_count(&mut wkb, 0);
+        square(&mut wkb, 0.);
+        write_count(&mut wkb, 0);
+        square(&mut wkb, 0.2);
+        let Ok(Geometry2::Polygon(poly)) = read_wkb(&wkb) else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(poly.hole_indices(), [4]);
+        assert!(
+            Polygon2::try_from_raw(poly.raw_coords().into(), poly.hole_indices().into()).is_ok()
+        );
+        let wkb = poly.to_wkb();
+        assert_eq!(read_wkb::<2>(&wkb).unwrap().to_wkb(), wkb);
+
+        // empty polygons are skipped
+        let mut wkb = Vec::new();
+        write_header::<2>(&mut wkb, code::MULTIPOLYGON);
+        write_count(&mut wkb, 4);
+        write_header::<2>(&mut wkb, code::POLYGON);
+        write_count(&mut wkb, 0);
+        write_header::<2>(&mut wkb, code::POLYGON);
+        write_count(&mut wkb, 1);
+        square(&mut wkb, 0.);
+        write_header::<2>(&mut wkb, code::POLYGON);
+        write_count(&mut wkb, 1);
+        write_count(&mut wkb, 0);
+        write_header::<2>(&mut wkb, code::POLYGON);
+        write_count(&mut wkb, 1);
+        square(&mut wkb, 2.);
+        let Ok(Geometry2::MultiPolygon(mpoly)) = read_wkb(&wkb) else {
+            panic!("MultiPolygon is expected");
+        };
+        assert_eq!(mpoly.len(), 2);
+        assert_eq!(mpoly.coords_spans(), [4]);
+        assert_eq!(mpoly.holes_spans(), [0]);
+
+        // empty linestrings are skipped
+        let mut wkb = Vec::new();
+        write_header::<2>(&mut wkb, code::MULTILINESTRING);
+        write_count(&mut wkb, 3);
+        for n in [2, 0, 2] {
+            write_header::<2>(&mut wkb, code::LINESTRING);
+            write_count(&mut wkb, n);
+            write_coords(&mut wkb, [[1., 2.]].repeat(n));
+        }
+        let Ok(Geometry2::MultiLineString(mls)) = read_wkb(&wkb) else {
+            panic!("MultiLineString is expected");
+        };
+        assert_eq!(mls.coords_spans(), [2]);
+
+        // an empty triangle is NaN, and empty triangles in a TIN are skipped
+        let mut wkb = Vec::new();
+        write_header::<2>(&mut wkb, code::TRIANGLE);
+        write_count(&mut wkb, 0);
+        let Ok(Geometry2::Triangle(tri)) = read_wkb(&wkb) else {
+            panic!("Triangle is expected");
+        };
+        assert!(tri.iter().all(|c| c[0].is_nan() && c[1].is_nan()));
+
+        let mut wkb = Vec::new();
+        write_header::<2>(&mut wkb, code::TIN);
+        write_count(&mut wkb, 2);
+        write_header::<2>(&mut wkb, code::TRIANGLE);
+        write_count(&mut wkb, 0);
+        write_triangle(&mut wkb, &Triangle::new([0., 0.], [1., 0.], [0., 1.]));
+        let Ok(Geometry2::MultiTriangle(mtri)) = read_wkb(&wkb) else {
+            panic!("MultiTriangle is expected");
+        };
+        assert_eq!(mtri.len(), 1);
+        assert_eq!(mtri.raw_coords(), [[0., 0.], [1., 0.], [0., 1.]]);
+    }
+
+    #[test]
+    fn test_line() {
+        let wkb = Line::new([0., 0.], [1., 1.]).to_wkb();
+        let Ok(Geometry2::LineString(ls)) = read_wkb(&wkb) else {
+            panic!("LineString is expected");
+        };
+        assert_eq!(ls.raw_coords(), [[0., 0.], [1., 1.]]);
+    }
+}
@@ -0,0 +1,70 @@
+//! Native WKB (Well-Known Binary) encoding and decoding.
+//!
+//! Both ISO WKB and EWKB (PostGIS) flavors with XY, XYZ, XYM and XYZM coordinates are supported.
+//! When decoding into `[f64; D]`, the Z value is stored at index 2 and the M value at index 3
+//! (if `D` is large enough); missing values are filled with zero.
+
+mod decode;
+mod encode;
+
+pub use decode::read_wkb;
+pub use encode::ToWkb;
+
+use core::fmt;
+
+/// Errors reported when decoding WKB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input ended before the geometry was complete.
+    UnexpectedEof,
+    /// The byte order marker is neither `0` (big endian) nor `1` (little endian).
+    InvalidByteOrder(u8),
+    /// The geometry type code is not supported.
+    UnsupportedGeometryType(u32),
+    /// A member of a multi-geometry has an unexpected type.
+    UnexpectedGeometryType { expected: u32, found: u32 },
+    /// A triangle ring does not consist of three vertices (plus the closing one).
+    InvalidTriangle { len: usize },
+    /// GeometryCollections are nested too deeply.
+    NestingTooDeep,
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEof => write!(f, "unexpected end of WKB input"),
+            Error::InvalidByteOrder(b) => write!(f, "invalid WKB byte order: {}", b),
+            Error::UnsupportedGeometryType(t) => {
+                write!(f, "unsupported WKB geometry type: {}", t)
+            }
+            Error::UnexpectedGeometryType { expected, found } => write!(
+                f,
+                "unexpected WKB geometry type: expected {}, found {}",
+                expected, found
+            ),
+            Error::InvalidTriangle { len } => write!(
+                f,
+                "invalid WKB triangle: ring must have 4 vertices, found {}",
+                len
+            ),
+            Error::NestingTooDeep => write!(f, "WKB geometry collections are nested too deeply"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// WKB geometry type codes
+pub(crate) mod code {
+    pub const POINT: u32 = 1;
+    pub const LINESTRING: u32 = 2;
+    pub const POLYGON: u32 = 3;
+    pub const MULTIPOINT: u32 = 4;
+    pub const MULTILINESTRING: u32 = 5;
+    pub const MULTIPOLYGON: u32 = 6;
+    pub const GEOMETRYCOLLECTION: u32 = 7;
+    pub const TIN: u32 = 16;
+    pub const TRIANGLE: u32 = 17;
+}
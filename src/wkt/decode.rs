@@ -0,0 +1,586 @@
+use alloc::vec::Vec;
+
+use super::{Error, Result};
+use crate::{
+    Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle, Point, Polygon,
+    Triangle,
+};
+
+/// Maximum nesting depth of GeometryCollections
+const MAX_NESTING_DEPTH: usize = 64;
+
+/// Parses a WKT string into a geometry.
+///
+/// GeometryCollections nested more than 64 levels deep are rejected with [`Error::NestingTooDeep`].
+pub fn read_wkt<const D: usize>(s: &str) -> Result<Geometry<'static, [f64; D]>> {
+    let mut parser = Parser { s, pos: 0 };
+    let geom = parser.geometry(0)?;
+    parser.skip_ws();
+    match parser.peek() {
+        None => Ok(geom),
+        Some(found) => Err(Error::UnexpectedCharacter {
+            pos: parser.pos,
+            found,
+        }),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Triangle,
+    Tin,
+}
+
+const KINDS: [(&str, Kind); 9] = [
+    ("POINT", Kind::Point),
+    ("LINESTRING", Kind::LineString),
+    ("POLYGON", Kind::Polygon),
+    ("MULTIPOINT", Kind::MultiPoint),
+    ("MULTILINESTRING", Kind::MultiLineString),
+    ("MULTIPOLYGON", Kind::MultiPolygon),
+    ("GEOMETRYCOLLECTION", Kind::GeometryCollection),
+    ("TRIANGLE", Kind::Triangle),
+    ("TIN", Kind::Tin),
+];
+
+/// Dimensions given by the `Z`, `M` or `ZM` tag
+#[derive(Clone, Copy)]
+struct Dims {
+    tagged: bool,
+    z: bool,
+    m: bool,
+}
+
+impl Dims {
+    fn from_tag(tag: &str) -> Option<Self> {
+        let (z, m) = match tag {
+            "" => return Some(Self::untagged()),
+            t if t.eq_ignore_ascii_case("Z") => (true, false),
+            t if t.eq_ignore_ascii_case("M") => (false, true),
+            t if t.eq_ignore_ascii_case("ZM") => (true, true),
+            _ => return None,
+        };
+        Some(Self { tagged: true, z, m })
+    }
+
+    fn untagged() -> Self {
+        Self {
+            tagged: false,
+            z: false,
+            m: false,
+        }
+    }
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn skip_ws(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        self.skip_ws();
+        match self.peek() {
+            Some(found) if found == c => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            Some(found) => Err(Error::UnexpectedCharacter {
+                pos: self.pos,
+                found,
+            }),
+            None => Err(Error::UnexpectedEof),
+        }
+    }
+
+    /// Consumes `,` and returns `true`, or consumes `)` and returns `false`.
+    fn next_item(&mut self) -> Result<bool> {
+        self.skip_ws();
+        match self.peek() {
+            Some(',') => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(')') => {
+                self.pos += 1;
+                Ok(false)
+            }
+            Some(found) => Err(Error::UnexpectedCharacter {
+                pos: self.pos,
+                found,
+            }),
+            None => Err(Error::UnexpectedEof),
+        }
+    }
+
+    fn word(&mut self) -> &str {
+        self.skip_ws();
+        let rest = &self.s[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consumes `EMPTY` and returns `true`, or consumes `(` and returns `false`.
+    fn empty_or_open(&mut self) -> Result<bool> {
+        self.skip_ws();
+        let start = self.pos;
+        if self.word().eq_ignore_ascii_case("EMPTY") {
+            return Ok(true);
+        }
+        self.pos = start;
+        self.expect('(')?;
+        Ok(false)
+    }
+
+    fn number(&mut self) -> Result<Option<f64>> {
+        self.skip_ws();
+        let rest = &self.s[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Ok(None);
+        }
+        let v = rest[..len]
+            .parse()
+            .map_err(|_| Error::InvalidNumber { pos: self.pos })?;
+        self.pos += len;
+        Ok(Some(v))
+    }
+
+    fn coord<const D: usize>(&mut self, dims: Dims) -> Result<[f64; D]> {
+        self.skip_ws();
+        let start = self.pos;
+        let mut values = [0.; 4];
+        let mut n = 0;
+        while let Some(v) = self.number()? {
+            if n == 4 {
+                return Err(Error::InvalidDimensions { pos: start });
+            }
+            values[n] = v;
+            n += 1;
+        }
+        let (z, m) = match (dims.tagged, n) {
+            (false, 2) => (None, None),
+            (false, 3) => (Some(values[2]), None),
+            (false, 4) => (Some(values[2]), Some(values[3])),
+            (true, n) if n == 2 + dims.z as usize + dims.m as usize => match (dims.z, dims.m) {
+                (true, true) => (Some(values[2]), Some(values[3])),
+                (true, false) => (Some(values[2]), None),
+                (false, true) => (None, Some(values[2])),
+                (false, false) => (None, None),
+            },
+            _ => return Err(Error::InvalidDimensions { pos: start }),
+        };
+        let mut coord = [0.; D];
+        coord[0] = values[0];
+        coord[1] = values[1];
+        if let (Some(z), true) = (z, D >= 3) {
+            coord[2] = z;
+        }
+        if let (Some(m), true) = (m, D >= 4) {
+            coord[3] = m;
+        }
+        Ok(coord)
+    }
+
+    /// Parses `EMPTY` or `(x y, x y, ...)`
+    fn coord_seq<const D: usize>(&mut self, dims: Dims, out: &mut Vec<[f64; D]>) -> Result<()> {
+        if self.empty_or_open()? {
+            return Ok(());
+        }
+        loop {
+            out.push(self.coord(dims)?);
+            if !self.next_item()? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Parses `EMPTY` or `((x y, ...), (x y, ...), ...)`
+    ///
+    /// Empty rings are skipped, so `add_ring` is called with consecutive indices of non-empty rings.
+    fn rings<const D: usize>(
+        &mut self,
+        dims: Dims,
+        mut add_ring: impl FnMut(usize, &mut Vec<[f64; D]>),
+    ) -> Result<()> {
+        if self.empty_or_open()? {
+            return Ok(());
+        }
+        let mut buf = Vec::new();
+        let mut i = 0;
+        loop {
+            self.coord_seq(dims, &mut buf)?;
+            if !buf.is_empty() {
+                add_ring(i, &mut buf);
+                buf.clear();
+                i += 1;
+            }
+            if !self.next_item()? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn triangle<const D: usize>(&mut self, dims: Dims) -> Result<Option<Triangle<[f64; D]>>> {
+        self.skip_ws();
+        let start = self.pos;
+        let mut rings = Vec::new();
+        self.rings(dims, |_, ring: &mut Vec<[f64; D]>| rings.push(ring.clone()))?;
+        match rings.as_slice() {
+            [] => Ok(None),
+            [ring] if ring.len() == 4 => Ok(Some(Triangle::new(ring[0], ring[1], ring[2]))),
+            _ => Err(Error::InvalidTriangle { pos: start }),
+        }
+    }
+
+    fn geometry<const D: usize>(&mut self, depth: usize) -> Result<Geometry<'static, [f64; D]>> {
+        self.skip_ws();
+        let start = self.pos;
+        let word = self.word();
+        if word.is_empty() {
+            return match self.peek() {
+                Some(found) => Err(Error::UnexpectedCharacter { pos: start, found }),
+                None => Err(Error::UnexpectedEof),
+            };
+        }
+
+        // the dimension tag may be attached to the type name (e.g. `POINTZ`)
+        let found = ["", "ZM", "Z", "M"].iter().find_map(|suffix| {
+            let name = word.get(..word.len().checked_sub(suffix.len())?)?;
+            let tag = &word[name.len()..];
+            let &(_, kind) = KINDS.iter().find(|(n, _)| n.eq_ignore_ascii_case(name))?;
+            Some((kind, Dims::from_tag(tag)?))
+        });
+        let Some((kind, mut dims)) = found else {
+            return Err(Error::UnsupportedGeometryType { pos: start });
+        };
+        if !dims.tagged {
+            let tag_start = self.pos;
+            match Dims::from_tag(self.word()) {
+                Some(d) if d.tagged => dims = d,
+                _ => self.pos = tag_start,
+            }
+        }
+
+        match kind {
+            Kind::Point => {
+                let mut coords = Vec::with_capacity(1);
+                self.coord_seq(dims, &mut coords)?;
+                match coords.as_slice() {
+                    [] => Ok(Geometry::Point(Point::new([f64::NAN; D]))),
+                    [coord] => Ok(Geometry::Point(Point::new(*coord))),
+                    _ => Err(Error::InvalidDimensions { pos: start }),
+                }
+            }
+            Kind::LineString => {
+                let mut coords = Vec::new();
+                self.coord_seq(dims, &mut coords)?;
+                Ok(Geometry::LineString(LineString::from_raw(coords.into())))
+            }
+            Kind::Polygon => {
+                let mut poly = Polygon::new();
+                self.rings(dims, |_, ring| poly.add_ring(ring.drain(..)))?;
+                Ok(Geometry::Polygon(poly))
+            }
+            Kind::MultiPoint => {
+                let mut mpoint = MultiPoint::new();
+                if !self.empty_or_open()? {
+                    loop {
+                        // both `MULTIPOINT(1 2, 3 4)` and `MULTIPOINT((1 2), (3 4))` are accepted
+                        self.skip_ws();
+                        if matches!(self.peek(), Some('(' | 'E' | 'e')) {
+                            let mut coords = Vec::with_capacity(1);
+                            self.coord_seq(dims, &mut coords)?;
+                            mpoint.extend(coords);
+                        } else {
+                            mpoint.push(self.coord(dims)?);
+                        }
+                        if !self.next_item()? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Geometry::MultiPoint(mpoint))
+            }
+            Kind::MultiLineString => {
+                let mut mls = MultiLineString::new();
+                self.rings(dims, |_, ls| mls.add_linestring(ls.drain(..)))?;
+                Ok(Geometry::MultiLineString(mls))
+            }
+            Kind::MultiPolygon => {
+                let mut mpoly = MultiPolygon::new();
+                if !self.empty_or_open()? {
+                    loop {
+                        self.rings(dims, |i, ring| match i {
+                            0 => mpoly.add_exterior(ring.drain(..)),
+                            _ => mpoly.add_interior(ring.drain(..)),
+                        })?;
+                        if !self.next_item()? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Geometry::MultiPolygon(mpoly))
+            }
+            Kind::GeometryCollection => {
+                if depth >= MAX_NESTING_DEPTH {
+                    return Err(Error::NestingTooDeep { pos: start });
+                }
+                let mut geoms = Vec::new();
+                if !self.empty_or_open()? {
+                    loop {
+                        geoms.push(self.geometry(depth + 1)?);
+                        if !self.next_item()? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Geometry::GeometryCollection(geoms))
+            }
+            Kind::Triangle => match self.triangle(dims)? {
+                Some(tri) => Ok(Geometry::Triangle(tri)),
+                None => Ok(Geometry::Triangle(Triangle::new(
+                    [f64::NAN; D],
+                    [f64::NAN; D],
+                    [f64::NAN; D],
+                ))),
+            },
+            Kind::Tin => {
+                let mut mtri = MultiTriangle::new();
+                if !self.empty_or_open()? {
+                    loop {
+                        if let Some(tri) = self.triangle(dims)? {
+                            mtri.push(&tri);
+                        }
+                        if !self.next_item()? {
+                            break;
+                        }
+                    }
+                }
+                Ok(Geometry::MultiTriangle(mtri))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Geometry2, Geometry3, Polygon2};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_read_point() {
+        let Ok(Geometry2::Point(p)) = read_wkt("POINT(1 2)") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2.]);
+
+        let Ok(Geometry3::Point(p)) = read_wkt("  point ( -1.5e2   2 ) ") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[-150., 2., 0.]);
+
+        let Ok(Geometry3::Point(p)) = read_wkt("POINT Z (1 2 3)") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2., 3.]);
+
+        let Ok(Geometry::<[f64; 4]>::Point(p)) = read_wkt("POINTM(1 2 4)") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2., 0., 4.]);
+
+        let Ok(Geometry::<[f64; 4]>::Point(p)) = read_wkt("POINT ZM (1 2 3 4)") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2., 3., 4.]);
+
+        // untagged 3D coordinates
+        let Ok(Geometry2::Point(p)) = read_wkt("POINT(1 2 3)") else {
+            panic!("Point is expected");
+        };
+        assert_eq!(p.coord(), &[1., 2.]);
+
+        let Ok(Geometry2::Point(p)) = read_wkt("POINT EMPTY") else {
+            panic!("Point is expected");
+        };
+        assert!(p.coord()[0].is_nan());
+    }
+
+    #[test]
+    fn test_read_geometries() {
+        let Ok(Geometry2::Polygon(poly)) =
+            read_wkt("POLYGON((0 0,5 0,5 5,0 5,0 0),(1 1,1 2,2 2,2 1,1 1))")
+        else {
+            panic!("Polygon is expected");
+        };
+        assert_eq!(poly.len(), 2);
+        assert_eq!(poly.exterior().len(), 4);
+        assert_eq!(poly.hole_indices(), [4]);
+
+        let Ok(Geometry2::MultiPoint(mp)) = read_wkt("MULTIPOINT((1 2),(3 4))") else {
+            panic!("MultiPoint is expected");
+        };
+        assert_eq!(mp.raw_coords(), [[1., 2.], [3., 4.]]);
+        let Ok(Geometry2::MultiPoint(mp)) = read_wkt("MULTIPOINT(1 2, 3 4)") else {
+            panic!("MultiPoint is expected");
+        };
+        assert_eq!(mp.raw_coords(), [[1., 2.], [3., 4.]]);
+
+        let Ok(Geometry3::MultiLineString(mls)) =
+            read_wkt("MULTILINESTRING Z ((0 0 0,1 1 1),(2 2 2,3 3 3,4 4 4))")
+        else {
+            panic!("MultiLineString is expected");
+        };
+        assert_eq!(mls.len(), 2);
+
+        let Ok(Geometry2::MultiPolygon(mpoly)) =
+            read_wkt("MULTIPOLYGON(((0 0,5 0,5 5,0 0)),((4 0,7 0,7 3,4 0),(5 1,6 1,6 2,5 1)))")
+        else {
+            panic!("MultiPolygon is expected");
+        };
+        assert_eq!(mpoly.len(), 2);
+        assert_eq!(mpoly.get(1).interiors().count(), 1);
+
+        let Ok(Geometry2::MultiTriangle(mtri)) =
+            read_wkt("TIN(((0 0,1 0,0 1,0 0)),((1 1,1 0,0 1,1 1)))")
+        else {
+            panic!("MultiTriangle is expected");
+        };
+        assert_eq!(mtri.len(), 2);
+
+        let Ok(Geometry2::GeometryCollection(geoms)) =
+            read_wkt("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY,GEOMETRYCOLLECTION EMPTY)")
+        else {
+            panic!("GeometryCollection is expected");
+        };
+        assert_eq!(geoms.len(), 3);
+
+        let Ok(Geometry2::MultiPolygon(mpoly)) = read_wkt("MULTIPOLYGON EMPTY") else {
+            panic!("MultiPolygon is expected");
+        };
+        assert!(mpoly.is_empty());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for wkt in [
+            "POINT(1 2)",
+            "POINT EMPTY",
+            "LINESTRING(0 0,1.5 1,2 -2)",
+            "LINESTRING EMPTY",
+            "POLYGON((0 0,5 0,5 5,0 5,0 0),(1 1,1 2,2 2,2 1,1 1))",
+            "MULTIPOINT(1 2,3 4)",
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+            "MULTIPOLYGON(((0 0,5 0,5 5,0 0)),((4 0,7 0,7 3,4 0),(5 1,6 1,6 2,5 1)))",
+            "TRIANGLE((0 0,1 0,0 1,0 0))",
+            "TIN(((0 0,1 0,0 1,0 0)),((1 1,1 0,0 1,1 1)))",
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))",
+        ] {
+            assert_eq!(read_wkt::<2>(wkt).unwrap().to_string(), wkt);
+        }
+
+        // EMPTY members are skipped
+        for (wkt, expected) in [
+            ("POLYGON((0 0,1 0,1 1),EMPTY)", "POLYGON((0 0,1 0,1 1,0 0))"),
+            ("POLYGON(EMPTY,(0 0,1 0,1 1))", "POLYGON((0 0,1 0,1 1,0 0))"),
+            (
+                "MULTILINESTRING((0 0,1 1),EMPTY,(2 2,3 3))",
+                "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+            ),
+            (
+                "MULTIPOLYGON(EMPTY,((0 0,1 0,1 1)),(EMPTY,(2 2,3 2,3 3),EMPTY))",
+                "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((2 2,3 2,3 3,2 2)))",
+            ),
+            ("TIN(EMPTY,((0 0,1 0,0 1,0 0)))", "TIN(((0 0,1 0,0 1,0 0)))"),
+        ] {
+            let displayed = read_wkt::<2>(wkt).unwrap().to_string();
+            assert_eq!(displayed, expected);
+            assert_eq!(read_wkt::<2>(&displayed).unwrap().to_string(), expected);
+        }
+        let Ok(Geometry2::Polygon(poly)) = read_wkt("POLYGON((0 0,1 0,1 1),EMPTY)") else {
+            panic!("Polygon is expected");
+        };
+        assert!(
+            Polygon2::try_from_raw(poly.raw_coords().into(), poly.hole_indices().into()).is_ok()
+        );
+        for wkt in [
+            "POINT Z (1 2 3)",
+            "POLYGON Z ((0 0 1,5 0 1,5 5 1,0 0 1))",
+            "GEOMETRYCOLLECTION Z (POINT Z (1 2 3))",
+        ] {
+            assert_eq!(read_wkt::<3>(wkt).unwrap().to_string(), wkt);
+        }
+    }
+
+    #[test]
+    fn test_read_nesting_depth() {
+        let nested = |depth: usize| {
+            "GEOMETRYCOLLECTION(".repeat(depth) + "GEOMETRYCOLLECTION EMPTY" + &")".repeat(depth)
+        };
+        assert!(read_wkt::<2>(&nested(63)).is_ok());
+        assert_eq!(
+            read_wkt::<2>(&nested(64)).unwrap_err(),
+            Error::NestingTooDeep { pos: 64 * 19 }
+        );
+        assert!(matches!(
+            read_wkt::<2>(&"GEOMETRYCOLLECTION(".repeat(100_000)),
+            Err(Error::NestingTooDeep { .. })
+        ));
+    }
+
+    #[test]
+    fn test_read_errors() {
+        assert_eq!(read_wkt::<2>("").unwrap_err(), Error::UnexpectedEof);
+        assert_eq!(
+            read_wkt::<2>("CIRCLE(1 2)").unwrap_err(),
+            Error::UnsupportedGeometryType { pos: 0 }
+        );
+        assert_eq!(
+            read_wkt::<2>("POINT(1 2").unwrap_err(),
+            Error::UnexpectedEof
+        );
+        assert_eq!(
+            read_wkt::<2>("POINT(1 x)").unwrap_err(),
+            Error::InvalidNumber { pos: 8 }
+        );
+        assert_eq!(
+            read_wkt::<2>("POINT Z (1 2)").unwrap_err(),
+            Error::InvalidDimensions { pos: 9 }
+        );
+        assert_eq!(
+            read_wkt::<2>("POINT(1 2) x").unwrap_err(),
+            Error::UnexpectedCharacter {
+                pos: 11,
+                found: 'x'
+            }
+        );
+        assert_eq!(
+            read_wkt::<2>("TRIANGLE((0 0,1 0,0 0))").unwrap_err(),
+            Error::InvalidTriangle { pos: 8 }
+        );
+    }
+}
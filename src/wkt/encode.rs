@@ -0,0 +1,302 @@
+use core::fmt::{self, Display, Write};
+
+use crate::{
+    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, MultiTriangle,
+    Point, Polygon, Triangle,
+};
+
+/// Writes the type name with its dimension tag, followed by ` EMPTY` if `empty` is true.
+///
+/// Returns `false` if the geometry is empty and nothing else needs to be written.
+fn write_header<const D: usize>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    empty: bool,
+) -> Result<bool, fmt::Error> {
+    f.write_str(name)?;
+    match D {
+        0..=2 => {}
+        3 => f.write_str(" Z")?,
+        _ => f.write_str(" ZM")?,
+    }
+    if empty {
+        f.write_str(" EMPTY")?;
+    } else if D >= 3 {
+        f.write_char(' ')?;
+    }
+    Ok(!empty)
+}
+
+fn write_coord<const D: usize, N: CoordNum>(
+    f: &mut fmt::Formatter<'_>,
+    coord: &[N; D],
+) -> fmt::Result {
+    for (i, v) in coord.iter().take(4).enumerate() {
+        if i > 0 {
+            f.write_char(' ')?;
+        }
+        write!(f, "{}", v.to_f64().unwrap())?;
+    }
+    Ok(())
+}
+
+/// Writes `(x y, x y, ...)`, or `EMPTY` if there are no coordinates.
+fn write_coords<const D: usize, N: CoordNum>(
+    f: &mut fmt::Formatter<'_>,
+    coords: impl IntoIterator<Item = [N; D]>,
+) -> fmt::Result {
+    let mut coords = coords.into_iter().peekable();
+    if coords.peek().is_none() {
+        return f.write_str("EMPTY");
+    }
+    f.write_char('(')?;
+    for (i, coord) in coords.enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_coord(f, &coord)?;
+    }
+    f.write_char(')')
+}
+
+fn write_rings<const D: usize, N: CoordNum>(
+    f: &mut fmt::Formatter<'_>,
+    poly: &Polygon<'_, [N; D]>,
+) -> fmt::Result {
+    f.write_char('(')?;
+    for (i, ring) in poly.rings().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write_coords(f, ring.iter_closed())?;
+    }
+    f.write_char(')')
+}
+
+fn write_triangle_rings<const D: usize, N: CoordNum>(
+    f: &mut fmt::Formatter<'_>,
+    tri: &Triangle<[N; D]>,
+) -> fmt::Result {
+    f.write_char('(')?;
+    write_coords(f, tri.iter_closed())?;
+    f.write_char(')')
+}
+
+impl<const D: usize, N: CoordNum> Display for Point<[N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let empty = self
+            .coord()
+            .iter()
+            .all(|v| v.to_f64().is_none_or(f64::is_nan));
+        if write_header::<D>(f, "POINT", empty)? {
+            write_coords(f, [self.coord().clone()])?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for MultiPoint<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "MULTIPOINT", self.is_empty())? {
+            write_coords(f, self)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for Line<[N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_header::<D>(f, "LINESTRING", false)?;
+        write_coords(f, self.iter())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for LineString<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "LINESTRING", self.is_empty())? {
+            write_coords(f, self.iter())?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for MultiLineString<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "MULTILINESTRING", self.is_empty())? {
+            f.write_char('(')?;
+            for (i, ls) in self.iter().enumerate() {
+                if i > 0 {
+                    f.write_char(',')?;
+                }
+                write_coords(f, ls.iter())?;
+            }
+            f.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for Polygon<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "POLYGON", self.raw_coords().is_empty())? {
+            write_rings(f, self)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for MultiPolygon<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "MULTIPOLYGON", self.is_empty())? {
+            f.write_char('(')?;
+            for (i, poly) in self.iter().enumerate() {
+                if i > 0 {
+                    f.write_char(',')?;
+                }
+                write_rings(f, &poly)?;
+            }
+            f.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for Triangle<[N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_header::<D>(f, "TRIANGLE", false)?;
+        write_triangle_rings(f, self)
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for MultiTriangle<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if write_header::<D>(f, "TIN", self.is_empty())? {
+            f.write_char('(')?;
+            for (i, tri) in self.iter().enumerate() {
+                if i > 0 {
+                    f.write_char(',')?;
+                }
+                write_triangle_rings(f, &tri)?;
+            }
+            f.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+impl<const D: usize, N: CoordNum> Display for Geometry<'_, [N; D]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Geometry::Point(geom) => geom.fmt(f),
+            Geometry::MultiPoint(geom) => geom.fmt(f),
+            Geometry::Line(geom) => geom.fmt(f),
+            Geometry::LineString(geom) => geom.fmt(f),
+            Geometry::MultiLineString(geom) => geom.fmt(f),
+            Geometry::Polygon(geom) => geom.fmt(f),
+            Geometry::MultiPolygon(geom) => geom.fmt(f),
+            Geometry::Triangle(geom) => geom.fmt(f),
+            Geometry::MultiTriangle(geom) => geom.fmt(f),
+            Geometry::GeometryCollection(geoms) => {
+                if write_header::<D>(f, "GEOMETRYCOLLECTION", geoms.is_empty())? {
+                    f.write_char('(')?;
+                    for (i, geom) in geoms.iter().enumerate() {
+                        if i > 0 {
+                            f.write_char(',')?;
+                        }
+                        geom.fmt(f)?;
+                    }
+                    f.write_char(')')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wkt::read_wkt;
+    use crate::{
+        Line2, MultiLineString2, MultiPoint3, MultiPolygon2, MultiTriangle2, Polygon2, Polygon3,
+    };
+    use alloc::{format, string::ToString, vec};
+
+    #[test]
+    fn test_display_point() {
+        assert_eq!(Point::new([1., 2.]).to_string(), "POINT(1 2)");
+        assert_eq!(Point::new([1.5, -2., 3.]).to_string(), "POINT Z (1.5 -2 3)");
+        assert_eq!(
+            Point::new([1., 2., 3., 4.]).to_string(),
+            "POINT ZM (1 2 3 4)"
+        );
+        assert_eq!(Point::new([1u16, 2]).to_string(), "POINT(1 2)");
+        assert_eq!(Point::new([f64::NAN; 2]).to_string(), "POINT EMPTY");
+    }
+
+    #[test]
+    fn test_display_geometries() {
+        let mut poly = Polygon2::new();
+        assert_eq!(poly.to_string(), "POLYGON EMPTY");
+        poly.add_ring([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        poly.add_ring([[1., 1.], [1., 2.], [2., 2.], [2., 1.]]);
+        assert_eq!(
+            poly.to_string(),
+            "POLYGON((0 0,5 0,5 5,0 5,0 0),(1 1,1 2,2 2,2 1,1 1))"
+        );
+
+        let mut mpoly = MultiPolygon2::new();
+        assert_eq!(mpoly.to_string(), "MULTIPOLYGON EMPTY");
+        mpoly.push(&poly);
+        mpoly.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        assert_eq!(
+            mpoly.to_string(),
+            "MULTIPOLYGON(((0 0,5 0,5 5,0 5,0 0),(1 1,1 2,2 2,2 1,1 1)),((6 0,7 0,7 1,6 0)))"
+        );
+
+        let mut poly3 = Polygon3::new();
+        poly3.add_ring([[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]]);
+        assert_eq!(poly3.to_string(), "POLYGON Z ((0 0 1,1 0 1,0 1 1,0 0 1))");
+
+        let mut mpoint = MultiPoint3::new();
+        assert_eq!(mpoint.to_string(), "MULTIPOINT Z EMPTY");
+        mpoint.extend([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(mpoint.to_string(), "MULTIPOINT Z (1 2 3,4 5 6)");
+
+        let mut mls = MultiLineString2::new();
+        mls.add_linestring([[0., 0.], [1., 1.]]);
+        mls.add_linestring([[2., 2.], [3., 3.]]);
+        assert_eq!(mls.to_string(), "MULTILINESTRING((0 0,1 1),(2 2,3 3))");
+
+        // empty members are written as EMPTY, never as `()`
+        mls.add_linestring([]);
+        assert_eq!(
+            mls.to_string(),
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3),EMPTY)"
+        );
+        let poly =
+            Polygon2::from_raw_unchecked(vec![[0., 0.], [1., 0.], [1., 1.]].into(), vec![3].into());
+        assert_eq!(poly.to_string(), "POLYGON((0 0,1 0,1 1,0 0),EMPTY)");
+        assert!(read_wkt::<2>(&poly.to_string()).is_ok());
+        assert!(read_wkt::<2>(&mls.to_string()).is_ok());
+
+        let line = Line2::new([0., 0.], [1., 1.]);
+        assert_eq!(line.to_string(), "LINESTRING(0 0,1 1)");
+
+        let tri = Triangle::new([0., 0.], [1., 0.], [0., 1.]);
+        assert_eq!(tri.to_string(), "TRIANGLE((0 0,1 0,0 1,0 0))");
+        let mut mtri = MultiTriangle2::new();
+        mtri.push(&tri);
+        assert_eq!(mtri.to_string(), "TIN(((0 0,1 0,0 1,0 0)))");
+
+        let geom = Geometry::GeometryCollection(vec![
+            Geometry::Point(Point::new([1., 2.])),
+            Geometry::Line(line),
+            Geometry::GeometryCollection(vec![]),
+        ]);
+        assert_eq!(
+            format!("{}", geom),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1),GEOMETRYCOLLECTION EMPTY)"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Native WKT (Well-Known Text) parsing and writing.
+//!
+//! WKT is written through the [`core::fmt::Display`] implementations of the geometry types,
+//! e.g. `POINT(1 2)` or `POLYGON Z ((0 0 0,1 0 0,0 1 0,0 0 0))`.
+//! 3-dimensional coordinates are written as Z, and 4-dimensional ones as ZM.
+//!
+//! When parsing into `[f64; D]`, the Z value is stored at index 2 and the M value at index 3
+//! (if `D` is large enough); missing values are filled with zero. `POINT EMPTY` is represented
+//! by a Point with NaN coordinates, as in WKB.
+
+mod decode;
+mod encode;
+
+pub use decode::read_wkt;
+
+use core::fmt;
+
+/// Errors reported when parsing WKT.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input ended before the geometry was complete.
+    UnexpectedEof,
+    /// An unexpected character was found at the given byte position.
+    UnexpectedCharacter { pos: usize, found: char },
+    /// A number could not be parsed at the given byte position.
+    InvalidNumber { pos: usize },
+    /// The geometry type at the given byte position is unknown or not supported.
+    UnsupportedGeometryType { pos: usize },
+    /// A coordinate at the given byte position has too few or too many values.
+    InvalidDimensions { pos: usize },
+    /// A triangle ring at the given byte position does not consist of three vertices (plus the closing one).
+    InvalidTriangle { pos: usize },
+    /// GeometryCollections are nested too deeply at the given byte position.
+    NestingTooDeep { pos: usize },
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEof => write!(f, "unexpected end of WKT input"),
+            Error::UnexpectedCharacter { pos, found } => {
+                write!(f, "unexpected character {:?} at position {}", found, pos)
+            }
+            Error::InvalidNumber { pos } => write!(f, "invalid number at position {}", pos),
+            Error::UnsupportedGeometryType { pos } => {
+                write!(f, "unsupported geometry type at position {}", pos)
+            }
+            Error::InvalidDimensions { pos } => {
+                write!(f, "invalid number of coordinate values at position {}", pos)
+            }
+            Error::InvalidTriangle { pos } => write!(
+                f,
+                "invalid triangle at position {}: ring must have 4 vertices",
+                pos
+            ),
+            Error::NestingTooDeep { pos } => write!(
+                f,
+                "geometry collections are nested too deeply at position {}",
+                pos
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -1,10 +1,11 @@
 //! Testing mutual conversion between geo_types and our MultiPolygon
 use flatgeom::{
-    geozero::ToFlatgeom, Geometry2, Geometry3, LineString2, LineString3, MultiLineString2,
-    MultiLineString3, MultiPoint2, MultiPoint3, MultiPolygon2, MultiPolygon3, Polygon2, Polygon3,
+    geozero::ToFlatgeom, Geometry2, Geometry3, Line2, Line3, LineString2, LineString3,
+    MultiLineString2, MultiLineString3, MultiPoint2, MultiPoint3, MultiPolygon2, MultiPolygon3,
+    MultiTriangle3, Point2, Point3, Polygon2, Polygon3, Triangle2,
 };
 
-use geozero::ToGeo;
+use geozero::{ToGeo, ToJson, ToWkt};
 
 #[test]
 fn multipolygon() {
@@ -348,3 +349,171 @@ fn geometry_collection() {
         _ => panic!("GeometryCollection is expected"),
     }
 }
+
+#[test]
+fn writer_unbalanced_events() {
+    use flatgeom::geozero::writer::FlatgeomWriter;
+    use geozero::GeomProcessor;
+
+    let mut writer = FlatgeomWriter::<2>::new();
+    assert!(writer.geometrycollection_end(0).is_err());
+
+    let mut writer = FlatgeomWriter::<2>::new();
+    assert!(writer.multipolygon_end(0).is_err());
+}
+
+#[test]
+fn writer_empty_point() {
+    use flatgeom::geozero::writer::FlatgeomWriter;
+    use geozero::GeomProcessor;
+
+    // empty points are read as NaN coordinates, as the WKT, WKB and GeoJSON readers do
+    let mut writer = FlatgeomWriter::<2>::new();
+    writer.point_begin(0).unwrap();
+    writer.point_end(0).unwrap();
+    let Some(Geometry2::Point(point)) = writer.take_geometry() else {
+        panic!("Point is expected");
+    };
+    assert!(point.coord().iter().all(|v| v.is_nan()));
+
+    let wkt = geozero::wkt::Wkt("POINT EMPTY");
+    let Ok(Geometry2::Point(point)) = wkt.to_flatgeom() else {
+        panic!("Point is expected");
+    };
+    assert!(point.coord().iter().all(|v| v.is_nan()));
+    assert_eq!(point.to_string(), "POINT EMPTY");
+    assert_eq!(point.to_wkt().unwrap(), "POINT EMPTY");
+}
+
+#[test]
+fn point() {
+    let point = Point2::new([1., 2.]);
+
+    // Conversion
+    let Ok(geo) = point.to_geo() else {
+        panic!("Conversion failed");
+    };
+    match &geo {
+        geo_types::Geometry::Point(geo_point) => {
+            assert_eq!(geo_point.x_y(), (1., 2.));
+        }
+        _ => panic!("Geometry type must be Point"),
+    }
+
+    // Inversion
+    let Ok(flat): geozero::error::Result<Geometry2> = geo.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::Point(point) => {
+            assert_eq!(point.coord(), &[1., 2.]);
+        }
+        _ => panic!("Point is expected"),
+    }
+}
+
+#[test]
+fn point3d() {
+    let point = Point3::new([1., 2., 3.]);
+    let Ok(flat): geozero::error::Result<Geometry3> = point.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::Point(point) => {
+            assert_eq!(point.coord(), &[1., 2., 3.]);
+        }
+        _ => panic!("Point is expected"),
+    }
+}
+
+#[test]
+fn point_geojson() {
+    let geojson = geozero::geojson::GeoJson(r#"{"type": "Point", "coordinates": [1.0, 2.0]}"#);
+    let Ok(flat): geozero::error::Result<Geometry2> = geojson.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::Point(point) => {
+            assert_eq!(point.coord(), &[1., 2.]);
+        }
+        _ => panic!("Point is expected"),
+    }
+
+    let Ok(json) = flat.to_json() else {
+        panic!("Conversion failed");
+    };
+    assert_eq!(json, r#"{"type": "Point", "coordinates": [1,2]}"#);
+}
+
+#[test]
+fn line() {
+    let line = Line2::new([0., 0.], [5., 5.]);
+
+    // A Line is converted to a LineString with two vertices
+    let Ok(geo) = line.to_geo() else {
+        panic!("Conversion failed");
+    };
+    match &geo {
+        geo_types::Geometry::LineString(geo_ls) => {
+            assert_eq!(geo_ls.0.len(), 2);
+        }
+        _ => panic!("Geometry type must be LineString"),
+    }
+
+    let geom = Geometry3::Line(Line3::new([0., 0., 0.], [5., 5., 5.]));
+    let Ok(flat): geozero::error::Result<Geometry3> = geom.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::LineString(ls) => {
+            assert_eq!(ls.raw_coords(), [[0., 0., 0.], [5., 5., 5.]]);
+        }
+        _ => panic!("LineString is expected"),
+    }
+}
+
+#[test]
+fn triangle() {
+    let tri = Triangle2::new([0., 0.], [1., 0.], [0., 1.]);
+    let Ok(wkt) = tri.to_wkt() else {
+        panic!("Conversion failed");
+    };
+    assert_eq!(wkt, "TRIANGLE((0 0,1 0,0 1,0 0))");
+
+    let Ok(flat): geozero::error::Result<Geometry2> = tri.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::Triangle(t) => {
+            assert_eq!(t, &tri);
+        }
+        _ => panic!("Triangle is expected"),
+    }
+}
+
+#[test]
+fn multitriangle() {
+    let mut mtri = MultiTriangle3::new();
+    let a = mtri.push_vertex([0., 0., 0.]);
+    let b = mtri.push_vertex([1., 0., 0.]);
+    let c = mtri.push_vertex([0., 1., 0.]);
+    let d = mtri.push_vertex([1., 1., 1.]);
+    mtri.push_indices([a, b, c]);
+    mtri.push_indices([c, b, d]);
+
+    let Ok(wkt) = mtri.to_wkt() else {
+        panic!("Conversion failed");
+    };
+    assert_eq!(wkt, "TIN(((0 0,1 0,0 1,0 0)),((0 1,1 0,1 1,0 1)))");
+
+    let Ok(flat): geozero::error::Result<Geometry3> = mtri.to_flatgeom() else {
+        panic!("Conversion failed");
+    };
+    match &flat {
+        flatgeom::Geometry::MultiTriangle(t) => {
+            assert_eq!(t.len(), 2);
+            assert_eq!(t.get(1), mtri.get(1));
+        }
+        _ => panic!("MultiTriangle is expected"),
+    }
+}
@@ -1,7 +1,8 @@
 #[cfg(feature = "serde")]
 mod tests {
     use flatgeom::{
-        Geometry2, LineString3, MultiLineString2, MultiPoint3, MultiPolygon2, Polygon2,
+        Geometry2, Line2, LineString3, MultiLineString2, MultiPoint3, MultiPolygon2,
+        MultiPolygonBatch2, MultiTriangle3, Point3, Polygon2, Triangle2,
     };
 
     #[derive(serde::Serialize, serde::Deserialize)]
@@ -11,6 +12,10 @@ mod tests {
         line: LineString3<'static>,
         mline: MultiLineString2<'static>,
         mpoint: MultiPoint3<'static>,
+        point: Point3,
+        segment: Line2,
+        tri: Triangle2,
+        mtri: MultiTriangle3<'static>,
         geom: Geometry2<'static>,
     }
 
@@ -22,9 +27,74 @@ mod tests {
             mline: Default::default(),
             line: Default::default(),
             mpoint: Default::default(),
+            point: Default::default(),
+            segment: Default::default(),
+            tri: Default::default(),
+            mtri: Default::default(),
             geom: Geometry2::MultiPoint(Default::default()),
         };
         let serialized = serde_json::to_string(&m).unwrap();
         let _: MyStruct = serde_json::from_str(&serialized).unwrap();
     }
+
+    #[test]
+    fn test_serde_point() {
+        let geom = Geometry2::Point([1., 2.].into());
+        let serialized = serde_json::to_string(&geom).unwrap();
+        let deserialized: Geometry2 = serde_json::from_str(&serialized).unwrap();
+        match deserialized {
+            Geometry2::Point(point) => assert_eq!(point.coord(), &[1., 2.]),
+            _ => panic!("Point is expected"),
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Feature {
+        #[serde(with = "flatgeom::geojson")]
+        geometry: Geometry2<'static>,
+        #[serde(with = "flatgeom::geojson")]
+        poly: Polygon2<'static>,
+    }
+
+    #[test]
+    fn test_serde_geojson() {
+        let mut poly = Polygon2::new();
+        poly.add_ring([[0., 0.], [1., 0.], [1., 1.], [0., 1.]]);
+        let f = Feature {
+            geometry: Geometry2::Point([1., 2.].into()),
+            poly,
+        };
+        let serialized = serde_json::to_string(&f).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"geometry":{"type":"Point","coordinates":[1.0,2.0]},"poly":{"type":"Polygon","coordinates":[[[0.0,0.0],[1.0,0.0],[1.0,1.0],[0.0,1.0],[0.0,0.0]]]}}"#
+        );
+        let deserialized: Feature = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.poly, f.poly);
+        match deserialized.geometry {
+            Geometry2::Point(point) => assert_eq!(point.coord(), &[1., 2.]),
+            _ => panic!("Point is expected"),
+        }
+    }
+
+    #[test]
+    fn test_serde_multipolygon_batch() {
+        let mut mpoly = MultiPolygon2::new();
+        mpoly.add_exterior([[0., 0.], [5., 0.], [5., 5.], [0., 5.]]);
+        mpoly.add_interior([[1., 1.], [2., 1.], [2., 2.], [1., 2.]]);
+        mpoly.add_exterior([[6., 0.], [7., 0.], [7., 1.]]);
+        let batch: MultiPolygonBatch2 = [MultiPolygon2::new(), mpoly].into_iter().collect();
+        let serialized = serde_json::to_string(&batch).unwrap();
+        let deserialized: MultiPolygonBatch2 = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, batch);
+
+        // the ends must cover the buffers
+        let invalid = r#"{"all_coords":[[0.0,0.0],[1.0,0.0],[1.0,1.0]],"all_coords_spans":[],"all_hole_indices":[],"all_holes_spans":[],"ends":[[2,0,0,0]]}"#;
+        let err = serde_json::from_str::<MultiPolygonBatch2>(invalid).unwrap_err();
+        assert!(err.to_string().contains("must match the buffer lengths"));
+
+        // spans of each multipolygon are checked
+        let invalid = r#"{"all_coords":[[0.0,0.0],[1.0,0.0],[1.0,1.0]],"all_coords_spans":[],"all_hole_indices":[3],"all_holes_spans":[],"ends":[[3,0,1,0]]}"#;
+        assert!(serde_json::from_str::<MultiPolygonBatch2>(invalid).is_err());
+    }
 }